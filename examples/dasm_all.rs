@@ -39,6 +39,7 @@ fn main() {
 
     // Process each line, replacing DASM patterns with disassembly
     for line in lines {
+        #[allow(clippy::regex_creation_in_loops)]
         let pattern = Regex::new(r"DASM\((0x[0-9a-fA-F]+)\)").unwrap();
         let modified_line = pattern.replace_all(line, |caps: &regex::Captures| {
             let hex_str = &caps[1];
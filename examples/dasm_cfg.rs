@@ -0,0 +1,55 @@
+use clap::Parser;
+use object::{Object, ObjectSection, ObjectSymbol};
+use rvdasm::cfg::*;
+use rvdasm::cfg_export::*;
+use rvdasm::disassembler::*;
+use std::fs::File;
+use std::io::Read;
+
+// Renders the control-flow graph of one function in an ELF's .text
+// section as Graphviz DOT (pipe into `dot -Tsvg`) or JSON.
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long)]
+    file: String,
+    /// Symbol name of the function, defaults to the start of .text
+    #[clap(short, long)]
+    symbol: Option<String>,
+    #[clap(short, long, default_value = "false")]
+    json: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut elf_file = File::open(args.file.clone()).unwrap();
+    let mut elf_buffer = Vec::new();
+    elf_file.read_to_end(&mut elf_buffer).unwrap();
+    let elf = object::File::parse(&*elf_buffer).unwrap();
+
+    let xlen = match elf.architecture() {
+        object::Architecture::Riscv64 => Xlen::XLEN64,
+        object::Architecture::Riscv32 => Xlen::XLEN32,
+        arch => panic!("Unsupported architecture: {:?}", arch),
+    };
+    let disassembler = Disassembler::new(xlen);
+
+    let text_section = elf.section_by_name(".text").unwrap();
+    let text_data = text_section.data().unwrap();
+    let entry = match &args.symbol {
+        Some(name) => elf
+            .symbols()
+            .find(|symbol| symbol.name() == Ok(name.as_str()))
+            .unwrap_or_else(|| panic!("Symbol not found: {}", name))
+            .address(),
+        None => text_section.address(),
+    };
+
+    let decoded_insns = disassembler.disassemble_all(text_data, text_section.address());
+    let cfg = Cfg::build(&decoded_insns, xlen);
+    if args.json {
+        println!("{}", to_json(&cfg, entry));
+    } else {
+        print!("{}", to_dot(&cfg, entry, &ExportOptions::default()));
+    }
+}
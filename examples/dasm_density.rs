@@ -0,0 +1,73 @@
+use clap::Parser;
+use object::{Object, ObjectSection, ObjectSymbol};
+use rvdasm::density::DensityReport;
+use rvdasm::disassembler::*;
+use rvdasm::functions::*;
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::Read;
+
+// Reports how much of an ELF's .text section is compressed, and which
+// 32-bit instructions could have been.
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long)]
+    file: String,
+    /// ISA string such as `rv64gc_zcb` limiting the compressed forms considered
+    #[clap(short, long)]
+    isa: Option<String>,
+    /// Print the report as JSON
+    #[clap(short, long, default_value = "false")]
+    json: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut elf_file = File::open(args.file.clone()).unwrap();
+    let mut elf_buffer = Vec::new();
+    elf_file.read_to_end(&mut elf_buffer).unwrap();
+    let elf = object::File::parse(&*elf_buffer).unwrap();
+
+    let xlen = match elf.architecture() {
+        object::Architecture::Riscv64 => Xlen::XLEN64,
+        object::Architecture::Riscv32 => Xlen::XLEN32,
+        arch => panic!("Unsupported architecture: {:?}", arch),
+    };
+    let disassembler = Disassembler::new(xlen);
+    let extensions = args.isa.as_ref().map(|isa| {
+        let isa = Disassembler::from_isa_string(isa).expect("invalid ISA string");
+        assert!(isa.xlen() == xlen, "ISA string does not match the ELF");
+        isa.extensions().unwrap().clone()
+    });
+
+    let text_section = elf.section_by_name(".text").unwrap();
+    let text_data = text_section.data().unwrap();
+    let text_range = text_section.address()..text_section.address() + text_data.len() as u64;
+    let insns = disassembler.disassemble_all(text_data, text_section.address());
+
+    // ELF symbols name the functions, stripped binaries fall back to discovery
+    let mut symbols = SymbolTable::new();
+    for symbol in elf.symbols().filter(|symbol| {
+        symbol.kind() == object::SymbolKind::Text
+            && symbol.size() > 0
+            && text_range.contains(&symbol.address())
+    }) {
+        symbols.insert(Function {
+            name: symbol.name().unwrap_or("").to_string(),
+            start: symbol.address(),
+            end: symbol.address() + symbol.size(),
+            reasons: BTreeSet::new(),
+        });
+    }
+    if symbols.is_empty() {
+        symbols = SymbolTable::discover(&insns, xlen, &[elf.entry()]);
+    }
+
+    let report = DensityReport::build(&insns, xlen, &symbols, extensions.as_ref());
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        print!("{}", report);
+    }
+}
@@ -1,6 +1,16 @@
 use clap::Parser;
-use object::{Object, ObjectSection};
+use object::read::elf::{ElfFile, FileHeader, SectionHeader};
+use object::{elf, Endianness, Object, ObjectSection, ObjectSymbol, SectionIndex, SymbolIndex};
+use rvdasm::cfg::Cfg;
+use rvdasm::const_prop::ConstProp;
 use rvdasm::disassembler::*;
+use rvdasm::functions::SymbolTable;
+use rvdasm::insn::Insn;
+use rvdasm::jump_table::disassemble_recursive_with_jump_tables;
+use rvdasm::mapping::*;
+use rvdasm::memory::MemoryImage;
+use rvdasm::reloc::*;
+use std::collections::{BTreeMap, HashMap};
 use std::fs::File;
 use std::io::Read;
 
@@ -10,6 +20,33 @@ struct Args {
     file: String,
     #[clap(short, long, default_value = "false")]
     canonical: bool,
+    /// Follow control flow from the entry point and symbols instead of a linear sweep
+    #[clap(short, long, default_value = "false")]
+    recursive: bool,
+    /// Label functions found by boundary detection, for stripped binaries
+    #[clap(long, default_value = "false")]
+    functions: bool,
+}
+
+/// Helper: Get the `.rela` sections that patch executable sections, with the
+/// index `sh_info` gives for the patched section, whatever either is named
+fn exec_rela_sections<'data, Elf: FileHeader<Endian = Endianness>>(
+    file: &ElfFile<'data, Elf>,
+) -> Vec<(SectionIndex, &'data [u8])> {
+    let endian = file.endian();
+    let headers = file.elf_section_table();
+    headers
+        .iter()
+        .filter(|header| header.sh_type(endian) == elf::SHT_RELA)
+        .filter_map(|header| {
+            let target = SectionIndex(header.sh_info(endian) as usize);
+            let flags: u64 = headers.section(target).ok()?.sh_flags(endian).into();
+            if flags & u64::from(elf::SHF_EXECINSTR) == 0 {
+                return None;
+            }
+            Some((target, header.data(endian, file.data()).ok()?))
+        })
+        .collect()
 }
 
 fn main() {
@@ -36,24 +73,137 @@ fn main() {
 
     println!("entry point: 0x{:08x}", entry_point);
 
-    let decoded_insns = disassembler.disassemble_all(&text_data, entry_point);
-
-    // sort keys by address
-    let mut keys: Vec<u64> = decoded_insns.keys().cloned().collect();
-    keys.sort();
+    let text_range = entry_point..entry_point + text_data.len() as u64;
+    let lines = if args.recursive {
+        let mut roots = vec![elf.entry()];
+        roots.extend(
+            elf.symbols()
+                .filter(|symbol| symbol.kind() == object::SymbolKind::Text)
+                .map(|symbol| symbol.address())
+                .filter(|addr| text_range.contains(addr)),
+        );
+        // jump tables usually live in .rodata, so map every loaded section
+        let mut memory = MemoryImage::new();
+        for section in elf.sections() {
+            if let (true, Ok(data)) = (section.address() != 0, section.data()) {
+                memory.add_segment(section.address(), data);
+            }
+        }
+        let (traversal, tables) = disassemble_recursive_with_jump_tables(
+            &disassembler,
+            text_data,
+            entry_point,
+            &roots,
+            &memory,
+        );
+        for table in &tables {
+            println!(
+                "jump table: 0x{:08x} via 0x{:08x}, {} entries",
+                table.jump,
+                table.table,
+                table.targets.len()
+            );
+        }
+        for conflict in &traversal.conflicts {
+            println!(
+                "conflict: 0x{:08x} overlaps 0x{:08x}",
+                conflict.other, conflict.addr
+            );
+        }
+        let mut lines: BTreeMap<u64, Line> = traversal
+            .insns
+            .into_iter()
+            .map(|(addr, insn)| (addr, Line::Insn(insn)))
+            .collect();
+        for range in &traversal.data {
+            let bytes = &text_data
+                [(range.start - entry_point) as usize..(range.end - entry_point) as usize];
+            for (addr, directive) in data_directives(bytes, range.start) {
+                lines.insert(addr, Line::Data(directive));
+            }
+        }
+        lines
+    } else {
+        // $x / $d / $x<isa> mapping symbols split code from data
+        let symbols: Vec<MappingSymbol> = elf
+            .symbols()
+            .filter(|symbol| text_range.contains(&symbol.address()))
+            .filter_map(|symbol| MappingSymbol::parse(symbol.name().ok()?, symbol.address()))
+            .collect();
+        disassembler.disassemble_mapped(text_data, entry_point, &symbols)
+    };
 
     // write to file with extension .dump
     // let mut dump_file = File::create(format!("{}.dump", args.file)).unwrap();
-    for key in keys {
-        if args.canonical {
-            println!("{}", decoded_insns[&key].to_canonical());
-        } else {
-            println!(
-                "0x{:08x}: {:08x}     {}",
-                key,
-                decoded_insns[&key].get_raw(),
-                decoded_insns[&key].to_string()
-            );
+    let insns: HashMap<u64, Insn> = lines
+        .iter()
+        .filter_map(|(addr, line)| match line {
+            Line::Insn(insn) => Some((*addr, insn.clone())),
+            Line::Data(_) => None,
+        })
+        .collect();
+    let symbols = if args.functions {
+        SymbolTable::discover(&insns, xlen, &[elf.entry()])
+    } else {
+        SymbolTable::new()
+    };
+    // object files and kernel modules keep their relocations, shown as `objdump -dr` does
+    let rela_sections = match &elf {
+        object::File::Elf32(file) => exec_rela_sections(file),
+        object::File::Elf64(file) => exec_rela_sections(file),
+        _ => Vec::new(),
+    };
+    let resolve = |index: u32| {
+        let symbol = elf.symbol_by_index(SymbolIndex(index as usize)).ok()?;
+        // section symbols are named after their section
+        let name = match (symbol.name(), symbol.section_index()) {
+            (Ok(""), Some(section)) => elf.section_by_index(section).ok()?.name().ok()?,
+            (name, _) => name.ok()?,
+        };
+        Some(RelocSymbol {
+            name: name.to_string(),
+            value: symbol.address(),
+        })
+    };
+    // only the relocations patching the disassembled section line up with it
+    let relocs = RelocationTable::from_relocations(
+        rela_sections
+            .into_iter()
+            .filter(|(target, _)| *target == text_section.index())
+            .flat_map(|(_, bytes)| parse_rela(bytes, xlen, resolve))
+            .collect(),
+    );
+    // resolved addresses become `# 0x...` comments, meaningless before relocation
+    let consts = if relocs.is_empty() {
+        ConstProp::within_blocks(&Cfg::build(&insns, xlen))
+    } else {
+        ConstProp::default()
+    };
+
+    for (addr, line) in &lines {
+        if let Some(function) = symbols.function(*addr) {
+            println!("\n<{}>:", function.name);
+        }
+        match line {
+            Line::Insn(insn) if args.canonical => println!("{}", insn.to_canonical()),
+            Line::Insn(insn) => match consts.comment(*addr) {
+                Some(comment) => println!(
+                    "0x{:08x}: {:08x}     {} {}",
+                    addr,
+                    insn.get_raw(),
+                    insn,
+                    comment
+                ),
+                None => println!("0x{:08x}: {:08x}     {}", addr, insn.get_raw(), insn),
+            },
+            Line::Data(directive) => {
+                println!("0x{:08x}: {:08x}     {}", addr, directive.value, directive)
+            }
+        }
+        if !args.canonical {
+            for reloc in relocs.at(*addr) {
+                println!("\t\t0x{:08x}: {}", addr, relocs.describe(reloc));
+            }
         }
     }
 }
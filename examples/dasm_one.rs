@@ -26,6 +26,6 @@ fn main() {
     if args.canonical {
         println!("{}", insn.to_canonical());
     } else {
-        println!("{}", insn.to_string());
+        println!("{}", insn);
     }
 }
@@ -0,0 +1,38 @@
+use clap::Parser;
+use rvdasm::disassembler::*;
+use rvdasm::overlap::OverlapReport;
+
+// Lists the encodings hidden by the first-match order of the spec tables,
+// and the specs that never decode.
+
+#[derive(Parser)]
+struct Args {
+    /// ISA string such as `rv64gc` limiting the extensions checked, all of
+    /// them by default
+    #[clap(short, long)]
+    isa: Option<String>,
+    /// XLEN when no ISA string is given
+    #[clap(short, long, default_value = "64")]
+    xlen: u32,
+    /// Print the report as JSON
+    #[clap(short, long, default_value = "false")]
+    json: bool,
+}
+
+fn main() {
+    let args = Args::parse();
+    let isa = match args.isa {
+        Some(isa) => Disassembler::from_isa_string(&isa).expect("invalid ISA string"),
+        None => match args.xlen {
+            32 => Disassembler::new(Xlen::XLEN32),
+            64 => Disassembler::new(Xlen::XLEN64),
+            xlen => panic!("Unsupported XLEN: {}", xlen),
+        },
+    };
+    let report = OverlapReport::build(isa.xlen(), isa.extensions());
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        print!("{}", report);
+    }
+}
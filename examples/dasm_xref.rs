@@ -0,0 +1,58 @@
+use clap::Parser;
+use object::{Object, ObjectSection};
+use rvdasm::cfg::*;
+use rvdasm::const_prop::*;
+use rvdasm::disassembler::*;
+use rvdasm::xref::*;
+use std::fs::File;
+use std::io::Read;
+
+// Answers "who calls this?" and "who touches this MMIO register?" for
+// the code in an ELF's .text section.
+
+#[derive(Parser)]
+struct Args {
+    #[clap(short, long)]
+    file: String,
+    /// Target address in hex, lists every reference when omitted
+    #[clap(short, long)]
+    addr: Option<String>,
+    /// Number of bytes from `addr` to include, e.g. a block of registers
+    #[clap(short, long, default_value = "1")]
+    len: u64,
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut elf_file = File::open(args.file.clone()).unwrap();
+    let mut elf_buffer = Vec::new();
+    elf_file.read_to_end(&mut elf_buffer).unwrap();
+    let elf = object::File::parse(&*elf_buffer).unwrap();
+
+    let xlen = match elf.architecture() {
+        object::Architecture::Riscv64 => Xlen::XLEN64,
+        object::Architecture::Riscv32 => Xlen::XLEN32,
+        arch => panic!("Unsupported architecture: {:?}", arch),
+    };
+    let disassembler = Disassembler::new(xlen);
+
+    let text_section = elf.section_by_name(".text").unwrap();
+    let text_data = text_section.data().unwrap();
+    let decoded_insns = disassembler.disassemble_all(text_data, text_section.address());
+    let cfg = Cfg::build(&decoded_insns, xlen);
+    let db = XrefDb::build(&cfg, &ConstProp::within_blocks(&cfg));
+
+    let xrefs: Vec<&Xref> = match &args.addr {
+        Some(addr) => {
+            let addr = u64::from_str_radix(addr.trim_start_matches("0x"), 16).unwrap();
+            db.refs_to_range(addr..addr + args.len).collect()
+        }
+        None => db.iter().collect(),
+    };
+    for xref in xrefs {
+        println!(
+            "0x{:08x} <- 0x{:08x} {:?}: {}",
+            xref.to, xref.from, xref.kind, decoded_insns[&xref.from]
+        );
+    }
+}
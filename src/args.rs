@@ -1,4 +1,39 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// How the ISA spec classifies an encoding, from the most to the least usable
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Legality {
+    #[default]
+    Legal,
+    /// Executes as a no-op today, reserved for future hints, e.g. `c.li x0, 1`
+    Hint,
+    /// Reserved for future standard or custom use, e.g. `c.addi4spn` with a
+    /// zero immediate
+    Reserved,
+    /// The defined illegal instruction, the all-zero halfword
+    Illegal,
+}
+
+impl Legality {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Legality::Legal => "legal",
+            Legality::Hint => "hint",
+            Legality::Reserved => "reserved",
+            Legality::Illegal => "illegal",
+        }
+    }
+}
+
+impl fmt::Display for Legality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum Arg {
     DstReg(u32),
@@ -8,7 +43,14 @@ pub enum Arg {
     Flag(u32),
     CSR(u32),
     Nothing,
+    /// The word belongs to another spec
     Error,
+    /// The word decodes as this spec but is a HINT
+    Hint,
+    /// The word decodes as this spec but is reserved
+    Reserved,
+    /// The word decodes as this spec but is the defined illegal instruction
+    Illegal,
 }
 
 impl Arg {
@@ -42,16 +84,13 @@ impl Arg {
         matches!(self, Arg::Error)
     }
 
-    /// Helper: Format the argument to a string representation
-    pub fn to_string(&self) -> String {
+    /// Helper: Get the status the argument gives its encoding
+    pub fn legality(&self) -> Legality {
         match self {
-            Arg::DstReg(val) => format!("{}", val),
-            Arg::SrcReg(val) => format!("{}", val),
-            Arg::Imm(val) => format!("{}", val),
-            Arg::UImm(val) => format!("{}", val),
-            Arg::Flag(val) => format!("{}", val),
-            Arg::CSR(val) => format!("{}", val),
-            _ => "".to_string(),
+            Arg::Hint => Legality::Hint,
+            Arg::Reserved => Legality::Reserved,
+            Arg::Illegal => Legality::Illegal,
+            _ => Legality::Legal,
         }
     }
 
@@ -78,6 +117,21 @@ impl Arg {
     }
 }
 
+impl fmt::Display for Arg {
+    /// Helper: Format the argument to a string representation
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Arg::DstReg(val) => write!(f, "{}", val),
+            Arg::SrcReg(val) => write!(f, "{}", val),
+            Arg::Imm(val) => write!(f, "{}", val),
+            Arg::UImm(val) => write!(f, "{}", val),
+            Arg::Flag(val) => write!(f, "{}", val),
+            Arg::CSR(val) => write!(f, "{}", val),
+            _ => Ok(()),
+        }
+    }
+}
+
 // helper functions
 fn x(insn: u32, lo: u32, len: u32) -> u32 {
     (insn >> lo) & ((1 << len) - 1)
@@ -210,14 +264,18 @@ pub fn rm(insn: u32) -> (Arg, String) {
 }
 
 // compressed
+// 3-bit register fields address x8-x15 (or f8-f15)
 pub fn rd_p(insn: u32) -> (Arg, String) {
-    (Arg::DstReg(x(insn, 2, 3)), "rd".to_string())
+    (Arg::DstReg(x(insn, 2, 3) + 8), "rd".to_string())
+}
+pub fn rd_rs1_p(insn: u32) -> (Arg, String) {
+    (Arg::DstReg(x(insn, 7, 3) + 8), "rd".to_string())
 }
 pub fn rs1_p(insn: u32) -> (Arg, String) {
-    (Arg::SrcReg(x(insn, 7, 3)), "rs1".to_string())
+    (Arg::SrcReg(x(insn, 7, 3) + 8), "rs1".to_string())
 }
 pub fn rs2_p(insn: u32) -> (Arg, String) {
-    (Arg::SrcReg(x(insn, 2, 3)), "rs2".to_string())
+    (Arg::SrcReg(x(insn, 2, 3) + 8), "rs2".to_string())
 }
 pub fn rs1_n0(insn: u32) -> (Arg, String) {
     match x(insn, 7, 5) {
@@ -231,12 +289,16 @@ pub fn rd_n0(insn: u32) -> (Arg, String) {
         val => (Arg::DstReg(val), "rd".to_string()),
     }
 }
+// x2 belongs to c.addi16sp, x0 is a HINT flagged by `hint_rd_x0`
 pub fn rd_n2(insn: u32) -> (Arg, String) {
     match x(insn, 7, 5) {
-        0 | 2 => (Arg::Error, "".to_string()),
+        2 => (Arg::Error, "".to_string()),
         val => (Arg::DstReg(val), "rd".to_string()),
     }
 }
+pub fn c_rs1(insn: u32) -> (Arg, String) {
+    (Arg::SrcReg(x(insn, 7, 5)), "rs1".to_string())
+}
 pub fn c_rs1_n0(insn: u32) -> (Arg, String) {
     match x(insn, 7, 5) {
         0 => (Arg::Error, "".to_string()),
@@ -254,7 +316,7 @@ pub fn c_rs2(insn: u32) -> (Arg, String) {
 }
 pub fn c_nzimm6hi(insn: u32) -> (Arg, String) {
     (
-        Arg::UImm(x(insn, 2, 5) + (x(insn, 12, 1) << 5)),
+        Arg::Imm(x(insn, 2, 5) as i32 + (xs(insn, 12, 1) << 5)),
         "imm".to_string(),
     )
 }
@@ -272,11 +334,12 @@ pub fn c_imm6lo(_insn: u32) -> (Arg, String) {
 }
 pub fn c_nzimm10hi(insn: u32) -> (Arg, String) {
     (
-        Arg::UImm(
-            (x(insn, 6, 1) << 2)
-                + (x(insn, 5, 1) << 3)
-                + (x(insn, 11, 2) << 4)
-                + (x(insn, 7, 4) << 6),
+        Arg::Imm(
+            (x(insn, 6, 1) << 4) as i32
+                + (x(insn, 2, 1) << 5) as i32
+                + (x(insn, 5, 1) << 6) as i32
+                + (x(insn, 3, 2) << 7) as i32
+                + (xs(insn, 12, 1) << 9),
         ),
         "imm".to_string(),
     )
@@ -361,6 +424,15 @@ pub fn c_uimm7hi(insn: u32) -> (Arg, String) {
 pub fn c_uimm7lo(_insn: u32) -> (Arg, String) {
     (Arg::Nothing, "".to_string())
 }
+pub fn c_uimm2(insn: u32) -> (Arg, String) {
+    (
+        Arg::UImm(x(insn, 6, 1) + (x(insn, 5, 1) << 1)),
+        "imm".to_string(),
+    )
+}
+pub fn c_uimm1(insn: u32) -> (Arg, String) {
+    (Arg::UImm(x(insn, 5, 1) << 1), "imm".to_string())
+}
 pub fn c_uimm8sphi(insn: u32) -> (Arg, String) {
     (
         Arg::UImm((x(insn, 4, 3) << 2) + (x(insn, 12, 1) << 5) + (x(insn, 2, 2) << 6)),
@@ -378,7 +450,7 @@ pub fn c_uimm9sp_s(insn: u32) -> (Arg, String) {
 }
 pub fn c_nzuimm6hi(insn: u32) -> (Arg, String) {
     (
-        Arg::UImm((x(insn, 2, 4)) + (x(insn, 12, 1) << 5)),
+        Arg::UImm(x(insn, 2, 5) + (x(insn, 12, 1) << 5)),
         "imm".to_string(),
     )
 }
@@ -392,6 +464,55 @@ pub fn c_uimm8sp_s(insn: u32) -> (Arg, String) {
     )
 }
 
+// reserved and HINT encodings
+// these mark a decoded word instead of reading an operand
+fn marker(marked: bool, arg: Arg) -> (Arg, String) {
+    if marked {
+        (arg, "".to_string())
+    } else {
+        (Arg::Nothing, "".to_string())
+    }
+}
+// rd, or rs1 of c.jr, in bits 11-7
+pub fn hint_rd_x0(insn: u32) -> (Arg, String) {
+    marker(x(insn, 7, 5) == 0, Arg::Hint)
+}
+pub fn reserved_rd_x0(insn: u32) -> (Arg, String) {
+    marker(x(insn, 7, 5) == 0, Arg::Reserved)
+}
+// addi with rd x0, other than nop itself
+pub fn hint_addi_rd_x0(insn: u32) -> (Arg, String) {
+    marker(x(insn, 7, 5) == 0 && insn != 0x13, Arg::Hint)
+}
+// fence with an empty predecessor or successor set
+pub fn hint_fence_empty(insn: u32) -> (Arg, String) {
+    marker(x(insn, 24, 4) == 0 || x(insn, 20, 4) == 0, Arg::Hint)
+}
+// the 6-bit immediate or shift amount of CI-type, in bits 12 and 6-2
+pub fn hint_c_imm6_zero(insn: u32) -> (Arg, String) {
+    marker(insn & 0x107c == 0, Arg::Hint)
+}
+pub fn hint_c_imm6_nonzero(insn: u32) -> (Arg, String) {
+    marker(insn & 0x107c != 0, Arg::Hint)
+}
+pub fn reserved_c_imm6_zero(insn: u32) -> (Arg, String) {
+    marker(insn & 0x107c == 0, Arg::Reserved)
+}
+// c.addi4spn, whose all-zero word is the defined illegal instruction
+pub fn reserved_c_nzuimm10_zero(insn: u32) -> (Arg, String) {
+    match insn & 0xffff {
+        0 => (Arg::Illegal, "".to_string()),
+        _ => marker(x(insn, 5, 8) == 0, Arg::Reserved),
+    }
+}
+// RV32 shift amounts stop at 31
+pub fn reserved_c_shamt5(insn: u32) -> (Arg, String) {
+    marker(x(insn, 12, 1) != 0, Arg::Reserved)
+}
+pub fn reserved_shamt5(insn: u32) -> (Arg, String) {
+    marker(x(insn, 25, 1) != 0, Arg::Reserved)
+}
+
 // vector
 pub fn vd(insn: u32) -> (Arg, String) {
     (Arg::DstReg(x(insn, 7, 5)), "vd".to_string())
@@ -412,8 +533,9 @@ pub fn vm(insn: u32) -> (Arg, String) {
 pub fn simm5(insn: u32) -> (Arg, String) {
     (Arg::Imm(xs(insn, 15, 5)), "imm".to_string())
 }
+// `vsetivli` vtype, kept apart from the uimm AVL
 pub fn zimm10(insn: u32) -> (Arg, String) {
-    (Arg::UImm(x(insn, 20, 10)), "imm".to_string())
+    (Arg::Flag(x(insn, 20, 10)), "vtypei".to_string())
 }
 pub fn zimm11(insn: u32) -> (Arg, String) {
     (Arg::UImm(x(insn, 20, 11)), "imm".to_string())
@@ -0,0 +1,1386 @@
+use crate::args::Arg;
+use crate::csr::csr_address;
+use crate::disassembler::{is_compressed, Xlen};
+use crate::encoder::{spec_operands, specs, Encoder};
+use crate::isa::Spec;
+use crate::isa_meta::{Category, Extension};
+use crate::mnemonic::Mnemonic;
+use crate::reg::{operand_reg, uses_v0_implicitly, Reg};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+
+const GPR_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+const FPR_NAMES: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+const ROUNDING_MODES: &[(&str, u32)] = &[
+    ("rne", 0),
+    ("rtz", 1),
+    ("rdn", 2),
+    ("rup", 3),
+    ("rmm", 4),
+    ("dyn", 7),
+];
+/// Vector multiply-adds take the multiplier operand before `vs2`
+const VECTOR_MADDS: &[&str] = &[
+    "vmacc", "vnmsac", "vmadd", "vnmsub", "vwmacc", "vwmaccu", "vwmaccsu", "vwmaccus", "vfmacc",
+    "vfnmacc", "vfmsac", "vfnmsac", "vfmadd", "vfnmadd", "vfmsub", "vfnmsub", "vfwmacc",
+    "vfwnmacc", "vfwmsac", "vfwnmsac",
+];
+/// Pseudo-instructions expanded before encoding
+const PSEUDOS: &[&str] = &[
+    "nop",
+    "li",
+    "la",
+    "lla",
+    "call",
+    "tail",
+    "mv",
+    "not",
+    "neg",
+    "negw",
+    "sext.w",
+    "zext.b",
+    "seqz",
+    "snez",
+    "sltz",
+    "sgtz",
+    "beqz",
+    "bnez",
+    "blez",
+    "bgez",
+    "bltz",
+    "bgtz",
+    "bgt",
+    "ble",
+    "bgtu",
+    "bleu",
+    "j",
+    "jr",
+    "ret",
+    "csrr",
+    "csrw",
+    "csrs",
+    "csrc",
+    "csrwi",
+    "csrsi",
+    "csrci",
+    "rdcycle",
+    "rdtime",
+    "rdinstret",
+    "fmv.s",
+    "fmv.d",
+    "fabs.s",
+    "fabs.d",
+    "fneg.s",
+    "fneg.d",
+];
+/// Vector `.vi` forms whose 5-bit immediate is unsigned in GNU syntax
+const VECTOR_UIMMS: &[&str] = &[
+    "vsll",
+    "vsrl",
+    "vsra",
+    "vssrl",
+    "vssra",
+    "vnsrl",
+    "vnsra",
+    "vnclip",
+    "vnclipu",
+    "vrgather",
+    "vslideup",
+    "vslidedown",
+];
+const NOP: u32 = 0x0000_0013;
+const C_NOP: u32 = 0x0001;
+
+/// Which textual form the assembler reads
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Syntax {
+    /// GNU `as` syntax: ABI register names, `imm(reg)` memory operands,
+    /// pseudo-instructions and 20-bit `lui`/`auipc` immediates
+    #[default]
+    Gnu,
+    /// The form `Insn::to_string` emits, e.g. `lw x11, x10, 0`, with operands in
+    /// decoder order and immediates as decoded
+    Display,
+}
+
+/// An assembly error and the 1-based line it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AsmError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for AsmError {}
+
+/// Machine code assembled from a snippet
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Program {
+    pub base: u64,
+    pub bytes: Vec<u8>,
+    /// Address and encoding of each instruction, in order
+    pub insns: Vec<(u64, u32)>,
+    /// Labels and `.equ` symbols defined by the snippet
+    pub labels: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Modifier {
+    None,
+    Hi,
+    Lo,
+    PcrelHi,
+    PcrelLo,
+}
+
+/// `[%modifier(] [symbol] [+- constant] [)]`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Expr {
+    modifier: Modifier,
+    symbol: Option<String>,
+    addend: i64,
+}
+
+impl Expr {
+    fn constant(addend: i64) -> Self {
+        Self {
+            modifier: Modifier::None,
+            symbol: None,
+            addend,
+        }
+    }
+
+    /// Helper: Get a bare identifier such as `rne` or `mstatus`
+    fn name(&self) -> Option<&str> {
+        match (self.modifier, &self.symbol, self.addend) {
+            (Modifier::None, Some(symbol), 0) => Some(symbol),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifier = match self.modifier {
+            Modifier::None => "",
+            Modifier::Hi => "%hi(",
+            Modifier::Lo => "%lo(",
+            Modifier::PcrelHi => "%pcrel_hi(",
+            Modifier::PcrelLo => "%pcrel_lo(",
+        };
+        write!(f, "{}", modifier)?;
+        match &self.symbol {
+            Some(symbol) if self.addend != 0 => write!(f, "{}{:+}", symbol, self.addend)?,
+            Some(symbol) => write!(f, "{}", symbol)?,
+            None => write!(f, "{}", self.addend)?,
+        }
+        if self.modifier != Modifier::None {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Operand {
+    Reg(Reg),
+    Expr(Expr),
+    Mem {
+        offset: Expr,
+        base: Reg,
+    },
+    /// `v0.t`
+    Mask,
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Reg(reg) => write!(f, "{}", reg),
+            Operand::Expr(expr) => write!(f, "{}", expr),
+            Operand::Mem { offset, base } => write!(f, "{}({})", offset, base),
+            Operand::Mask => write!(f, "v0.t"),
+        }
+    }
+}
+
+/// Where each written operand goes, in order
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Slot {
+    Tag(String),
+    /// `offset(base)`; a missing base tag means the implicit `sp`
+    Mem {
+        offset: Option<String>,
+        base: Option<String>,
+    },
+    /// `sp` written out but not encoded
+    Sp,
+    /// `v0` written out for carry and merge forms, not encoded
+    V0,
+    /// `e32, m1, ta, ma` or a number, filling the tag
+    Vtype(String),
+    /// Optional trailing rounding mode
+    Rm,
+    /// Optional trailing `v0.t`
+    Mask,
+    /// `iorw` sets of a `fence`
+    FenceSet(String),
+}
+
+/// Helper: Parse an integer literal
+fn parse_number(text: &str) -> Option<i64> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let lower = digits.to_ascii_lowercase();
+    let val = if let Some(hex) = lower.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16).ok()?
+    } else if let Some(bin) = lower.strip_prefix("0b") {
+        u64::from_str_radix(bin, 2).ok()?
+    } else if lower.chars().all(|c| c.is_ascii_digit()) && !lower.is_empty() {
+        lower.parse::<u64>().ok()?
+    } else {
+        return None;
+    };
+    Some(if negative {
+        (val as i64).wrapping_neg()
+    } else {
+        val as i64
+    })
+}
+
+/// Helper: Check if a token can name a label
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '$'))
+}
+
+/// Helper: Parse `symbol`, `constant` or `symbol +- constant` sums
+fn parse_sum(text: &str) -> Option<Expr> {
+    let mut expr = Expr::constant(0);
+    let mut term = String::new();
+    let mut sign = 1;
+    let mut terms = Vec::new();
+    for (i, c) in text.chars().enumerate() {
+        if (c == '+' || c == '-') && i > 0 && !term.trim().is_empty() {
+            terms.push((sign, term.trim().to_string()));
+            term.clear();
+            sign = if c == '-' { -1 } else { 1 };
+        } else {
+            term.push(c);
+        }
+    }
+    terms.push((sign, term.trim().to_string()));
+    for (sign, term) in terms {
+        if let Some(val) = parse_number(&term) {
+            expr.addend = expr.addend.wrapping_add(sign * val);
+        } else if is_identifier(&term) && sign == 1 && expr.symbol.is_none() {
+            expr.symbol = Some(term);
+        } else {
+            return None;
+        }
+    }
+    Some(expr)
+}
+
+/// Helper: Parse an expression with an optional relocation modifier
+fn parse_expr(text: &str) -> Option<Expr> {
+    let text = text.trim();
+    for (prefix, modifier) in [
+        ("%hi(", Modifier::Hi),
+        ("%lo(", Modifier::Lo),
+        ("%pcrel_hi(", Modifier::PcrelHi),
+        ("%pcrel_lo(", Modifier::PcrelLo),
+    ] {
+        if let Some(inner) = text.strip_prefix(prefix) {
+            let mut expr = parse_sum(inner.strip_suffix(')')?)?;
+            expr.modifier = modifier;
+            return Some(expr);
+        }
+    }
+    parse_sum(text)
+}
+
+/// Helper: Parse a register in ABI, numeric or `Insn::to_string` (`vs21`) form
+fn parse_reg(text: &str) -> Option<Reg> {
+    let number = |digits: &str| {
+        digits
+            .parse::<u32>()
+            .ok()
+            .filter(|n| *n < 32 && !digits.starts_with('+'))
+    };
+    if text == "fp" {
+        return Some(Reg::Gpr(8));
+    }
+    if let Some(n) = GPR_NAMES.iter().position(|name| *name == text) {
+        return Some(Reg::Gpr(n as u32));
+    }
+    if let Some(n) = FPR_NAMES.iter().position(|name| *name == text) {
+        return Some(Reg::Fpr(n as u32));
+    }
+    if let Some(n) = text.strip_prefix('x').and_then(number) {
+        return Some(Reg::Gpr(n));
+    }
+    if let Some(n) = text.strip_prefix('f').and_then(number) {
+        return Some(Reg::Fpr(n));
+    }
+    // `Insn::to_string` prefixes vector registers with their tag
+    for prefix in ["vs1", "vs2", "vs3", "vd", "v"] {
+        if let Some(n) = text.strip_prefix(prefix).and_then(number) {
+            return Some(Reg::Vr(n));
+        }
+    }
+    None
+}
+
+/// Helper: Parse one comma-separated operand
+fn parse_operand(text: &str) -> Result<Operand, String> {
+    if text == "v0.t" {
+        return Ok(Operand::Mask);
+    }
+    if let Some(csr) = text.strip_prefix("CSR#") {
+        return parse_number(csr)
+            .map(|val| Operand::Expr(Expr::constant(val)))
+            .ok_or(format!("bad CSR operand `{}`", text));
+    }
+    if let Some(reg) = parse_reg(text) {
+        return Ok(Operand::Reg(reg));
+    }
+    if let Some(open) = text.strip_suffix(')').and_then(|rest| rest.rfind('(')) {
+        if let Some(base) = parse_reg(text[open + 1..text.len() - 1].trim()) {
+            let offset = match text[..open].trim() {
+                "" => Expr::constant(0),
+                offset => parse_expr(offset).ok_or(format!("bad offset `{}`", offset))?,
+            };
+            return Ok(Operand::Mem { offset, base });
+        }
+    }
+    parse_expr(text)
+        .map(Operand::Expr)
+        .ok_or(format!("bad operand `{}`", text))
+}
+
+/// Helper: Split operands on the commas outside parentheses
+fn split_operands(text: &str) -> Vec<String> {
+    let mut operands = Vec::new();
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in text.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                operands.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.trim().is_empty() || !operands.is_empty() {
+        operands.push(current.trim().to_string());
+    }
+    operands
+}
+
+/// Helper: Sign-extend the low 12 bits
+fn sext12(val: i64) -> i64 {
+    (val << 52) >> 52
+}
+
+/// Helper: Split a value into `lui`/`auipc` and `addi` parts
+fn hi_lo(val: i64) -> (i64, i64) {
+    let lo = sext12(val);
+    ((val.wrapping_sub(lo) >> 12) & 0xfffff, lo)
+}
+
+/// Helper: Check if a value is a sign- or zero-extended 32-bit value
+fn fits_32(val: i64) -> bool {
+    val == val as i32 as i64 || val == val as u32 as i64
+}
+
+/// Helper: Build the `li` sequence for a constant: `lui`/`addi(w)` for 32-bit
+/// values, with `slli`/`addi` steps or a final `srli` for wider ones
+fn li_sequence(rd: &str, val: i64, xlen: Xlen) -> Vec<String> {
+    if val == val as i32 as i64 {
+        let (hi, lo) = hi_lo(val);
+        if hi == 0 {
+            return vec![format!("addi {}, zero, {}", rd, lo)];
+        }
+        let mut seq = vec![format!("lui {}, {}", rd, hi)];
+        if lo != 0 {
+            let addi = match xlen {
+                Xlen::XLEN32 => "addi",
+                Xlen::XLEN64 => "addiw",
+            };
+            seq.push(format!("{} {}, {}, {}", addi, rd, rd, lo));
+        }
+        return seq;
+    }
+    // build the upper bits, shift them into place and add the low 12
+    let lo = sext12(val);
+    let mut hi = val.wrapping_sub(lo);
+    let mut shift = hi.trailing_zeros();
+    hi >>= shift;
+    if shift > 12 && !(-2048..2048).contains(&hi) && (hi << 12) == (hi << 12) as i32 as i64 {
+        shift -= 12;
+        hi <<= 12;
+    }
+    let mut seq = li_sequence(rd, hi, xlen);
+    seq.push(format!("slli {}, {}, {}", rd, rd, shift));
+    if lo != 0 {
+        seq.push(format!("addi {}, {}, {}", rd, rd, lo));
+    }
+    // positive values may be shorter built shifted up and logically shifted down
+    let zeros = (val as u64).leading_zeros();
+    if seq.len() > 2 && zeros > 0 {
+        for fill in [(1 << zeros) - 1, 0] {
+            let mut alt = li_sequence(rd, ((val as u64) << zeros | fill) as i64, xlen);
+            alt.push(format!("srli {}, {}, {}", rd, rd, zeros));
+            if alt.len() < seq.len() {
+                seq = alt;
+            }
+        }
+    }
+    seq
+}
+
+/// Helper: Parse an `iorw` fence set
+fn fence_set(text: &str) -> Option<u32> {
+    if text == "0" {
+        return Some(0);
+    }
+    text.chars().try_fold(0, |set, c| match c {
+        'i' => Some(set | 8),
+        'o' => Some(set | 4),
+        'r' => Some(set | 2),
+        'w' => Some(set | 1),
+        _ => None,
+    })
+}
+
+/// Helper: Parse `e32, m1, ta, ma` into a vtype immediate
+fn vtype(parts: &[&str]) -> Option<u32> {
+    let mut vtype = 0;
+    for part in parts {
+        vtype |= match *part {
+            "e8" => 0 << 3,
+            "e16" => 1 << 3,
+            "e32" => 2 << 3,
+            "e64" => 3 << 3,
+            "m1" => 0,
+            "m2" => 1,
+            "m4" => 2,
+            "m8" => 3,
+            "mf8" => 5,
+            "mf4" => 6,
+            "mf2" => 7,
+            "ta" => 1 << 6,
+            "ma" => 1 << 7,
+            "tu" | "mu" => 0,
+            _ => return None,
+        };
+    }
+    Some(vtype)
+}
+
+/// Symbol values and PC-relative pairs seen so far during a pass
+struct Resolver<'a> {
+    named: &'a BTreeMap<String, u64>,
+    /// `1:`-style labels as (name, statement index, address)
+    numeric: &'a [(String, usize, u64)],
+    /// Statement being assembled, for `1b`/`1f`
+    index: usize,
+    /// Sizing pass: unknown symbols read as 0
+    sizing: bool,
+    /// Targets of `%pcrel_hi` keyed by the `auipc` address
+    pcrel: HashMap<u64, i64>,
+}
+
+impl Resolver<'_> {
+    fn symbol(&self, name: &str) -> Result<u64, String> {
+        let local = name
+            .strip_suffix('b')
+            .map(|n| (n, true))
+            .or(name.strip_suffix('f').map(|n| (n, false)))
+            .filter(|(n, _)| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()));
+        let found = match local {
+            Some((n, true)) => self
+                .numeric
+                .iter()
+                .rev()
+                .find(|(label, index, _)| label == n && *index <= self.index)
+                .map(|(_, _, addr)| *addr),
+            Some((n, false)) => self
+                .numeric
+                .iter()
+                .find(|(label, index, _)| label == n && *index > self.index)
+                .map(|(_, _, addr)| *addr),
+            None => self.named.get(name).copied(),
+        };
+        match found {
+            Some(addr) => Ok(addr),
+            None if self.sizing => Ok(0),
+            None => Err(format!("undefined symbol `{}`", name)),
+        }
+    }
+
+    /// Evaluate an expression for the instruction at `pc`
+    fn eval(&mut self, expr: &Expr, pc: u64) -> Result<i64, String> {
+        let base = match &expr.symbol {
+            Some(name) => self.symbol(name)? as i64,
+            None => 0,
+        };
+        let val = base.wrapping_add(expr.addend);
+        Ok(match expr.modifier {
+            Modifier::None => val,
+            Modifier::Hi | Modifier::Lo if !fits_32(val) => {
+                return Err(format!("{} does not fit in 32 bits", val))
+            }
+            Modifier::Hi => hi_lo(val).0,
+            Modifier::Lo => hi_lo(val).1,
+            Modifier::PcrelHi => {
+                self.pcrel.insert(pc, val);
+                hi_lo(val.wrapping_sub(pc as i64)).0
+            }
+            Modifier::PcrelLo => {
+                let auipc = val as u64;
+                match self.pcrel.get(&auipc) {
+                    Some(target) => hi_lo(target.wrapping_sub(auipc as i64)).1,
+                    None if self.sizing => 0,
+                    None => return Err(format!("no %pcrel_hi at 0x{:x}", auipc)),
+                }
+            }
+        })
+    }
+
+    /// Evaluate a branch or jump target as an offset from `pc`; plain
+    /// numbers are offsets already
+    fn offset(&mut self, expr: &Expr, pc: u64) -> Result<i64, String> {
+        let val = self.eval(expr, pc)?;
+        match (&expr.symbol, expr.modifier) {
+            (Some(_), Modifier::None) => Ok(val.wrapping_sub(pc as i64)),
+            _ => Ok(val),
+        }
+    }
+}
+
+/// An instruction name and its operand texts
+type Line = (String, Vec<String>);
+
+/// An operand tagged as the decoder tags it
+type Tagged = (String, Arg);
+
+/// One source statement after labels are split off
+struct Statement {
+    line: usize,
+    labels: Vec<String>,
+    op: Option<(String, Vec<String>)>,
+}
+
+/// Helper: Split source text into statements
+fn statements(text: &str) -> Vec<Statement> {
+    let mut statements = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        // `#` starts a comment, except in the `CSR#768` operands of `Insn::to_string`
+        let mut code = line;
+        let mut search = 0;
+        while let Some(pos) = code[search..].find('#') {
+            let pos = search + pos;
+            if code[..pos].ends_with("CSR") {
+                search = pos + 1;
+            } else {
+                code = &code[..pos];
+                break;
+            }
+        }
+        for part in code.split(';') {
+            let mut rest = part.trim();
+            let mut labels = Vec::new();
+            while let Some(colon) = rest.find(':') {
+                let label = rest[..colon].trim();
+                if !is_identifier(label) {
+                    break;
+                }
+                labels.push(label.to_string());
+                rest = rest[colon + 1..].trim();
+            }
+            let op = (!rest.is_empty()).then(|| {
+                let (name, operands) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+                (name.to_ascii_lowercase(), split_operands(operands))
+            });
+            if op.is_some() || !labels.is_empty() {
+                statements.push(Statement {
+                    line: i + 1,
+                    labels,
+                    op,
+                });
+            }
+        }
+    }
+    statements
+}
+
+/// A text assembler for snippets, turning source into machine code
+#[derive(Debug, Clone)]
+pub struct Assembler {
+    encoder: Encoder,
+    syntax: Syntax,
+    /// Symbols defined outside the snippet, e.g. functions it calls
+    symbols: BTreeMap<String, u64>,
+}
+
+impl Assembler {
+    pub fn new(xlen: Xlen) -> Self {
+        Self::with_syntax(xlen, Syntax::Gnu)
+    }
+
+    pub fn with_syntax(xlen: Xlen, syntax: Syntax) -> Self {
+        Self {
+            encoder: Encoder::new(xlen),
+            syntax,
+            symbols: BTreeMap::new(),
+        }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.encoder.xlen()
+    }
+
+    pub fn syntax(&self) -> Syntax {
+        self.syntax
+    }
+
+    /// Make an external symbol available to the snippet
+    pub fn define_symbol(&mut self, name: &str, addr: u64) {
+        self.symbols.insert(name.to_string(), addr);
+    }
+
+    /// Assemble a single instruction at address 0
+    pub fn assemble_one(&self, text: &str) -> Result<u32, AsmError> {
+        let program = self.assemble(text, 0)?;
+        match program.insns.as_slice() {
+            [(_, raw)] if program.bytes.len() == if is_compressed(*raw) { 2 } else { 4 } => {
+                Ok(*raw)
+            }
+            _ => Err(AsmError {
+                line: 1,
+                message: "expected exactly one instruction".to_string(),
+            }),
+        }
+    }
+
+    /// Assemble a snippet loaded at `base`, resolving its labels
+    pub fn assemble(&self, text: &str, base: u64) -> Result<Program, AsmError> {
+        let statements = statements(text);
+        // first pass sizes every statement to place the labels
+        let mut named = self.symbols.clone();
+        let mut numeric = Vec::new();
+        let mut pc = base;
+        let mut sizes = Vec::new();
+        for (index, statement) in statements.iter().enumerate() {
+            for label in &statement.labels {
+                if label.chars().all(|c| c.is_ascii_digit()) {
+                    numeric.push((label.clone(), index, pc));
+                } else if named.insert(label.clone(), pc).is_some() {
+                    return Err(AsmError {
+                        line: statement.line,
+                        message: format!("`{}` is defined twice", label),
+                    });
+                }
+            }
+            let mut sized = Program::default();
+            let mut resolver = Resolver {
+                named: &named,
+                numeric: &numeric,
+                index,
+                sizing: true,
+                pcrel: HashMap::new(),
+            };
+            self.statement(statement, pc, &mut resolver, &mut sized)
+                .map_err(|message| AsmError {
+                    line: statement.line,
+                    message,
+                })?;
+            if let Some((name, value)) = sized.labels.pop_first() {
+                named.insert(name, value);
+            }
+            sizes.push(sized.bytes.len());
+            pc += sized.bytes.len() as u64;
+        }
+
+        let mut program = Program {
+            base,
+            ..Default::default()
+        };
+        let mut resolver = Resolver {
+            named: &named,
+            numeric: &numeric,
+            index: 0,
+            sizing: false,
+            pcrel: HashMap::new(),
+        };
+        for (index, statement) in statements.iter().enumerate() {
+            resolver.index = index;
+            let start = program.bytes.len();
+            let pc = base + start as u64;
+            self.statement(statement, pc, &mut resolver, &mut program)
+                .map_err(|message| AsmError {
+                    line: statement.line,
+                    message,
+                })?;
+            // labels were placed with the first pass sizes
+            if program.bytes.len() - start != sizes[index] {
+                return Err(AsmError {
+                    line: statement.line,
+                    message: "size depends on a symbol defined later".to_string(),
+                });
+            }
+        }
+        program.labels = named
+            .into_iter()
+            .filter(|(name, _)| !self.symbols.contains_key(name))
+            .collect();
+        Ok(program)
+    }
+
+    /// Helper: Emit one statement into `program`. In the sizing pass `.equ`
+    /// definitions are handed back through `program.labels`
+    fn statement(
+        &self,
+        statement: &Statement,
+        pc: u64,
+        resolver: &mut Resolver,
+        program: &mut Program,
+    ) -> Result<(), String> {
+        let Some((name, operands)) = &statement.op else {
+            return Ok(());
+        };
+        if name.starts_with('.') {
+            return self.directive(name, operands, pc, resolver, program);
+        }
+        // pseudo-instructions expand to GNU syntax
+        let (lines, syntax) = match self.pseudo(name, operands, pc, resolver)? {
+            Some(lines) => (lines, Syntax::Gnu),
+            None => (vec![(name.clone(), operands.clone())], self.syntax),
+        };
+        let mut at = pc;
+        for (name, operands) in lines {
+            let (spec, args) = self.instruction(&name, &operands, at, resolver, syntax)?;
+            let raw = if resolver.sizing {
+                spec.match_bits
+            } else {
+                let args: Vec<(&str, Arg)> =
+                    args.iter().map(|(tag, arg)| (tag.as_str(), *arg)).collect();
+                self.encoder
+                    .encode(spec.mnemonic, &args)
+                    .map_err(|err| format!("{}: {}", spec.mnemonic, err))?
+            };
+            at += emit(program, at, raw);
+        }
+        Ok(())
+    }
+
+    /// Helper: Handle an assembler directive
+    fn directive(
+        &self,
+        name: &str,
+        operands: &[String],
+        pc: u64,
+        resolver: &mut Resolver,
+        program: &mut Program,
+    ) -> Result<(), String> {
+        let width = match name {
+            ".byte" => 1,
+            ".half" | ".short" | ".2byte" => 2,
+            ".word" | ".long" | ".4byte" => 4,
+            ".dword" | ".quad" | ".8byte" => 8,
+            _ => 0,
+        };
+        match name {
+            _ if width > 0 => {
+                for operand in operands {
+                    let expr = parse_expr(operand).ok_or(format!("bad value `{}`", operand))?;
+                    let val = resolver.eval(&expr, pc)?;
+                    program.bytes.extend_from_slice(&val.to_le_bytes()[..width]);
+                }
+            }
+            ".align" | ".p2align" | ".balign" => {
+                let n = operands
+                    .first()
+                    .and_then(|operand| parse_number(operand))
+                    .ok_or(format!("{} needs an alignment", name))?;
+                let align = match name {
+                    ".balign" => n as u64,
+                    _ if (0..32).contains(&n) => 1 << n,
+                    _ => 0,
+                };
+                if !align.is_power_of_two() {
+                    return Err(format!("bad alignment `{}`", n));
+                }
+                let mut at = pc;
+                while !at.is_multiple_of(align) {
+                    at += match at % 4 {
+                        1 | 3 => {
+                            program.bytes.push(0);
+                            1
+                        }
+                        2 => emit(program, at, C_NOP),
+                        _ => emit(program, at, NOP),
+                    };
+                }
+            }
+            ".equ" | ".set" => {
+                let [symbol, value] = operands else {
+                    return Err(format!("{} expects a name and a value", name));
+                };
+                let expr = parse_expr(value).ok_or(format!("bad value `{}`", value))?;
+                let val = resolver.eval(&expr, pc)?;
+                if resolver.sizing {
+                    program.labels.insert(symbol.clone(), val as u64);
+                }
+            }
+            ".text" | ".globl" | ".global" | ".local" | ".option" | ".type" | ".size"
+            | ".section" | ".file" | ".attribute" => {}
+            _ => return Err(format!("unknown directive `{}`", name)),
+        }
+        Ok(())
+    }
+
+    /// Helper: Expand a pseudo-instruction into GNU instructions, or `None` if
+    /// `name` is not one
+    fn pseudo(
+        &self,
+        name: &str,
+        operands: &[String],
+        pc: u64,
+        resolver: &mut Resolver,
+    ) -> Result<Option<Vec<Line>>, String> {
+        let ops: Vec<&str> = operands.iter().map(String::as_str).collect();
+        let expr = |text: &str| parse_expr(text).ok_or(format!("bad operand `{}`", text));
+        // `auipc` plus a low part, for a target that must be within 2 GiB
+        let mut pcrel = |text: &str| -> Result<(i64, i64), String> {
+            let offset = resolver.offset(&expr(text)?, pc)?;
+            let reach = offset
+                .checked_add(0x800)
+                .and_then(|val| i32::try_from(val).ok());
+            if reach.is_none() {
+                return Err(format!("`{}` is out of range of auipc", text));
+            }
+            Ok(hi_lo(offset))
+        };
+        let text = match (name, ops.as_slice()) {
+            ("nop", []) => vec!["addi zero, zero, 0".to_string()],
+            ("li", [rd, val]) => {
+                let val = resolver.eval(&expr(val)?, pc)?;
+                let val = match self.xlen() {
+                    Xlen::XLEN32 if fits_32(val) => val as i32 as i64,
+                    Xlen::XLEN32 => return Err(format!("{} does not fit in 32 bits", val)),
+                    Xlen::XLEN64 => val,
+                };
+                li_sequence(rd, val, self.xlen())
+            }
+            ("la" | "lla", [rd, symbol]) => {
+                let (hi, lo) = pcrel(symbol)?;
+                vec![
+                    format!("auipc {}, {}", rd, hi),
+                    format!("addi {}, {}, {}", rd, rd, lo),
+                ]
+            }
+            ("call", [symbol]) => {
+                let (hi, lo) = pcrel(symbol)?;
+                vec![format!("auipc ra, {}", hi), format!("jalr ra, {}(ra)", lo)]
+            }
+            ("tail", [symbol]) => {
+                let (hi, lo) = pcrel(symbol)?;
+                vec![
+                    format!("auipc t1, {}", hi),
+                    format!("jalr zero, {}(t1)", lo),
+                ]
+            }
+            ("mv", [rd, rs]) => vec![format!("addi {}, {}, 0", rd, rs)],
+            ("not", [rd, rs]) => vec![format!("xori {}, {}, -1", rd, rs)],
+            ("neg", [rd, rs]) => vec![format!("sub {}, zero, {}", rd, rs)],
+            ("negw", [rd, rs]) => vec![format!("subw {}, zero, {}", rd, rs)],
+            ("sext.w", [rd, rs]) => vec![format!("addiw {}, {}, 0", rd, rs)],
+            ("zext.b", [rd, rs]) => vec![format!("andi {}, {}, 255", rd, rs)],
+            ("seqz", [rd, rs]) => vec![format!("sltiu {}, {}, 1", rd, rs)],
+            ("snez", [rd, rs]) => vec![format!("sltu {}, zero, {}", rd, rs)],
+            ("sltz", [rd, rs]) => vec![format!("slt {}, {}, zero", rd, rs)],
+            ("sgtz", [rd, rs]) => vec![format!("slt {}, zero, {}", rd, rs)],
+            ("beqz", [rs, off]) => vec![format!("beq {}, zero, {}", rs, off)],
+            ("bnez", [rs, off]) => vec![format!("bne {}, zero, {}", rs, off)],
+            ("blez", [rs, off]) => vec![format!("bge zero, {}, {}", rs, off)],
+            ("bgez", [rs, off]) => vec![format!("bge {}, zero, {}", rs, off)],
+            ("bltz", [rs, off]) => vec![format!("blt {}, zero, {}", rs, off)],
+            ("bgtz", [rs, off]) => vec![format!("blt zero, {}, {}", rs, off)],
+            ("bgt", [a, b, off]) => vec![format!("blt {}, {}, {}", b, a, off)],
+            ("ble", [a, b, off]) => vec![format!("bge {}, {}, {}", b, a, off)],
+            ("bgtu", [a, b, off]) => vec![format!("bltu {}, {}, {}", b, a, off)],
+            ("bleu", [a, b, off]) => vec![format!("bgeu {}, {}, {}", b, a, off)],
+            ("j", [off]) => vec![format!("jal zero, {}", off)],
+            ("jr", [rs]) => vec![format!("jalr zero, 0({})", rs)],
+            ("ret", []) => vec!["jalr zero, 0(ra)".to_string()],
+            ("csrr", [rd, csr]) => vec![format!("csrrs {}, {}, zero", rd, csr)],
+            ("csrw", [csr, rs]) => vec![format!("csrrw zero, {}, {}", csr, rs)],
+            ("csrs", [csr, rs]) => vec![format!("csrrs zero, {}, {}", csr, rs)],
+            ("csrc", [csr, rs]) => vec![format!("csrrc zero, {}, {}", csr, rs)],
+            ("csrwi", [csr, imm]) => vec![format!("csrrwi zero, {}, {}", csr, imm)],
+            ("csrsi", [csr, imm]) => vec![format!("csrrsi zero, {}, {}", csr, imm)],
+            ("csrci", [csr, imm]) => vec![format!("csrrci zero, {}, {}", csr, imm)],
+            ("rdcycle" | "rdtime" | "rdinstret", [rd]) => {
+                vec![format!("csrrs {}, {}, zero", rd, &name[2..])]
+            }
+            ("fmv.s" | "fmv.d" | "fabs.s" | "fabs.d" | "fneg.s" | "fneg.d", [rd, rs]) => {
+                let (op, width) = name.split_once('.').unwrap_or_default();
+                let sgnj = match op {
+                    "fmv" => "fsgnj",
+                    "fabs" => "fsgnjx",
+                    _ => "fsgnjn",
+                };
+                vec![format!("{}.{} {}, {}, {}", sgnj, width, rd, rs, rs)]
+            }
+            _ if PSEUDOS.contains(&name) => {
+                return Err(format!("wrong number of operands for {}", name))
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(
+            text.iter()
+                .map(|line| {
+                    let (name, operands) = line.split_once(' ').unwrap_or((line, ""));
+                    (name.to_string(), split_operands(operands))
+                })
+                .collect(),
+        ))
+    }
+
+    /// Helper: Parse one instruction into its spec and tagged operands
+    fn instruction(
+        &self,
+        name: &str,
+        operands: &[String],
+        pc: u64,
+        resolver: &mut Resolver,
+        syntax: Syntax,
+    ) -> Result<(&'static Spec, Vec<Tagged>), String> {
+        let mut args: BTreeMap<String, Arg> = BTreeMap::new();
+        let mut name = name;
+        if syntax == Syntax::Gnu {
+            if name == "fence.tso" {
+                // `fence rw, rw` with fm = 0b1000
+                name = "fence";
+                args.insert("fm".to_string(), Arg::Flag(8));
+                args.insert("pred".to_string(), Arg::Flag(3));
+                args.insert("succ".to_string(), Arg::Flag(3));
+            }
+            for (suffix, aq, rl) in [(".aqrl", 1, 1), (".aq", 1, 0), (".rl", 0, 1)] {
+                let atomic = |base: &&str| {
+                    base.starts_with("lr.") || base.starts_with("sc.") || base.starts_with("amo")
+                };
+                if let Some(base) = name.strip_suffix(suffix).filter(atomic) {
+                    name = base;
+                    args.insert("aq".to_string(), Arg::Flag(aq));
+                    args.insert("rl".to_string(), Arg::Flag(rl));
+                    break;
+                }
+            }
+        }
+        let mnemonic =
+            Mnemonic::from_str(name).map_err(|_| format!("unknown instruction `{}`", name))?;
+        let spec = specs(self.xlen())
+            .find(|spec| spec.mnemonic == mnemonic)
+            .ok_or(format!("{} is not available at this XLEN", mnemonic))?;
+        let fields = spec_operands(spec);
+        for (tag, arg) in &fields {
+            let default = match (tag.as_str(), mnemonic) {
+                // exact conversions round to nearest, like GNU `as`
+                ("rm", Mnemonic::FcvtDS | Mnemonic::FcvtDW | Mnemonic::FcvtDWu) => Arg::Flag(0),
+                ("rm", _) => Arg::Flag(7),
+                ("vm", _) => Arg::Flag(1),
+                ("pred" | "succ", _) => Arg::Flag(15),
+                (_, _) if matches!(arg, Arg::Flag(_)) => Arg::Flag(0),
+                ("rd", Mnemonic::Jal | Mnemonic::Jalr) => Arg::DstReg(1),
+                ("imm", Mnemonic::Jalr | Mnemonic::CNop) => Arg::Imm(0),
+                ("rd", Mnemonic::Fence) => Arg::DstReg(0),
+                ("rs1", Mnemonic::Fence) => Arg::SrcReg(0),
+                _ => continue,
+            };
+            args.entry(tag.clone()).or_insert(default);
+        }
+
+        let operands = operands
+            .iter()
+            .map(|operand| parse_operand(operand))
+            .collect::<Result<Vec<_>, _>>()?;
+        let slots = match syntax {
+            Syntax::Gnu => gnu_slots(spec, &fields, &operands),
+            Syntax::Display => display_slots(&fields),
+        };
+        let kind = |tag: &str| {
+            fields
+                .iter()
+                .find(|(field, _)| field == tag)
+                .map(|(_, arg)| *arg)
+                .unwrap_or(Arg::Nothing)
+        };
+        let bad = |operand: &Operand| format!("{}: bad operand `{}`", mnemonic, operand);
+        let mut rest = operands.iter();
+        for slot in &slots {
+            let optional = matches!(slot, Slot::Rm | Slot::Mask | Slot::V0);
+            let Some(operand) = rest.next() else {
+                if optional {
+                    break;
+                }
+                return Err(format!("{}: expected more operands", mnemonic));
+            };
+            match (slot, operand) {
+                (Slot::Tag(tag), _) => {
+                    let arg =
+                        operand_value(mnemonic, tag, kind(tag), operand, pc, resolver, syntax)?;
+                    args.insert(tag.clone(), arg);
+                }
+                (
+                    Slot::Mem { offset, base },
+                    Operand::Mem {
+                        offset: expr,
+                        base: reg,
+                    },
+                ) => {
+                    match offset {
+                        Some(tag) => {
+                            let arg = operand_value(
+                                mnemonic,
+                                tag,
+                                kind(tag),
+                                &Operand::Expr(expr.clone()),
+                                pc,
+                                resolver,
+                                syntax,
+                            )?;
+                            args.insert(tag.clone(), arg);
+                        }
+                        None if resolver.eval(expr, pc)? != 0 => {
+                            return Err(format!("{}: takes no offset", mnemonic))
+                        }
+                        None => {}
+                    }
+                    match base {
+                        Some(tag) => {
+                            let arg = operand_value(
+                                mnemonic,
+                                tag,
+                                kind(tag),
+                                &Operand::Reg(*reg),
+                                pc,
+                                resolver,
+                                syntax,
+                            )?;
+                            args.insert(tag.clone(), arg);
+                        }
+                        None if *reg != Reg::Gpr(2) => {
+                            return Err(format!("{}: base must be sp", mnemonic))
+                        }
+                        None => {}
+                    }
+                }
+                (Slot::Sp, Operand::Reg(Reg::Gpr(2))) | (Slot::V0, Operand::Reg(Reg::Vr(0))) => {}
+                (Slot::Mask, Operand::Mask) => {
+                    args.insert("vm".to_string(), Arg::Flag(0));
+                }
+                (Slot::Rm, Operand::Expr(expr)) => {
+                    let rm = ROUNDING_MODES
+                        .iter()
+                        .find(|(name, _)| Some(*name) == expr.name())
+                        .ok_or(bad(operand))?;
+                    args.insert("rm".to_string(), Arg::Flag(rm.1));
+                }
+                (Slot::FenceSet(tag), Operand::Expr(expr)) => {
+                    let set = match (expr.name(), expr.symbol.is_none() && expr.addend == 0) {
+                        (_, true) => Some(0),
+                        (Some(name), _) => fence_set(name),
+                        _ => None,
+                    };
+                    args.insert(tag.clone(), Arg::Flag(set.ok_or(bad(operand))?));
+                }
+                (Slot::Vtype(tag), _) => {
+                    // the vtype list takes the remaining operands
+                    let parts: Vec<&Operand> =
+                        std::iter::once(operand).chain(rest.by_ref()).collect();
+                    let val = match parts.as_slice() {
+                        [Operand::Expr(expr)] if expr.symbol.is_none() => expr.addend as u32,
+                        _ => {
+                            let names = parts
+                                .iter()
+                                .map(|part| match part {
+                                    Operand::Expr(expr) => expr.name(),
+                                    _ => None,
+                                })
+                                .collect::<Option<Vec<&str>>>();
+                            names.and_then(|names| vtype(&names)).ok_or(bad(operand))?
+                        }
+                    };
+                    let arg = match kind(tag) {
+                        Arg::Flag(_) => Arg::Flag(val),
+                        _ => Arg::UImm(val),
+                    };
+                    args.insert(tag.clone(), arg);
+                }
+                _ => return Err(bad(operand)),
+            }
+        }
+        if rest.next().is_some() {
+            return Err(format!("{}: too many operands", mnemonic));
+        }
+        Ok((spec, args.into_iter().collect()))
+    }
+}
+
+/// Helper: Convert an operand for the field tagged `tag`
+fn operand_value(
+    mnemonic: Mnemonic,
+    tag: &str,
+    kind: Arg,
+    operand: &Operand,
+    pc: u64,
+    resolver: &mut Resolver,
+    syntax: Syntax,
+) -> Result<Arg, String> {
+    let out_of_range = |val: i64| format!("{}: {} = {} is out of range", mnemonic, tag, val);
+    match (kind, operand) {
+        (Arg::DstReg(_) | Arg::SrcReg(_), Operand::Reg(reg)) => {
+            let (Reg::Gpr(n) | Reg::Fpr(n) | Reg::Vr(n) | Reg::Csr(n)) = *reg;
+            if syntax == Syntax::Gnu && operand_reg(mnemonic, tag, n) != *reg {
+                return Err(format!("{}: {} is not a valid {}", mnemonic, reg, tag));
+            }
+            Ok(match kind {
+                Arg::DstReg(_) => Arg::DstReg(n),
+                _ => Arg::SrcReg(n),
+            })
+        }
+        (Arg::Imm(_) | Arg::UImm(_), Operand::Expr(expr)) => {
+            let pc_relative = mnemonic.category() == Category::Branch
+                || matches!(mnemonic, Mnemonic::Jal | Mnemonic::CJ | Mnemonic::CJal);
+            let mut val = if pc_relative {
+                resolver.offset(expr, pc)?
+            } else {
+                resolver.eval(expr, pc)?
+            };
+            // GNU writes the 20 bits of `lui`, the decoder the shifted value
+            if syntax == Syntax::Gnu
+                && matches!(mnemonic, Mnemonic::Lui | Mnemonic::Auipc | Mnemonic::CLui)
+            {
+                if !(0..1 << 20).contains(&val) {
+                    return Err(out_of_range(val));
+                }
+                val = (val << 12) as u32 as i32 as i64;
+            }
+            let op = mnemonic.as_str().split('.').next().unwrap_or("");
+            if syntax == Syntax::Gnu && VECTOR_UIMMS.contains(&op) {
+                if !(0..32).contains(&val) {
+                    return Err(out_of_range(val));
+                }
+                val = (val << 59) >> 59;
+            }
+            match kind {
+                Arg::Imm(_) => i32::try_from(val).map(Arg::Imm),
+                _ => u32::try_from(val).map(Arg::UImm),
+            }
+            .map_err(|_| out_of_range(val))
+        }
+        (Arg::CSR(_), Operand::Expr(expr)) => {
+            let csr = match expr.name().and_then(csr_address) {
+                Some(csr) => csr as i64,
+                None => resolver.eval(expr, pc)?,
+            };
+            u32::try_from(csr)
+                .map(Arg::CSR)
+                .map_err(|_| out_of_range(csr))
+        }
+        _ => Err(format!(
+            "{}: `{}` is not a valid {}",
+            mnemonic, operand, tag
+        )),
+    }
+}
+
+/// Helper: Append an encoded instruction, returning its size
+fn emit(program: &mut Program, at: u64, raw: u32) -> u64 {
+    program.insns.push((at, raw));
+    if is_compressed(raw) {
+        program.bytes.extend_from_slice(&(raw as u16).to_le_bytes());
+        2
+    } else {
+        program.bytes.extend_from_slice(&raw.to_le_bytes());
+        4
+    }
+}
+
+/// Helper: Lay out operands as `Insn::to_string` prints them: destination,
+/// sources by tag, immediate, CSR
+fn display_slots(fields: &[(String, Arg)]) -> Vec<Slot> {
+    let tagged = |kind: fn(&Arg) -> bool| {
+        let mut tags: Vec<&String> = fields
+            .iter()
+            .filter(|(_, arg)| kind(arg))
+            .map(|(tag, _)| tag)
+            .collect();
+        tags.sort();
+        tags.into_iter().map(|tag| Slot::Tag(tag.clone()))
+    };
+    let mut slots: Vec<Slot> = tagged(|arg| matches!(arg, Arg::DstReg(_))).collect();
+    slots.extend(tagged(|arg| matches!(arg, Arg::SrcReg(_))));
+    slots.extend(tagged(|arg| matches!(arg, Arg::Imm(_) | Arg::UImm(_))));
+    slots.extend(tagged(|arg| matches!(arg, Arg::CSR(_))));
+    slots
+}
+
+/// Helper: Lay out operands in GNU order
+fn gnu_slots(spec: &Spec, fields: &[(String, Arg)], operands: &[Operand]) -> Vec<Slot> {
+    let has = |tag: &str| fields.iter().any(|(field, _)| field == tag);
+    let tag = |tag: &str| Slot::Tag(tag.to_string());
+    let mem = |offset: Option<&str>, base: Option<&str>| Slot::Mem {
+        offset: offset.map(str::to_string),
+        base: base.map(str::to_string),
+    };
+    let dst: Vec<&str> = fields
+        .iter()
+        .filter(|(_, arg)| matches!(arg, Arg::DstReg(_)))
+        .map(|(tag, _)| tag.as_str())
+        .collect();
+    let mut src: Vec<&str> = fields
+        .iter()
+        .filter(|(_, arg)| matches!(arg, Arg::SrcReg(_)))
+        .map(|(tag, _)| tag.as_str())
+        .collect();
+    src.sort();
+    let mnemonic = spec.mnemonic;
+    let name = mnemonic.as_str();
+    let category = mnemonic.category();
+    let compressed = is_compressed(spec.match_bits);
+
+    match mnemonic {
+        Mnemonic::CNop if operands.is_empty() => return vec![],
+        Mnemonic::CAddi16sp => return vec![Slot::Sp, tag("imm")],
+        Mnemonic::CAddi4spn => return vec![tag("rd"), Slot::Sp, tag("imm")],
+        Mnemonic::Jal if operands.len() == 1 => return vec![tag("imm")],
+        Mnemonic::Jalr => {
+            return match operands {
+                [_] => vec![tag("rs1")],
+                [_, Operand::Mem { .. }] => vec![tag("rd"), mem(Some("imm"), Some("rs1"))],
+                [_, _] => vec![tag("rd"), tag("rs1")],
+                _ => vec![tag("rd"), tag("rs1"), tag("imm")],
+            }
+        }
+        Mnemonic::Fence if operands.is_empty() => return vec![],
+        Mnemonic::Fence => {
+            return vec![
+                Slot::FenceSet("pred".to_string()),
+                Slot::FenceSet("succ".to_string()),
+            ]
+        }
+        Mnemonic::Vsetvli => return vec![tag("rd"), tag("rs1"), Slot::Vtype("imm".to_string())],
+        Mnemonic::Vsetivli => {
+            return vec![tag("rd"), tag("imm"), Slot::Vtype("vtypei".to_string())]
+        }
+        _ => {}
+    }
+
+    if mnemonic.extension() == Extension::RvV {
+        let mut slots: Vec<Slot> = dst.iter().map(|dst| tag(dst)).collect();
+        if matches!(category, Category::Load | Category::Store) {
+            // `vse32.v vs3, (rs1), vs2/rs2, v0.t`
+            slots.extend(src.iter().filter(|src| **src == "vs3").map(|src| tag(src)));
+            slots.push(mem(None, Some("rs1")));
+            slots.extend(["rs2", "vs2"].into_iter().filter(|src| has(src)).map(tag));
+        } else {
+            let vs2 = src.iter().filter(|src| **src == "vs2").map(|src| tag(src));
+            let other: Vec<Slot> = src
+                .iter()
+                .filter(|src| **src != "vs2")
+                .map(|src| tag(src))
+                .chain(has("imm").then(|| tag("imm")))
+                .collect();
+            let op = name.split('.').next().unwrap_or(name);
+            if VECTOR_MADDS.contains(&op) {
+                slots.extend(other);
+                slots.extend(vs2);
+            } else {
+                slots.extend(vs2);
+                slots.extend(other);
+            }
+            if uses_v0_implicitly(name) {
+                slots.push(Slot::V0);
+            }
+        }
+        if has("vm") {
+            slots.push(Slot::Mask);
+        }
+        return slots;
+    }
+
+    match category {
+        // `c.lwsp` and friends address off `sp`
+        Category::Load | Category::Store if compressed && !has("rs1") => {
+            let value = dst.first().or(src.first()).copied().unwrap_or("rd");
+            vec![tag(value), mem(Some("imm"), None)]
+        }
+        Category::Load => vec![tag(dst[0]), mem(Some("imm"), Some("rs1"))],
+        Category::Store => {
+            let value = src
+                .iter()
+                .find(|src| **src != "rs1")
+                .copied()
+                .unwrap_or("rs2");
+            vec![tag(value), mem(Some("imm"), Some("rs1"))]
+        }
+        Category::Atomic => {
+            let mut slots = vec![tag("rd")];
+            if has("rs2") {
+                slots.push(tag("rs2"));
+            }
+            slots.push(mem(None, Some("rs1")));
+            slots
+        }
+        Category::Csr => vec![
+            tag("rd"),
+            tag("csr"),
+            tag(if has("rs1") { "rs1" } else { "imm" }),
+        ],
+        _ => {
+            // compressed forms write `rd` once for `rd == rs1`
+            if compressed && has("rd") {
+                src.retain(|src| *src != "rs1");
+            }
+            let mut slots: Vec<Slot> = dst.iter().chain(&src).map(|field| tag(field)).collect();
+            slots.extend(
+                ["imm", "csr"]
+                    .into_iter()
+                    .filter(|field| has(field))
+                    .map(tag),
+            );
+            if has("rm") {
+                slots.push(Slot::Rm);
+            }
+            slots
+        }
+    }
+}
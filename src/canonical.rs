@@ -0,0 +1,179 @@
+use crate::args::Arg;
+use crate::disassembler::{Disassembler, Xlen};
+use crate::encoder::{spec_operands, specs, EncodeError, Encoder};
+use crate::insn::{tag_to_string, Insn};
+use crate::mnemonic::Mnemonic;
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a canonical line could not be read back
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanonicalError {
+    /// The line does not follow the `name TAG value ...` layout
+    Malformed(String),
+    UnknownMnemonic(String),
+    /// The operands do not form a valid instruction
+    Encode(EncodeError),
+    /// The operands encode an instruction that decodes as another mnemonic
+    Mismatch {
+        parsed: Mnemonic,
+        decoded: Mnemonic,
+    },
+}
+
+impl fmt::Display for CanonicalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanonicalError::Malformed(line) => write!(f, "malformed canonical line `{}`", line),
+            CanonicalError::UnknownMnemonic(name) => write!(f, "unknown mnemonic `{}`", name),
+            CanonicalError::Encode(err) => write!(f, "{}", err),
+            CanonicalError::Mismatch { parsed, decoded } => {
+                write!(f, "`{}` decodes as `{}`", parsed, decoded)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CanonicalError {}
+
+impl From<EncodeError> for CanonicalError {
+    fn from(err: EncodeError) -> Self {
+        CanonicalError::Encode(err)
+    }
+}
+
+/// One `START INST ... TIMESTAMP n END` trace record
+#[derive(Debug, Clone)]
+pub struct TraceRecord {
+    pub insn: Insn,
+    pub timestamp: u64,
+}
+
+impl fmt::Display for TraceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "START INST {} TIMESTAMP {} END",
+            self.insn.to_canonical_with_flags(),
+            self.timestamp
+        )
+    }
+}
+
+/// Reads the `Insn::to_canonical_with_flags` form back into instructions,
+/// or the `Insn::to_canonical` form with neutral flags
+#[derive(Debug, Clone)]
+pub struct CanonicalParser {
+    encoder: Encoder,
+    disassembler: Disassembler,
+}
+
+impl CanonicalParser {
+    pub fn new(xlen: Xlen) -> Self {
+        Self {
+            encoder: Encoder::new(xlen),
+            disassembler: Disassembler::new(xlen),
+        }
+    }
+
+    /// Parse a line like `addi RD x5 RS1 x0 IMM 0` by re-encoding it and
+    /// decoding the result. Flags missing from older traces take their
+    /// neutral values: `RM 7` (dynamic), `VM 1` (unmasked), fence sets
+    /// `PRED 15 SUCC 15`, otherwise 0
+    pub fn parse(&self, line: &str) -> Result<Insn, CanonicalError> {
+        let malformed = || CanonicalError::Malformed(line.to_string());
+        let mut tokens = line.split_whitespace();
+        let name = tokens.next().ok_or_else(malformed)?;
+        let mnemonic = Mnemonic::from_str(name)
+            .map_err(|_| CanonicalError::UnknownMnemonic(name.to_string()))?;
+        let fields = specs(self.encoder.xlen())
+            .find(|spec| spec.mnemonic == mnemonic)
+            .map(spec_operands)
+            .ok_or(EncodeError::UnknownMnemonic(mnemonic))?;
+
+        let mut operands: Vec<(String, Arg)> = Vec::new();
+        let rest: Vec<&str> = tokens.collect();
+        for pair in rest.chunks(2) {
+            let [key, value] = pair else {
+                return Err(malformed());
+            };
+            let tag = key.to_lowercase();
+            let kind = fields
+                .iter()
+                .find(|(field, _)| *field == tag)
+                .map(|(_, arg)| *arg)
+                .ok_or_else(|| EncodeError::UnexpectedOperand(tag.clone()))?;
+            // registers carry the prefix `Insn::to_canonical` gave them
+            let digits = match kind {
+                Arg::DstReg(_) | Arg::SrcReg(_) => value.strip_prefix(tag_to_string(&tag).as_str()),
+                _ => Some(*value),
+            };
+            let val: i64 = digits
+                .and_then(|digits| digits.parse().ok())
+                .filter(|val| i32::try_from(*val).is_ok() || u32::try_from(*val).is_ok())
+                .ok_or_else(malformed)?;
+            let arg = match kind {
+                Arg::DstReg(_) => Arg::DstReg(val as u32),
+                Arg::SrcReg(_) => Arg::SrcReg(val as u32),
+                Arg::Flag(_) => Arg::Flag(val as u32),
+                Arg::CSR(_) => Arg::CSR(val as u32),
+                Arg::Imm(_) => Arg::Imm(val as i32),
+                _ => Arg::UImm(val as u32),
+            };
+            operands.push((tag, arg));
+        }
+        for (tag, arg) in &fields {
+            if matches!(arg, Arg::Flag(_)) && !operands.iter().any(|(field, _)| field == tag) {
+                let default = match tag.as_str() {
+                    "rm" => 7,
+                    "vm" => 1,
+                    "pred" | "succ" => 15,
+                    _ => 0,
+                };
+                operands.push((tag.clone(), Arg::Flag(default)));
+            }
+        }
+
+        let operands: Vec<(&str, Arg)> = operands
+            .iter()
+            .map(|(tag, arg)| (tag.as_str(), *arg))
+            .collect();
+        let raw = self.encoder.encode(mnemonic, &operands)?;
+        let insn = self
+            .disassembler
+            .disassmeble_one(raw)
+            .ok_or(CanonicalError::Encode(EncodeError::UnknownMnemonic(
+                mnemonic,
+            )))?;
+        if insn.mnemonic != mnemonic {
+            return Err(CanonicalError::Mismatch {
+                parsed: mnemonic,
+                decoded: insn.mnemonic,
+            });
+        }
+        Ok(insn)
+    }
+
+    /// Parse a `START INST <canonical> TIMESTAMP <n> END` record
+    pub fn parse_record(&self, line: &str) -> Result<TraceRecord, CanonicalError> {
+        let malformed = || CanonicalError::Malformed(line.to_string());
+        let body = line
+            .trim()
+            .strip_prefix("START INST ")
+            .and_then(|body| body.strip_suffix(" END"))
+            .ok_or_else(malformed)?;
+        let (canonical, timestamp) = body.rsplit_once(" TIMESTAMP ").ok_or_else(malformed)?;
+        Ok(TraceRecord {
+            insn: self.parse(canonical)?,
+            timestamp: timestamp.trim().parse().map_err(|_| malformed())?,
+        })
+    }
+
+    /// Parse every record of a trace, skipping blank lines
+    pub fn parse_trace(&self, text: &str) -> Result<Vec<TraceRecord>, CanonicalError> {
+        text.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| self.parse_record(line))
+            .collect()
+    }
+}
@@ -0,0 +1,424 @@
+use crate::control_flow::{FlowKind, Target};
+use crate::disassembler::Xlen;
+use crate::insn::Insn;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum EdgeKind {
+    /// Branch taken or unconditional jump
+    Taken,
+    /// Sequential execution, including the return site of a call
+    FallThrough,
+    /// Call site to callee entry
+    Call,
+    /// Returning block to the return site of a caller
+    Return,
+}
+
+impl EdgeKind {
+    /// Helper: Check if the edge stays within a function
+    pub fn is_intraprocedural(&self) -> bool {
+        matches!(self, EdgeKind::Taken | EdgeKind::FallThrough)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Edge {
+    pub from: u64,
+    pub to: u64,
+    pub kind: EdgeKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct BasicBlock {
+    /// Address of the first instruction
+    pub start: u64,
+    /// Address just past the last instruction
+    pub end: u64,
+    pub insns: Vec<(u64, Insn)>,
+    pub successors: Vec<Edge>,
+    pub predecessors: Vec<Edge>,
+}
+
+impl BasicBlock {
+    /// Helper: Get the last instruction of the block
+    pub fn terminator(&self) -> &(u64, Insn) {
+        self.insns.last().unwrap()
+    }
+}
+
+/// A control-flow graph of basic blocks keyed by start address
+#[derive(Debug, Clone)]
+pub struct Cfg {
+    pub xlen: Xlen,
+    blocks: BTreeMap<u64, BasicBlock>,
+}
+
+/// Immediate dominators of the blocks reachable from an entry block
+#[derive(Debug, Clone)]
+pub struct DominatorTree {
+    pub entry: u64,
+    idom: HashMap<u64, u64>,
+}
+
+impl DominatorTree {
+    /// Get the immediate dominator of a block, `None` for the entry
+    /// and for unreachable blocks
+    pub fn idom(&self, block: u64) -> Option<u64> {
+        if block == self.entry {
+            return None;
+        }
+        self.idom.get(&block).copied()
+    }
+
+    /// Check if `a` dominates `b`
+    pub fn dominates(&self, a: u64, b: u64) -> bool {
+        if !self.idom.contains_key(&b) {
+            return false;
+        }
+        let mut node = b;
+        loop {
+            if node == a {
+                return true;
+            }
+            match self.idom(node) {
+                Some(parent) => node = parent,
+                None => return false,
+            }
+        }
+    }
+
+    /// Get the blocks immediately dominated by a block
+    pub fn children(&self, block: u64) -> Vec<u64> {
+        let mut children: Vec<u64> = self
+            .idom
+            .iter()
+            .filter(|(&node, &parent)| parent == block && node != self.entry)
+            .map(|(&node, _)| node)
+            .collect();
+        children.sort();
+        children
+    }
+}
+
+/// A natural loop formed by one or more back edges into `header`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub header: u64,
+    /// Blocks with a back edge to the header
+    pub latches: Vec<u64>,
+    pub body: BTreeSet<u64>,
+}
+
+impl Cfg {
+    /// Partition decoded instructions into basic blocks and link them
+    pub fn build(insns: &HashMap<u64, Insn>, xlen: Xlen) -> Self {
+        Self::build_with_targets(insns, xlen, &HashMap::new())
+    }
+
+    /// Like `build`, with the possible targets of indirect jumps keyed by
+    /// the jump's address, e.g. from jump table recovery
+    pub fn build_with_targets(
+        insns: &HashMap<u64, Insn>,
+        xlen: Xlen,
+        indirect: &HashMap<u64, Vec<u64>>,
+    ) -> Self {
+        let mut addrs: Vec<u64> = insns.keys().copied().collect();
+        addrs.sort();
+
+        // find the leaders: branch targets and the instruction after any transfer
+        let mut leaders = BTreeSet::new();
+        if let Some(first) = addrs.first() {
+            leaders.insert(*first);
+        }
+        for addr in &addrs {
+            let flow = insns[addr].control_flow(*addr, xlen);
+            if flow.kind == FlowKind::Sequential {
+                continue;
+            }
+            if let Some(Target::Direct(target)) = flow.target {
+                if insns.contains_key(&target) {
+                    leaders.insert(target);
+                }
+            }
+            if insns.contains_key(&flow.fall_through) {
+                leaders.insert(flow.fall_through);
+            }
+        }
+        for targets in indirect.values() {
+            leaders.extend(targets.iter().filter(|target| insns.contains_key(target)));
+        }
+
+        // split into blocks at leaders and at gaps in the decoded code
+        let mut blocks = BTreeMap::new();
+        let mut current: Option<BasicBlock> = None;
+        for addr in &addrs {
+            let insn = &insns[addr];
+            let contiguous = current.as_ref().is_some_and(|block| block.end == *addr);
+            if leaders.contains(addr) || !contiguous {
+                if let Some(block) = current.take() {
+                    blocks.insert(block.start, block);
+                }
+            }
+            let block = current.get_or_insert_with(|| BasicBlock {
+                start: *addr,
+                end: *addr,
+                insns: Vec::new(),
+                successors: Vec::new(),
+                predecessors: Vec::new(),
+            });
+            block.insns.push((*addr, insn.clone()));
+            block.end = addr + insn.len as u64;
+        }
+        if let Some(block) = current.take() {
+            blocks.insert(block.start, block);
+        }
+
+        let mut cfg = Self { xlen, blocks };
+        cfg.link(indirect);
+        cfg
+    }
+
+    /// Helper: Add an edge if both ends are blocks
+    fn add_edge(&mut self, from: u64, to: u64, kind: EdgeKind) {
+        if !self.blocks.contains_key(&to) {
+            return;
+        }
+        let edge = Edge { from, to, kind };
+        let source = self.blocks.get_mut(&from).unwrap();
+        if source.successors.contains(&edge) {
+            return;
+        }
+        source.successors.push(edge);
+        self.blocks.get_mut(&to).unwrap().predecessors.push(edge);
+    }
+
+    /// Helper: Create the edges out of every block's terminator
+    fn link(&mut self, indirect: &HashMap<u64, Vec<u64>>) {
+        let mut edges = Vec::new();
+        let mut call_sites = Vec::new();
+        for block in self.blocks.values() {
+            let (addr, insn) = block.terminator();
+            let flow = insn.control_flow(*addr, self.xlen);
+            let target = match flow.target {
+                Some(Target::Direct(target)) => Some(target),
+                _ => None,
+            };
+            match flow.kind {
+                FlowKind::Call => {
+                    if let Some(target) = target {
+                        edges.push((block.start, target, EdgeKind::Call));
+                        call_sites.push((target, flow.fall_through));
+                    }
+                }
+                FlowKind::Branch | FlowKind::Jump | FlowKind::TailCall => {
+                    if let Some(target) = target {
+                        edges.push((block.start, target, EdgeKind::Taken));
+                    }
+                    for target in indirect.get(addr).into_iter().flatten() {
+                        edges.push((block.start, *target, EdgeKind::Taken));
+                    }
+                }
+                _ => {}
+            }
+            if flow.falls_through() {
+                edges.push((block.start, flow.fall_through, EdgeKind::FallThrough));
+            }
+        }
+        for (from, to, kind) in edges {
+            self.add_edge(from, to, kind);
+        }
+        // returns go back to the return site of every caller of their function
+        for (callee, return_site) in call_sites {
+            let returns: Vec<u64> = self
+                .reachable(callee)
+                .into_iter()
+                .filter(|block| {
+                    let (addr, insn) = self.blocks[block].terminator();
+                    insn.control_flow(*addr, self.xlen).kind == FlowKind::Return
+                })
+                .collect();
+            for block in returns {
+                self.add_edge(block, return_site, EdgeKind::Return);
+            }
+        }
+    }
+
+    /// Get the blocks reachable from `entry` along intraprocedural edges
+    pub fn reachable(&self, entry: u64) -> BTreeSet<u64> {
+        let mut seen = BTreeSet::new();
+        if !self.blocks.contains_key(&entry) {
+            return seen;
+        }
+        let mut stack = vec![entry];
+        while let Some(node) = stack.pop() {
+            if !seen.insert(node) {
+                continue;
+            }
+            for edge in &self.blocks[&node].successors {
+                if edge.kind.is_intraprocedural() {
+                    stack.push(edge.to);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Iterate over the blocks in address order
+    pub fn blocks(&self) -> impl Iterator<Item = &BasicBlock> {
+        self.blocks.values()
+    }
+
+    /// Iterate over all edges
+    pub fn edges(&self) -> impl Iterator<Item = &Edge> {
+        self.blocks
+            .values()
+            .flat_map(|block| block.successors.iter())
+    }
+
+    /// Get the block starting at `start`
+    pub fn block(&self, start: u64) -> Option<&BasicBlock> {
+        self.blocks.get(&start)
+    }
+
+    /// Get the block containing the instruction at `addr`
+    pub fn block_containing(&self, addr: u64) -> Option<&BasicBlock> {
+        self.blocks
+            .range(..=addr)
+            .next_back()
+            .map(|(_, block)| block)
+            .filter(|block| addr < block.end)
+    }
+
+    pub fn successors(&self, start: u64) -> &[Edge] {
+        self.blocks
+            .get(&start)
+            .map_or(&[], |block| block.successors.as_slice())
+    }
+
+    pub fn predecessors(&self, start: u64) -> &[Edge] {
+        self.blocks
+            .get(&start)
+            .map_or(&[], |block| block.predecessors.as_slice())
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    /// Helper: Get the reverse postorder of the blocks reachable from `entry`
+    fn reverse_postorder(&self, entry: u64) -> Vec<u64> {
+        let mut order = Vec::new();
+        let mut seen = BTreeSet::new();
+        // iterative DFS, the flag marks a node whose successors are done
+        let mut stack = vec![(entry, false)];
+        while let Some((node, done)) = stack.pop() {
+            if done {
+                order.push(node);
+                continue;
+            }
+            if !seen.insert(node) {
+                continue;
+            }
+            stack.push((node, true));
+            for edge in self.successors(node).iter().rev() {
+                if edge.kind.is_intraprocedural() && !seen.contains(&edge.to) {
+                    stack.push((edge.to, false));
+                }
+            }
+        }
+        order.reverse();
+        order
+    }
+
+    /// Compute the dominator tree of the function starting at `entry`,
+    /// following intraprocedural edges only
+    pub fn dominators(&self, entry: u64) -> DominatorTree {
+        let mut idom = HashMap::new();
+        if !self.blocks.contains_key(&entry) {
+            return DominatorTree { entry, idom };
+        }
+        // Cooper, Harvey and Kennedy, "A Simple, Fast Dominance Algorithm"
+        let order = self.reverse_postorder(entry);
+        let index: HashMap<u64, usize> = order.iter().enumerate().map(|(i, b)| (*b, i)).collect();
+        idom.insert(entry, entry);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for node in order.iter().skip(1) {
+                let mut new_idom: Option<u64> = None;
+                for edge in self.predecessors(*node) {
+                    if !edge.kind.is_intraprocedural() || !idom.contains_key(&edge.from) {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => edge.from,
+                        Some(current) => {
+                            let (mut a, mut b) = (current, edge.from);
+                            while a != b {
+                                while index[&a] > index[&b] {
+                                    a = idom[&a];
+                                }
+                                while index[&b] > index[&a] {
+                                    b = idom[&b];
+                                }
+                            }
+                            a
+                        }
+                    });
+                }
+                if let Some(new_idom) = new_idom {
+                    if idom.get(node) != Some(&new_idom) {
+                        idom.insert(*node, new_idom);
+                        changed = true;
+                    }
+                }
+            }
+        }
+        DominatorTree { entry, idom }
+    }
+
+    /// Find the natural loops of the function starting at `entry`
+    pub fn loops(&self, entry: u64) -> Vec<Loop> {
+        let dominators = self.dominators(entry);
+        let mut loops: BTreeMap<u64, Loop> = BTreeMap::new();
+        for node in self.reachable(entry) {
+            for edge in self.successors(node) {
+                if !edge.kind.is_intraprocedural() || !dominators.dominates(edge.to, node) {
+                    continue;
+                }
+                // walk backwards from the latch until the header
+                let header = edge.to;
+                let lp = loops.entry(header).or_insert_with(|| Loop {
+                    header,
+                    latches: Vec::new(),
+                    body: BTreeSet::from([header]),
+                });
+                lp.latches.push(node);
+                let mut stack = vec![node];
+                while let Some(block) = stack.pop() {
+                    if !lp.body.insert(block) {
+                        continue;
+                    }
+                    for pred in self.predecessors(block) {
+                        if pred.kind.is_intraprocedural() && dominators.dominates(header, pred.from)
+                        {
+                            stack.push(pred.from);
+                        }
+                    }
+                }
+            }
+        }
+        loops.into_values().collect()
+    }
+
+    /// Check if an edge is a loop back edge under the given dominator tree
+    pub fn is_back_edge(&self, edge: &Edge, dominators: &DominatorTree) -> bool {
+        edge.kind.is_intraprocedural() && dominators.dominates(edge.to, edge.from)
+    }
+}
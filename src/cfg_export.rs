@@ -0,0 +1,175 @@
+use crate::cfg::{Cfg, Edge, EdgeKind};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExportOptions {
+    /// Colour edges by their kind
+    pub color_edges: bool,
+    /// Draw loop back edges in bold red
+    pub highlight_back_edges: bool,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            color_edges: true,
+            highlight_back_edges: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonInsn {
+    pub addr: u64,
+    pub raw: u32,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonBlock {
+    pub start: u64,
+    pub end: u64,
+    pub insns: Vec<JsonInsn>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonEdge {
+    pub from: u64,
+    pub to: u64,
+    pub kind: EdgeKind,
+    pub back_edge: bool,
+}
+
+/// Machine-readable form of a function's control-flow graph
+#[derive(Debug, Clone, Serialize)]
+pub struct JsonGraph {
+    pub entry: u64,
+    pub blocks: Vec<JsonBlock>,
+    pub edges: Vec<JsonEdge>,
+}
+
+/// Helper: Get the colour of an edge kind
+fn edge_color(kind: EdgeKind) -> &'static str {
+    match kind {
+        EdgeKind::Taken => "darkgreen",
+        EdgeKind::FallThrough => "blue",
+        EdgeKind::Call => "purple",
+        EdgeKind::Return => "orange",
+    }
+}
+
+/// Helper: Escape text for a Graphviz label
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('{', "\\{")
+        .replace('}', "\\}")
+        .replace('<', "\\<")
+        .replace('>', "\\>")
+        .replace('|', "\\|")
+}
+
+/// Helper: Collect the function's blocks and every edge leaving them
+fn function_edges(cfg: &Cfg, entry: u64) -> (BTreeSet<u64>, Vec<(Edge, bool)>) {
+    let blocks = cfg.reachable(entry);
+    let dominators = cfg.dominators(entry);
+    let edges = blocks
+        .iter()
+        .flat_map(|block| cfg.successors(*block).iter())
+        .map(|edge| (*edge, cfg.is_back_edge(edge, &dominators)))
+        .collect();
+    (blocks, edges)
+}
+
+/// Render the function starting at `entry` as a Graphviz DOT digraph
+pub fn to_dot(cfg: &Cfg, entry: u64, options: &ExportOptions) -> String {
+    let (blocks, edges) = function_edges(cfg, entry);
+    let mut dot = String::new();
+    writeln!(dot, "digraph \"0x{:x}\" {{", entry).unwrap();
+    writeln!(dot, "    node [shape=record, fontname=\"monospace\"];").unwrap();
+    for start in &blocks {
+        let block = cfg.block(*start).unwrap();
+        let mut label = format!("0x{:x}:\\l", block.start);
+        for (addr, insn) in &block.insns {
+            label.push_str(&escape(&format!("  0x{:08x}: {}", addr, insn)));
+            label.push_str("\\l");
+        }
+        writeln!(dot, "    b{:x} [label=\"{{{}}}\"];", block.start, label).unwrap();
+    }
+    // calls and returns leave the function, draw their ends as plain nodes
+    let external: BTreeSet<u64> = edges
+        .iter()
+        .map(|(edge, _)| edge.to)
+        .filter(|to| !blocks.contains(to))
+        .collect();
+    for addr in external {
+        writeln!(
+            dot,
+            "    b{:x} [shape=plaintext, label=\"0x{:x}\"];",
+            addr, addr
+        )
+        .unwrap();
+    }
+    for (edge, back_edge) in edges {
+        let mut attrs = Vec::new();
+        if options.highlight_back_edges && back_edge {
+            attrs.push("color=red".to_string());
+            attrs.push("style=bold".to_string());
+        } else if options.color_edges {
+            attrs.push(format!("color={}", edge_color(edge.kind)));
+        }
+        if matches!(edge.kind, EdgeKind::Call | EdgeKind::Return) {
+            attrs.push("style=dashed".to_string());
+        }
+        write!(dot, "    b{:x} -> b{:x}", edge.from, edge.to).unwrap();
+        if !attrs.is_empty() {
+            write!(dot, " [{}]", attrs.join(", ")).unwrap();
+        }
+        writeln!(dot, ";").unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Build the machine-readable graph of the function starting at `entry`
+pub fn to_json_graph(cfg: &Cfg, entry: u64) -> JsonGraph {
+    let (blocks, edges) = function_edges(cfg, entry);
+    JsonGraph {
+        entry,
+        blocks: blocks
+            .iter()
+            .map(|start| {
+                let block = cfg.block(*start).unwrap();
+                JsonBlock {
+                    start: block.start,
+                    end: block.end,
+                    insns: block
+                        .insns
+                        .iter()
+                        .map(|(addr, insn)| JsonInsn {
+                            addr: *addr,
+                            raw: insn.raw,
+                            text: insn.to_string(),
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+        edges: edges
+            .into_iter()
+            .map(|(edge, back_edge)| JsonEdge {
+                from: edge.from,
+                to: edge.to,
+                kind: edge.kind,
+                back_edge,
+            })
+            .collect(),
+    }
+}
+
+/// Render the function starting at `entry` as a JSON graph
+pub fn to_json(cfg: &Cfg, entry: u64) -> String {
+    serde_json::to_string_pretty(&to_json_graph(cfg, entry)).unwrap()
+}
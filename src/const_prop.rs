@@ -0,0 +1,271 @@
+use crate::cfg::Cfg;
+use crate::control_flow::FlowKind;
+use crate::disassembler::Xlen;
+use crate::insn::Insn;
+use crate::mnemonic::Mnemonic;
+use crate::reg::Reg;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const SP: u32 = 2;
+/// Registers a call may clobber under the psABI: ra, t0-t6 and a0-a7
+const CALLER_SAVED: &[u32] = &[1, 5, 6, 7, 10, 11, 12, 13, 14, 15, 16, 17, 28, 29, 30, 31];
+
+/// Known GPR values keyed by register number
+pub type RegValues = BTreeMap<u32, u64>;
+
+/// Known GPR values, and which of them were built from a `lui`, `auipc`
+/// or `c.lui` and so are likely addresses
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegState {
+    pub values: RegValues,
+    pub rooted: BTreeSet<u32>,
+}
+
+/// What constant tracking learned about one instruction
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InsnConsts {
+    /// GPR values known before the instruction executes
+    pub before: RegValues,
+    /// Value written to the destination GPR, if known
+    pub result: Option<u64>,
+    /// Effective address of a load, store or `jalr`, if known
+    pub address: Option<u64>,
+    /// The address worth showing in a listing: an effective address, or
+    /// a value built from a `lui`/`auipc`, e.g. the end of an `auipc` +
+    /// `addi` pair
+    pub annotation: Option<u64>,
+}
+
+/// Helper: Truncate a value to XLEN bits
+fn wrap(val: u64, xlen: Xlen) -> u64 {
+    match xlen {
+        Xlen::XLEN32 => val & 0xffff_ffff,
+        Xlen::XLEN64 => val,
+    }
+}
+
+/// Helper: Sign-extend the low 32 bits, as the `*w` instructions do
+fn sext32(val: u64) -> u64 {
+    val as i32 as i64 as u64
+}
+
+/// Helper: Shift right arithmetically at XLEN width
+fn sra(val: u64, shamt: u32, xlen: Xlen) -> u64 {
+    match xlen {
+        Xlen::XLEN32 => ((val as i32) >> (shamt & 0x1f)) as u32 as u64,
+        Xlen::XLEN64 => ((val as i64) >> (shamt & 0x3f)) as u64,
+    }
+}
+
+/// Apply one instruction at `pc` to the known register values
+pub fn transfer(pc: u64, insn: &Insn, state: &mut RegState, xlen: Xlen) -> InsnConsts {
+    let mut consts = InsnConsts {
+        before: state.values.clone(),
+        ..Default::default()
+    };
+    let get = |tag: &str| match insn.operand(tag)? {
+        0 => Some(0),
+        reg => state.values.get(&reg).copied(),
+    };
+    let is_rooted = |tag: &str| {
+        insn.operand(tag)
+            .is_some_and(|reg| state.rooted.contains(&reg))
+    };
+    let imm = insn.offset as i64 as u64;
+    let shamt = insn.offset as u32;
+    let result = match insn.mnemonic {
+        Mnemonic::Lui | Mnemonic::CLui | Mnemonic::CLi => Some(imm),
+        Mnemonic::Auipc => Some(pc.wrapping_add(imm)),
+        Mnemonic::Addi | Mnemonic::CAddi => get("rs1").map(|a| a.wrapping_add(imm)),
+        Mnemonic::CAddi16sp => state.values.get(&SP).map(|sp| sp.wrapping_add(imm)),
+        Mnemonic::CAddi4spn => state.values.get(&SP).map(|sp| sp.wrapping_add(imm)),
+        Mnemonic::Addiw | Mnemonic::CAddiw => get("rs1").map(|a| sext32(a.wrapping_add(imm))),
+        Mnemonic::Xori => get("rs1").map(|a| a ^ imm),
+        Mnemonic::Ori => get("rs1").map(|a| a | imm),
+        Mnemonic::Andi | Mnemonic::CAndi => get("rs1").map(|a| a & imm),
+        Mnemonic::Slli | Mnemonic::CSlli => get("rs1").map(|a| a << (shamt & 0x3f)),
+        Mnemonic::Srli | Mnemonic::CSrli => get("rs1").map(|a| wrap(a, xlen) >> (shamt & 0x3f)),
+        Mnemonic::Srai | Mnemonic::CSrai => get("rs1").map(|a| sra(a, shamt, xlen)),
+        Mnemonic::CMv => get("rs2"),
+        _ => match (get("rs1"), get("rs2")) {
+            (Some(a), Some(b)) => match insn.mnemonic {
+                Mnemonic::Add | Mnemonic::CAdd => Some(a.wrapping_add(b)),
+                Mnemonic::Sub | Mnemonic::CSub => Some(a.wrapping_sub(b)),
+                Mnemonic::Addw | Mnemonic::CAddw => Some(sext32(a.wrapping_add(b))),
+                Mnemonic::Subw | Mnemonic::CSubw => Some(sext32(a.wrapping_sub(b))),
+                Mnemonic::Xor | Mnemonic::CXor => Some(a ^ b),
+                Mnemonic::Or | Mnemonic::COr => Some(a | b),
+                Mnemonic::And | Mnemonic::CAnd => Some(a & b),
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+    .map(|val| wrap(val, xlen));
+
+    if let Some(access) = insn.mem_access() {
+        let base = match access.base {
+            0 => Some(0),
+            reg => state.values.get(&reg).copied(),
+        };
+        consts.address =
+            base.map(|base| wrap(base.wrapping_add(access.offset as i64 as u64), xlen));
+    }
+    if matches!(
+        insn.mnemonic,
+        Mnemonic::Jalr | Mnemonic::CJr | Mnemonic::CJalr
+    ) {
+        consts.address = get("rs1").map(|base| wrap(base.wrapping_add(imm), xlen) & !1);
+    }
+    // an address is rooted in a `lui`/`auipc` and may be offset or moved,
+    // plain arithmetic on small constants is not worth a comment
+    let rooted = match insn.mnemonic {
+        Mnemonic::Lui | Mnemonic::CLui | Mnemonic::Auipc => true,
+        Mnemonic::Addi | Mnemonic::Addiw | Mnemonic::CAddi | Mnemonic::CAddiw => is_rooted("rs1"),
+        Mnemonic::Add | Mnemonic::CAdd => is_rooted("rs1") || is_rooted("rs2"),
+        Mnemonic::CMv => is_rooted("rs2"),
+        _ => false,
+    };
+    // `lui` and `auipc` alone are only halves of an address
+    let materialises = rooted
+        && !matches!(
+            insn.mnemonic,
+            Mnemonic::Lui | Mnemonic::CLui | Mnemonic::Auipc
+        );
+    consts.result = result;
+    consts.annotation = consts.address.or(result.filter(|_| materialises));
+
+    for reg in insn.regs_written() {
+        if let Reg::Gpr(reg) = reg {
+            state.values.remove(&reg);
+            state.rooted.remove(&reg);
+        }
+    }
+    if let (Some(val), Some(rd)) = (result, insn.operand("rd")) {
+        if rd != 0 {
+            state.values.insert(rd, val);
+            if rooted {
+                state.rooted.insert(rd);
+            }
+        }
+    }
+    if matches!(
+        insn.control_flow(pc, xlen).kind,
+        FlowKind::Call | FlowKind::CoroutineSwap
+    ) {
+        for reg in CALLER_SAVED {
+            state.values.remove(reg);
+            state.rooted.remove(reg);
+        }
+    }
+    consts
+}
+
+/// Helper: Keep only the values both states agree on
+fn meet(into: &mut RegState, other: &RegState) {
+    into.values
+        .retain(|reg, val| other.values.get(reg) == Some(val));
+    into.rooted
+        .retain(|reg| into.values.contains_key(reg) && other.rooted.contains(reg));
+}
+
+/// Register constants tracked over decoded code, keyed by instruction address
+#[derive(Debug, Clone, Default)]
+pub struct ConstProp {
+    consts: HashMap<u64, InsnConsts>,
+}
+
+impl ConstProp {
+    /// Track constants within each basic block, starting every block
+    /// with nothing known
+    pub fn within_blocks(cfg: &Cfg) -> Self {
+        let mut consts = HashMap::new();
+        for block in cfg.blocks() {
+            let mut state = RegState::default();
+            for (addr, insn) in &block.insns {
+                consts.insert(*addr, transfer(*addr, insn, &mut state, cfg.xlen));
+            }
+        }
+        Self { consts }
+    }
+
+    /// Track constants across the blocks of the function starting at
+    /// `entry`, keeping the values that agree on every incoming path
+    pub fn within_function(cfg: &Cfg, entry: u64) -> Self {
+        let blocks = cfg.reachable(entry);
+        let mut inputs: HashMap<u64, RegState> = HashMap::new();
+        let mut outputs: HashMap<u64, RegState> = HashMap::new();
+        if blocks.contains(&entry) {
+            inputs.insert(entry, RegState::default());
+        }
+        let mut work: Vec<u64> = vec![entry];
+        while let Some(start) = work.pop() {
+            let Some(mut state) = inputs.get(&start).cloned() else {
+                continue;
+            };
+            let block = cfg.block(start).unwrap();
+            for (addr, insn) in &block.insns {
+                transfer(*addr, insn, &mut state, cfg.xlen);
+            }
+            if outputs.get(&start) == Some(&state) {
+                continue;
+            }
+            outputs.insert(start, state.clone());
+            for edge in cfg.successors(start) {
+                if !edge.kind.is_intraprocedural() || !blocks.contains(&edge.to) {
+                    continue;
+                }
+                // only the predecessors seen so far take part in the meet,
+                // and the entry has its caller too, which tells nothing
+                let mut input = state.clone();
+                if edge.to == entry {
+                    meet(&mut input, &RegState::default());
+                }
+                for pred in cfg.predecessors(edge.to) {
+                    if let Some(out) = outputs
+                        .get(&pred.from)
+                        .filter(|_| pred.kind.is_intraprocedural())
+                    {
+                        meet(&mut input, out);
+                    }
+                }
+                if inputs.get(&edge.to) != Some(&input) {
+                    inputs.insert(edge.to, input);
+                    work.push(edge.to);
+                }
+            }
+        }
+        let mut consts = HashMap::new();
+        for start in blocks {
+            let mut state = inputs.get(&start).cloned().unwrap_or_default();
+            for (addr, insn) in &cfg.block(start).unwrap().insns {
+                consts.insert(*addr, transfer(*addr, insn, &mut state, cfg.xlen));
+            }
+        }
+        Self { consts }
+    }
+
+    /// Get what is known about the instruction at `addr`
+    pub fn get(&self, addr: u64) -> Option<&InsnConsts> {
+        self.consts.get(&addr)
+    }
+
+    /// Get the listing comment for the instruction at `addr`, e.g. `# 0x80001234`
+    pub fn comment(&self, addr: u64) -> Option<String> {
+        let annotation = self.consts.get(&addr)?.annotation?;
+        Some(format!("# 0x{:x}", annotation))
+    }
+
+    /// Get every resolved address as (instruction address, referenced address),
+    /// in instruction order
+    pub fn referenced_addresses(&self) -> Vec<(u64, u64)> {
+        let mut refs: Vec<(u64, u64)> = self
+            .consts
+            .iter()
+            .filter_map(|(addr, consts)| consts.annotation.map(|target| (*addr, target)))
+            .collect();
+        refs.sort();
+        refs
+    }
+}
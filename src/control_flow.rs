@@ -0,0 +1,128 @@
+use crate::disassembler::Xlen;
+use crate::insn::Insn;
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+
+/// Link registers per the RISC-V return-address stack hints
+const RA: u32 = 1;
+const T0: u32 = 5;
+/// The psABI `tail` pseudo-instruction jumps through t1
+const T1: u32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowKind {
+    /// Execution continues at the fall-through address
+    Sequential,
+    /// Conditional branch, either taken or falls through
+    Branch,
+    Jump,
+    /// Pushes a return address
+    Call,
+    /// Jump through t1, as emitted for the `tail` pseudo-instruction
+    TailCall,
+    /// Pops a return address
+    Return,
+    /// Pops a return address then pushes a new one (e.g. `jalr ra, t0`)
+    CoroutineSwap,
+    /// `ecall` / `ebreak`, resumes at the fall-through address
+    Trap,
+    /// `mret` / `sret`
+    TrapReturn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Target {
+    /// Absolute target address
+    Direct(u64),
+    /// Register jump, target unknown statically
+    Indirect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ControlFlow {
+    pub kind: FlowKind,
+    /// Address of the next sequential instruction
+    pub fall_through: u64,
+    /// Where control goes when the transfer is taken
+    pub target: Option<Target>,
+}
+
+impl ControlFlow {
+    /// Helper: Check if execution can continue at the fall-through address
+    pub fn falls_through(&self) -> bool {
+        matches!(
+            self.kind,
+            FlowKind::Sequential
+                | FlowKind::Branch
+                | FlowKind::Call
+                | FlowKind::CoroutineSwap
+                | FlowKind::Trap
+        )
+    }
+}
+
+/// Helper: Check if a register is a link register
+fn is_link(reg: u32) -> bool {
+    reg == RA || reg == T0
+}
+
+/// Helper: Truncate an address to XLEN bits
+fn wrap(addr: u64, xlen: Xlen) -> u64 {
+    match xlen {
+        Xlen::XLEN32 => addr & 0xffff_ffff,
+        Xlen::XLEN64 => addr,
+    }
+}
+
+/// Helper: Classify a jump by its link register hints
+fn classify_jump(rd: u32, rs1: Option<u32>) -> FlowKind {
+    match rs1 {
+        None if is_link(rd) => FlowKind::Call,
+        None => FlowKind::Jump,
+        Some(rs1) => match (is_link(rd), is_link(rs1)) {
+            (false, false) if rd == 0 && rs1 == T1 => FlowKind::TailCall,
+            (false, false) => FlowKind::Jump,
+            (false, true) => FlowKind::Return,
+            (true, false) => FlowKind::Call,
+            (true, true) if rd == rs1 => FlowKind::Call,
+            (true, true) => FlowKind::CoroutineSwap,
+        },
+    }
+}
+
+/// Describe how an instruction at `pc` transfers control
+pub fn control_flow(insn: &Insn, pc: u64, xlen: Xlen) -> ControlFlow {
+    let fall_through = wrap(pc.wrapping_add(insn.len as u64), xlen);
+    let relative = Target::Direct(wrap(pc.wrapping_add(insn.offset as i64 as u64), xlen));
+    let rd = insn.args.dst.get("rd").map_or(0, |rd| rd.get_val());
+    let rs1 = insn.args.src.get("rs1").map(|rs1| rs1.get_val());
+    let (kind, target) = match insn.mnemonic {
+        _ if insn.is_branch() => (FlowKind::Branch, Some(relative)),
+        Mnemonic::Jal => (classify_jump(rd, None), Some(relative)),
+        Mnemonic::CJ => (FlowKind::Jump, Some(relative)),
+        Mnemonic::CJal => (FlowKind::Call, Some(relative)),
+        Mnemonic::Jalr | Mnemonic::CJr | Mnemonic::CJalr => {
+            let rd = if insn.mnemonic == Mnemonic::CJalr {
+                RA
+            } else {
+                rd
+            };
+            let rs1 = rs1.unwrap_or(0);
+            // jalr off(x0) reaches an absolute address
+            let target = if rs1 == 0 {
+                Target::Direct(wrap(insn.offset as i64 as u64, xlen) & !1)
+            } else {
+                Target::Indirect
+            };
+            (classify_jump(rd, Some(rs1)), Some(target))
+        }
+        Mnemonic::Ecall | Mnemonic::Ebreak | Mnemonic::CEbreak => (FlowKind::Trap, None),
+        Mnemonic::Mret | Mnemonic::Sret => (FlowKind::TrapReturn, Some(Target::Indirect)),
+        _ => (FlowKind::Sequential, None),
+    };
+    ControlFlow {
+        kind,
+        fall_through,
+        target,
+    }
+}
@@ -0,0 +1,209 @@
+use crate::disassembler::Xlen;
+use crate::functions::SymbolTable;
+use crate::insn::Insn;
+use crate::isa_meta::Extension;
+use crate::mnemonic::Mnemonic;
+use crate::rvc::{try_compress, Incompressible};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+
+/// How many instructions of each size a piece of code holds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeCounts {
+    pub compressed: usize,
+    pub full: usize,
+    /// 32-bit instructions that have a 16-bit encoding
+    pub compressible: usize,
+}
+
+impl SizeCounts {
+    pub fn total(&self) -> usize {
+        self.compressed + self.full
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.compressed * 2 + self.full * 4
+    }
+
+    /// Fraction of the instructions that are 16-bit, 0 for no instructions
+    pub fn compressed_ratio(&self) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.compressed as f64 / total as f64,
+        }
+    }
+
+    /// Bytes saved by compressing every compressible instruction
+    pub fn savings(&self) -> usize {
+        self.compressible * 2
+    }
+
+    /// Helper: Count one instruction
+    fn add(&mut self, insn: &Insn, compressible: bool) {
+        if insn.len == 2 {
+            self.compressed += 1;
+        } else {
+            self.full += 1;
+        }
+        if compressible {
+            self.compressible += 1;
+        }
+    }
+}
+
+impl fmt::Display for SizeCounts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} x 16-bit, {} x 32-bit ({:.1}% compressed, {} bytes), {} compressible",
+            self.compressed,
+            self.full,
+            self.compressed_ratio() * 100.0,
+            self.bytes(),
+            self.compressible
+        )
+    }
+}
+
+/// A 32-bit instruction and whether it has a 16-bit encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Candidate {
+    pub addr: u64,
+    pub raw: u32,
+    pub mnemonic: Mnemonic,
+    pub compressed: Result<u16, Incompressible>,
+}
+
+/// Instruction size statistics of one function
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionDensity {
+    pub name: String,
+    pub start: u64,
+    pub counts: SizeCounts,
+}
+
+/// Code density of a disassembled image: how much of it is compressed, and
+/// which 32-bit instructions could have been
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DensityReport {
+    pub total: SizeCounts,
+    /// Keyed by function start, for the functions of the symbol table
+    pub functions: BTreeMap<u64, FunctionDensity>,
+    pub extensions: BTreeMap<Extension, SizeCounts>,
+    /// 32-bit instructions with a compressed counterpart, whether it fits
+    /// or not, by address
+    pub candidates: Vec<Candidate>,
+    /// How many 32-bit instructions could not be compressed, by reason
+    pub reasons: BTreeMap<Incompressible, usize>,
+}
+
+impl DensityReport {
+    /// Build the report over decoded instructions, with compressed forms
+    /// limited to `extensions` as in `Disassembler::with_extensions`.
+    /// Undecoded words are left out
+    pub fn build(
+        insns: &HashMap<u64, Insn>,
+        xlen: Xlen,
+        symbols: &SymbolTable,
+        extensions: Option<&BTreeSet<Extension>>,
+    ) -> Self {
+        let mut report = Self::default();
+        for function in symbols.functions() {
+            report.functions.insert(
+                function.start,
+                FunctionDensity {
+                    name: function.name.clone(),
+                    start: function.start,
+                    counts: SizeCounts::default(),
+                },
+            );
+        }
+
+        let mut addrs: Vec<u64> = insns.keys().copied().collect();
+        addrs.sort();
+        for addr in addrs {
+            let insn = &insns[&addr];
+            if insn.mnemonic == Mnemonic::Unknown {
+                continue;
+            }
+            let compressed = try_compress(insn, xlen, extensions);
+            match compressed {
+                Err(Incompressible::Compressed) => {}
+                Err(reason) => *report.reasons.entry(reason).or_default() += 1,
+                Ok(_) => {}
+            }
+            if !matches!(
+                compressed,
+                Err(Incompressible::Compressed | Incompressible::NoEquivalent)
+            ) {
+                report.candidates.push(Candidate {
+                    addr,
+                    raw: insn.raw,
+                    mnemonic: insn.mnemonic,
+                    compressed,
+                });
+            }
+
+            let compressible = compressed.is_ok();
+            report.total.add(insn, compressible);
+            report
+                .extensions
+                .entry(insn.extension())
+                .or_default()
+                .add(insn, compressible);
+            if let Some(function) = symbols.function_containing(addr) {
+                if let Some(density) = report.functions.get_mut(&function.start) {
+                    density.counts.add(insn, compressible);
+                }
+            }
+        }
+        report
+    }
+
+    /// The 32-bit instructions that have a 16-bit encoding
+    pub fn compressible(&self) -> impl Iterator<Item = &Candidate> {
+        self.candidates
+            .iter()
+            .filter(|candidate| candidate.compressed.is_ok())
+    }
+}
+
+impl fmt::Display for DensityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "total: {}", self.total)?;
+        writeln!(f, "saving: {} bytes", self.total.savings())?;
+        writeln!(f, "by extension:")?;
+        for (extension, counts) in &self.extensions {
+            writeln!(f, "  {}: {}", extension.as_str(), counts)?;
+        }
+        writeln!(f, "by function:")?;
+        for function in self.functions.values() {
+            writeln!(
+                f,
+                "  0x{:08x} {}: {}",
+                function.start, function.name, function.counts
+            )?;
+        }
+        writeln!(f, "not compressible:")?;
+        for (reason, count) in &self.reasons {
+            writeln!(f, "  {}: {}", reason, count)?;
+        }
+        writeln!(f, "candidates:")?;
+        for candidate in &self.candidates {
+            match candidate.compressed {
+                Ok(compressed) => writeln!(
+                    f,
+                    "  0x{:08x} {:08x} {} -> {:04x}",
+                    candidate.addr, candidate.raw, candidate.mnemonic, compressed
+                )?,
+                Err(reason) => writeln!(
+                    f,
+                    "  0x{:08x} {:08x} {}: {}",
+                    candidate.addr, candidate.raw, candidate.mnemonic, reason
+                )?,
+            }
+        }
+        Ok(())
+    }
+}
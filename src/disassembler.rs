@@ -1,7 +1,12 @@
 use crate::args::*;
+use crate::control_flow::Target;
 use crate::insn::*;
 use crate::isa::*;
-use std::collections::HashMap;
+use crate::isa_meta::Extension;
+use crate::mapping::{data_directives, Line, MappingKind, MappingSymbol};
+use crate::mnemonic::Mnemonic;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ops::Range;
 
 /// Helper: Check if the instruction is RVC
 pub fn is_compressed_byte(byte: u8) -> bool {
@@ -15,18 +20,205 @@ pub fn get_opcode(code: u32) -> u8 {
     (code & 0x7f) as u8
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Xlen {
     XLEN32,
     XLEN64,
 }
 
+#[derive(Debug, Clone)]
 pub struct Disassembler {
     xlen: Xlen,
+    /// Extensions whose specs may match, `None` enables all of them
+    extensions: Option<BTreeSet<Extension>>,
+}
+
+/// Helper: Strip a trailing `<major>p<minor>` version from an extension name
+fn strip_version(name: &str) -> &str {
+    let trimmed = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    match trimmed.strip_suffix('p') {
+        Some(rest)
+            if rest.ends_with(|c: char| c.is_ascii_digit()) && trimmed.len() < name.len() =>
+        {
+            rest.trim_end_matches(|c: char| c.is_ascii_digit())
+        }
+        _ => trimmed,
+    }
+}
+
+/// Parse an ISA string such as `rv64imafdc_zicsr` or `rv32i2p1_c2p0`
+/// into the XLEN and the set of extensions the decoder knows about.
+/// Unknown extensions are ignored, privileged instructions are always enabled
+pub fn parse_isa_string(isa: &str) -> Option<(Xlen, BTreeSet<Extension>)> {
+    let isa = isa.to_ascii_lowercase();
+    let (xlen, rest) = if let Some(rest) = isa.strip_prefix("rv32") {
+        (Xlen::XLEN32, rest)
+    } else if let Some(rest) = isa.strip_prefix("rv64") {
+        (Xlen::XLEN64, rest)
+    } else {
+        return None;
+    };
+    let mut parts = rest.split('_');
+    let mut names: Vec<String> = Vec::new();
+    // single-letter extensions, each optionally followed by a version
+    let base: Vec<char> = parts.next().unwrap_or("").chars().collect();
+    let mut i = 0;
+    while i < base.len() {
+        let letter = base[i];
+        if !letter.is_ascii_alphabetic() {
+            return None;
+        }
+        i += 1;
+        while i < base.len() && base[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i + 1 < base.len() && base[i] == 'p' && base[i + 1].is_ascii_digit() {
+            i += 1;
+            while i < base.len() && base[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+        match letter {
+            'g' => names.extend(["i", "m", "a", "f", "d", "zicsr"].map(String::from)),
+            _ => names.push(letter.to_string()),
+        }
+    }
+    names.extend(
+        parts
+            .filter(|part| !part.is_empty())
+            .map(|part| strip_version(part).to_string()),
+    );
+
+    let has = |name: &str| names.iter().any(|n| n == name);
+    let mut extensions = BTreeSet::from([Extension::RvS, Extension::RvSystem]);
+    if has("i") || has("e") {
+        extensions.extend([Extension::RvI, Extension::Rv64I]);
+    }
+    if has("m") {
+        extensions.extend([Extension::RvM, Extension::Rv64M]);
+    }
+    if has("a") {
+        extensions.extend([Extension::RvA, Extension::Rv64A]);
+    }
+    let has_f = has("f") || has("d");
+    if has_f {
+        extensions.extend([Extension::RvF, Extension::Rv64F, Extension::RvZicsr]);
+    }
+    if has("d") {
+        extensions.extend([Extension::RvD, Extension::Rv64D]);
+    }
+    if has("v") {
+        extensions.extend([Extension::RvV, Extension::RvZicsr]);
+    }
+    if has("zicsr") {
+        extensions.insert(Extension::RvZicsr);
+    }
+    // C is Zca plus Zcf (RV32 only) and Zcd when F and D are present
+    if has("c") || has("zca") {
+        extensions.extend([Extension::RvC, Extension::Rv32C, Extension::Rv64C]);
+    }
+    if (has("c") && has_f && xlen == Xlen::XLEN32) || has("zcf") {
+        extensions.insert(Extension::Rv32CF);
+    }
+    if (has("c") && has("d")) || has("zcd") {
+        extensions.insert(Extension::RvCD);
+    }
+    if has("zcb") {
+        extensions.insert(Extension::RvZcb);
+    }
+    Some((xlen, extensions))
+}
+
+/// Two reachable instructions whose encodings overlap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conflict {
+    /// Address of the instruction that was decoded first
+    pub addr: u64,
+    /// Address of the overlapping instruction that was rejected
+    pub other: u64,
+}
+
+/// Result of a recursive-traversal disassembly
+#[derive(Debug, Clone, Default)]
+pub struct Traversal {
+    pub insns: HashMap<u64, Insn>,
+    /// Byte ranges never reached as code, in address order
+    pub data: Vec<Range<u64>>,
+    pub conflicts: Vec<Conflict>,
+    /// Reached addresses holding no valid instruction, in address order.
+    /// Their bytes stay in `data`
+    pub undecodable: Vec<u64>,
+}
+
+/// Helper: Build the placeholder for an undecodable instruction
+fn unknown_insn(raw: u32) -> Insn {
+    Insn::new(
+        raw,
+        Mnemonic::Unknown,
+        HashMap::new(),
+        None,
+        HashMap::new(),
+        HashMap::new(),
+        None,
+    )
+}
+
+/// Helper: Read the instruction word at `offset`, if the bytes are there
+fn fetch(code: &[u8], offset: usize) -> Option<u32> {
+    let first = *code.get(offset)?;
+    if is_compressed_byte(first) {
+        let bytes = code.get(offset..offset + 2)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], 0, 0]))
+    } else {
+        let bytes = code.get(offset..offset + 4)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
 }
 
 impl Disassembler {
     pub fn new(xlen: Xlen) -> Self {
-        Self { xlen }
+        Self {
+            xlen,
+            extensions: None,
+        }
+    }
+
+    /// Create a disassembler that only decodes the given extensions
+    pub fn with_extensions(xlen: Xlen, extensions: &[Extension]) -> Self {
+        Self {
+            xlen,
+            extensions: Some(extensions.iter().copied().collect()),
+        }
+    }
+
+    /// Create a disassembler for an ISA string such as `rv64imafdc`
+    pub fn from_isa_string(isa: &str) -> Option<Self> {
+        let (xlen, extensions) = parse_isa_string(isa)?;
+        Some(Self {
+            xlen,
+            extensions: Some(extensions),
+        })
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    /// Get the enabled extensions, `None` when all are enabled
+    pub fn extensions(&self) -> Option<&BTreeSet<Extension>> {
+        self.extensions.as_ref()
+    }
+
+    /// Restrict decoding to the given extensions, `None` enables all of them
+    pub fn set_extensions(&mut self, extensions: Option<BTreeSet<Extension>>) {
+        self.extensions = extensions;
+    }
+
+    /// Helper: Check if a spec belongs to an enabled extension
+    fn is_enabled(&self, spec: &Spec) -> bool {
+        self.extensions
+            .as_ref()
+            .is_none_or(|extensions| extensions.contains(&spec.extension))
     }
 
     pub fn extract_from_mask_match(&self, spec: &Spec, code: u32) -> Option<Insn> {
@@ -35,7 +227,7 @@ impl Disassembler {
         // iterate over the args and check for Args::Error
         let valid = args.iter().all(|(arg, _)| !arg.is_error());
         if !valid {
-            return None;
+            None
         } else {
             // iterate over the args and check for Args::Error
             let mut src_args = HashMap::new();
@@ -43,6 +235,11 @@ impl Disassembler {
             let mut flags = HashMap::new();
             let mut imm = None;
             let mut csr = None;
+            let legality = args
+                .iter()
+                .map(|(arg, _)| arg.legality())
+                .max()
+                .unwrap_or_default();
             for (arg, tag) in args {
                 if arg.is_src() {
                     src_args.insert(tag, arg);
@@ -56,8 +253,9 @@ impl Disassembler {
                     csr = Some(arg);
                 }
             }
-            let insn = Insn::new(code, &spec.name, src_args, imm, dst_args, flags, csr);
-            return Some(insn);
+            let mut insn = Insn::new(code, spec.mnemonic, src_args, imm, dst_args, flags, csr);
+            insn.legality = legality;
+            Some(insn)
         }
     }
 
@@ -68,7 +266,7 @@ impl Disassembler {
         if is_compressed(code) {
             // iterate over generic compressed specs
             for spec in RV_ISA_SPECS_GENERIC_COMPRESSED.iter() {
-                if spec.compare(code) {
+                if spec.compare(code) && self.is_enabled(spec) {
                     let result = self.extract_from_mask_match(spec, code);
                     if result.is_some() {
                         return result;
@@ -83,7 +281,7 @@ impl Disassembler {
                 Xlen::XLEN64 => &*RV_ISA_SPECS_64_COMPRESSED,
             };
             for spec in xlen_specs.iter() {
-                if spec.compare(code) {
+                if spec.compare(code) && self.is_enabled(spec) {
                     let result = self.extract_from_mask_match(spec, code);
                     if result.is_some() {
                         return result;
@@ -95,11 +293,10 @@ impl Disassembler {
             return None;
         }
         // then, check if the instruction is a regular instruction
-        let spec = get_generic_full_specs_by_opcode(get_opcode(code));
-        if spec.is_some() {
+        if let Some(specs) = get_generic_full_specs_by_opcode(get_opcode(code)) {
             // check if the masked result creates a match
-            for spec in spec.unwrap().iter() {
-                if spec.compare(code) {
+            for spec in specs.iter() {
+                if spec.compare(code) && self.is_enabled(spec) {
                     let result = self.extract_from_mask_match(spec, code);
                     if result.is_some() {
                         return result;
@@ -113,9 +310,9 @@ impl Disassembler {
             Xlen::XLEN32 => get_32_full_specs_by_opcode(get_opcode(code)),
             Xlen::XLEN64 => get_64_full_specs_by_opcode(get_opcode(code)),
         };
-        if xlen_specs.is_some() {
-            for spec in xlen_specs.unwrap().iter() {
-                if spec.compare(code) {
+        if let Some(xlen_specs) = xlen_specs {
+            for spec in xlen_specs.iter() {
+                if spec.compare(code) && self.is_enabled(spec) {
                     let result = self.extract_from_mask_match(spec, code);
                     if result.is_some() {
                         return result;
@@ -139,27 +336,15 @@ impl Disassembler {
         let mut insns = HashMap::new();
         let mut i = 0;
         while i < code.len() {
-            let code_u32;
             let is_compressed = is_compressed_byte(code[i]);
-            if is_compressed {
-                code_u32 = u32::from_le_bytes([code[i], code[i + 1], 0 as u8, 0 as u8]);
+            let code_u32 = if is_compressed {
+                u32::from_le_bytes([code[i], code[i + 1], 0, 0])
             } else {
-                code_u32 = u32::from_le_bytes([code[i], code[i + 1], code[i + 2], code[i + 3]]);
-            }
+                u32::from_le_bytes([code[i], code[i + 1], code[i + 2], code[i + 3]])
+            };
             let insn_opt = self.disassmeble_one(code_u32);
             if insn_opt.is_none() {
-                insns.insert(
-                    i as u64 + entry_point,
-                    Insn::new(
-                        code_u32,
-                        "unknown",
-                        HashMap::new(),
-                        None,
-                        HashMap::new(),
-                        HashMap::new(),
-                        None,
-                    ),
-                );
+                insns.insert(i as u64 + entry_point, unknown_insn(code_u32));
                 i += if is_compressed { 2 } else { 4 };
                 continue;
             }
@@ -170,4 +355,140 @@ impl Disassembler {
         }
         insns
     }
+
+    /// Disassemble a chunk of binary by following control flow from `roots`
+    /// (e.g. the entry point and symbol addresses). Bytes that no path
+    /// reaches are reported as data instead of being decoded
+    pub fn disassemble_recursive(&self, code: &[u8], base: u64, roots: &[u64]) -> Traversal {
+        self.disassemble_recursive_with_targets(code, base, roots, &HashMap::new())
+    }
+
+    /// Like `disassemble_recursive`, also following the given targets of
+    /// indirect jumps keyed by the jump's address
+    pub fn disassemble_recursive_with_targets(
+        &self,
+        code: &[u8],
+        base: u64,
+        roots: &[u64],
+        indirect: &HashMap<u64, Vec<u64>>,
+    ) -> Traversal {
+        let end = base + code.len() as u64;
+        let mut traversal = Traversal::default();
+        // start address of the instruction covering each decoded byte
+        let mut owner: BTreeMap<u64, u64> = BTreeMap::new();
+        let mut conflicts = BTreeSet::new();
+        let mut undecodable = BTreeSet::new();
+        let mut work: Vec<u64> = roots.iter().rev().copied().collect();
+        while let Some(addr) = work.pop() {
+            if addr < base || addr >= end || traversal.insns.contains_key(&addr) {
+                continue;
+            }
+            let Some(insn) =
+                fetch(code, (addr - base) as usize).and_then(|raw| self.disassmeble_one(raw))
+            else {
+                undecodable.insert(addr);
+                continue;
+            };
+            let len = insn.len as u64;
+            if let Some((_, other)) = owner.range(addr..addr + len).next() {
+                conflicts.insert((*other, addr));
+                continue;
+            }
+            for byte in addr..addr + len {
+                owner.insert(byte, addr);
+            }
+            let flow = insn.control_flow(addr, self.xlen);
+            if flow.falls_through() {
+                work.push(flow.fall_through);
+            }
+            if let Some(Target::Direct(target)) = flow.target {
+                work.push(target);
+            }
+            work.extend(indirect.get(&addr).into_iter().flatten());
+            traversal.insns.insert(addr, insn);
+        }
+        // coalesce the bytes nobody owns into data ranges
+        let mut start = None;
+        for addr in base..end {
+            match (owner.contains_key(&addr), start) {
+                (false, None) => start = Some(addr),
+                (true, Some(from)) => {
+                    traversal.data.push(from..addr);
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        if let Some(from) = start {
+            traversal.data.push(from..end);
+        }
+        traversal.conflicts = conflicts
+            .into_iter()
+            .map(|(addr, other)| Conflict { addr, other })
+            .collect();
+        traversal.undecodable = undecodable.into_iter().collect();
+        traversal
+    }
+
+    /// Disassemble a section honouring its psABI mapping symbols: `$d`
+    /// regions become data directives and `$x<isa>` switches the enabled
+    /// extensions until the next `$x<isa>`. Bytes before the first symbol
+    /// are treated as code
+    pub fn disassemble_mapped(
+        &self,
+        code: &[u8],
+        base: u64,
+        symbols: &[MappingSymbol],
+    ) -> BTreeMap<u64, Line> {
+        let end = base + code.len() as u64;
+        let mut symbols: Vec<&MappingSymbol> = symbols
+            .iter()
+            .filter(|symbol| symbol.addr >= base && symbol.addr < end)
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.addr);
+        let mut regions = vec![(base, MappingKind::Code { isa: None })];
+        regions.extend(
+            symbols
+                .iter()
+                .map(|symbol| (symbol.addr, symbol.kind.clone())),
+        );
+
+        let mut lines = BTreeMap::new();
+        let mut decoder = self.clone();
+        for (i, (start, kind)) in regions.iter().enumerate() {
+            let stop = regions.get(i + 1).map_or(end, |(next, _)| *next);
+            let bytes = &code[(start - base) as usize..(stop - base) as usize];
+            let isa = match kind {
+                MappingKind::Data => {
+                    for (addr, directive) in data_directives(bytes, *start) {
+                        lines.insert(addr, Line::Data(directive));
+                    }
+                    continue;
+                }
+                MappingKind::Code { isa } => isa,
+            };
+            if let Some((_, extensions)) = isa.as_deref().and_then(parse_isa_string) {
+                decoder.extensions = Some(extensions);
+            }
+            let mut offset = 0;
+            while offset < bytes.len() {
+                let Some(raw) = fetch(bytes, offset) else {
+                    // a truncated instruction at the end of the region
+                    for (addr, directive) in
+                        data_directives(&bytes[offset..], *start + offset as u64)
+                    {
+                        lines.insert(addr, Line::Data(directive));
+                    }
+                    break;
+                };
+                let insn = decoder
+                    .disassmeble_one(raw)
+                    .unwrap_or_else(|| unknown_insn(raw));
+                let len = insn.get_len() as usize;
+                lines.insert(*start + offset as u64, Line::Insn(insn));
+                offset += len;
+            }
+        }
+        lines
+    }
 }
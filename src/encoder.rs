@@ -0,0 +1,268 @@
+use crate::args::Arg;
+use crate::disassembler::{is_compressed, Disassembler, Xlen};
+use crate::insn::Insn;
+use crate::isa::*;
+use crate::mnemonic::Mnemonic;
+use std::fmt;
+
+/// Why an instruction could not be encoded
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodeError {
+    /// No spec defines the mnemonic at this XLEN
+    UnknownMnemonic(Mnemonic),
+    MissingOperand(String),
+    /// The spec has no operand with this tag
+    UnexpectedOperand(String),
+    /// The value does not fit the field, or is misaligned for a scaled immediate
+    OutOfRange {
+        operand: String,
+        value: i64,
+    },
+    /// The value is reserved for the field, e.g. `x0` for `c.addi`, or
+    /// disagrees with another operand sharing its bits
+    Invalid {
+        operand: String,
+        value: i64,
+    },
+    /// The encoding decodes as a different instruction, e.g. `c.addi x0, 0`
+    /// is `c.nop`
+    Shadowed {
+        by: Mnemonic,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::UnknownMnemonic(mnemonic) => {
+                write!(f, "no encoding for {} at this XLEN", mnemonic)
+            }
+            EncodeError::MissingOperand(tag) => write!(f, "missing operand {}", tag),
+            EncodeError::UnexpectedOperand(tag) => write!(f, "unexpected operand {}", tag),
+            EncodeError::OutOfRange { operand, value } => {
+                write!(f, "{} = {} is out of range", operand, value)
+            }
+            EncodeError::Invalid { operand, value } => {
+                write!(f, "{} = {} is not a valid value", operand, value)
+            }
+            EncodeError::Shadowed { by } => write!(f, "encoding decodes as {}", by),
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Where one operand lives in the instruction word: `value = offset + sum of
+/// the weights of the set bits`, with a negative weight for the sign bit
+#[derive(Debug, Clone)]
+pub(crate) struct Field {
+    pub(crate) decode: fn(u32) -> (Arg, String),
+    pub(crate) tag: String,
+    offset: i64,
+    pub(crate) bits: Vec<(u32, i64)>,
+}
+
+/// Helper: Get the numeric value of a decoded argument
+pub(crate) fn arg_value(arg: Arg) -> Option<i64> {
+    match arg {
+        Arg::DstReg(val) | Arg::SrcReg(val) | Arg::Flag(val) | Arg::CSR(val) | Arg::UImm(val) => {
+            Some(val as i64)
+        }
+        Arg::Imm(val) => Some(val as i64),
+        Arg::Nothing | Arg::Error | Arg::Hint | Arg::Reserved | Arg::Illegal => None,
+    }
+}
+
+/// Helper: Get the bits of a spec's word that its operands fill in
+pub(crate) fn free_bits(spec: &Spec) -> u32 {
+    let width = if is_compressed(spec.match_bits) {
+        0xffff
+    } else {
+        0xffff_ffff
+    };
+    !spec.mask_bits & width
+}
+
+/// Helper: Get the tagged operands of a spec, decoded from a word with every
+/// operand bit set so reserved values don't hide them
+pub(crate) fn spec_operands(spec: &Spec) -> Vec<(String, Arg)> {
+    let word = spec.match_bits | free_bits(spec);
+    spec.args
+        .iter()
+        .map(|arg| {
+            let (arg, tag) = arg(word);
+            (tag, arg)
+        })
+        .filter(|(tag, arg)| !tag.is_empty() && !matches!(arg, Arg::Nothing | Arg::Error))
+        .collect()
+}
+
+/// Helper: Recover the layout of an operand by flipping each free bit and
+/// watching how its decoded value changes, so the scrambling in `args.rs`
+/// stays the single source of truth
+pub(crate) fn field_layout(spec: &Spec, arg: fn(u32) -> (Arg, String)) -> Option<Field> {
+    let free = free_bits(spec);
+    let ones = spec.match_bits | free;
+    let (top, tag) = arg(ones);
+    let top = arg_value(top)?;
+    let bits: Vec<(u32, i64)> = (0..32)
+        .filter(|bit| free & (1 << bit) != 0)
+        .filter_map(|bit| {
+            let val = arg_value(arg(ones & !(1 << bit)).0)?;
+            Some((bit, top - val)).filter(|(_, weight)| *weight != 0)
+        })
+        .collect();
+    let offset = top - bits.iter().map(|(_, weight)| weight).sum::<i64>();
+    Some(Field {
+        decode: arg,
+        tag,
+        offset,
+        bits,
+    })
+}
+
+/// Helper: Place `value` into the bits of `field`
+pub(crate) fn place(field: &Field, value: i64) -> Option<u32> {
+    let mut rest = value - field.offset;
+    let mut word = 0;
+    if let Some((bit, weight)) = field.bits.iter().find(|(_, weight)| *weight < 0) {
+        if rest < 0 {
+            word |= 1 << bit;
+            rest -= weight;
+        }
+    }
+    for (bit, weight) in field.bits.iter().filter(|(_, weight)| *weight > 0) {
+        if rest & weight != 0 {
+            word |= 1 << bit;
+            rest -= weight;
+        }
+    }
+    (rest == 0).then_some(word)
+}
+
+/// Helper: Iterate over every spec decodable at `xlen`
+pub(crate) fn specs(xlen: Xlen) -> impl Iterator<Item = &'static Spec> {
+    let compressed = match xlen {
+        Xlen::XLEN32 => &*RV_ISA_SPECS_32_COMPRESSED,
+        Xlen::XLEN64 => &*RV_ISA_SPECS_64_COMPRESSED,
+    };
+    let full = (0..0x80u8).flat_map(move |opcode| {
+        let xlen_specs = match xlen {
+            Xlen::XLEN32 => get_32_full_specs_by_opcode(opcode),
+            Xlen::XLEN64 => get_64_full_specs_by_opcode(opcode),
+        };
+        get_generic_full_specs_by_opcode(opcode)
+            .into_iter()
+            .chain(xlen_specs)
+            .flat_map(|specs| specs.iter())
+    });
+    RV_ISA_SPECS_GENERIC_COMPRESSED
+        .iter()
+        .chain(compressed.iter())
+        .chain(full)
+}
+
+/// Turns mnemonics and operands back into machine code
+#[derive(Debug, Clone)]
+pub struct Encoder {
+    xlen: Xlen,
+}
+
+impl Encoder {
+    pub fn new(xlen: Xlen) -> Self {
+        Self { xlen }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.xlen
+    }
+
+    /// Encode an instruction from operands tagged as the decoder tags them,
+    /// e.g. `[("rd", Arg::DstReg(10)), ("rs1", Arg::SrcReg(10)), ("imm", Arg::Imm(-4))]`.
+    /// Compressed instructions come back in the low 16 bits
+    pub fn encode(&self, mnemonic: Mnemonic, operands: &[(&str, Arg)]) -> Result<u32, EncodeError> {
+        let mut error = EncodeError::UnknownMnemonic(mnemonic);
+        for spec in specs(self.xlen).filter(|spec| spec.mnemonic == mnemonic) {
+            match self.encode_spec(spec, operands) {
+                Ok(word) => return Ok(word),
+                Err(err) => error = err,
+            }
+        }
+        Err(error)
+    }
+
+    /// Re-encode a decoded, possibly modified, instruction
+    pub fn encode_insn(&self, insn: &Insn) -> Result<u32, EncodeError> {
+        let args = &insn.args;
+        let mut operands: Vec<(&str, Arg)> = Vec::new();
+        for (tag, arg) in args.dst.iter().chain(&args.src).chain(&args.flags) {
+            operands.push((tag.as_str(), *arg));
+        }
+        if let Some(imm) = args.imm {
+            operands.push(("imm", imm));
+        }
+        if let Some(csr) = args.csr {
+            operands.push(("csr", csr));
+        }
+        self.encode(insn.mnemonic, &operands)
+    }
+
+    /// Helper: Encode against one spec of the mnemonic
+    fn encode_spec(&self, spec: &Spec, operands: &[(&str, Arg)]) -> Result<u32, EncodeError> {
+        let fields: Vec<Field> = spec
+            .args
+            .iter()
+            .filter_map(|arg| field_layout(spec, *arg))
+            .collect();
+        let lookup = |tag: &str| {
+            operands
+                .iter()
+                .find(|(operand, _)| *operand == tag)
+                .and_then(|(_, arg)| arg_value(*arg))
+        };
+        if let Some((tag, _)) = operands
+            .iter()
+            .find(|(tag, _)| !fields.iter().any(|field| field.tag == *tag))
+        {
+            return Err(EncodeError::UnexpectedOperand(tag.to_string()));
+        }
+
+        let mut word = spec.match_bits;
+        for field in &fields {
+            let value = match lookup(&field.tag) {
+                Some(value) => value,
+                // e.g. `rs1` of `c.add` shares its bits with `rd`
+                None if fields
+                    .iter()
+                    .any(|other| other.bits == field.bits && lookup(&other.tag).is_some()) =>
+                {
+                    continue
+                }
+                None => return Err(EncodeError::MissingOperand(field.tag.clone())),
+            };
+            word |= place(field, value).ok_or(EncodeError::OutOfRange {
+                operand: field.tag.clone(),
+                value,
+            })?;
+        }
+
+        // decoding must give back exactly what was asked for
+        for field in &fields {
+            let Some(value) = lookup(&field.tag) else {
+                continue;
+            };
+            if arg_value((field.decode)(word).0) != Some(value) {
+                return Err(EncodeError::Invalid {
+                    operand: field.tag.clone(),
+                    value,
+                });
+            }
+        }
+        let decoded = Disassembler::new(self.xlen).disassmeble_one(word);
+        match decoded.map(|insn| insn.mnemonic) {
+            Some(mnemonic) if mnemonic == spec.mnemonic => Ok(word),
+            Some(by) => Err(EncodeError::Shadowed { by }),
+            None => Err(EncodeError::UnknownMnemonic(spec.mnemonic)),
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::cfg::Cfg;
+use crate::control_flow::{FlowKind, Target};
+use crate::disassembler::Xlen;
+use crate::insn::Insn;
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+const RA: u32 = 1;
+const SP: u32 = 2;
+/// The psABI `tail` pseudo-instruction jumps through t1
+const T1: u32 = 6;
+/// How many instructions after the stack adjustment may hold the `ra` spill
+const PROLOGUE_WINDOW: usize = 4;
+
+/// Why an address was considered the start of a function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum StartReason {
+    /// Supplied by the caller, e.g. the ELF entry point
+    Root,
+    /// Target of `jal ra`, `auipc ra` + `jalr ra` or a `tail` sequence
+    CallTarget,
+    /// `addi sp, sp, -N` followed by a spill of `ra`
+    Prologue,
+    /// Zcmp `cm.push` saving `ra`
+    Push,
+    /// First instruction after a return that nothing jumps to
+    AfterReturn,
+}
+
+/// A function recovered from decoded code
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Function {
+    pub name: String,
+    pub start: u64,
+    /// Address just past the last instruction
+    pub end: u64,
+    pub reasons: BTreeSet<StartReason>,
+}
+
+impl Function {
+    pub fn contains(&self, addr: u64) -> bool {
+        self.start <= addr && addr < self.end
+    }
+}
+
+/// A synthetic symbol table of functions keyed by start address
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SymbolTable {
+    functions: BTreeMap<u64, Function>,
+}
+
+/// Helper: Check if an instruction allocates a stack frame
+fn is_stack_alloc(insn: &Insn) -> bool {
+    let sp_to_sp =
+        insn.operand("rd") == Some(SP) && insn.operand("rs1").is_none_or(|rs1| rs1 == SP);
+    match insn.mnemonic {
+        Mnemonic::Addi | Mnemonic::CAddi => sp_to_sp && insn.offset < 0,
+        Mnemonic::CAddi16sp => insn.offset < 0,
+        _ => false,
+    }
+}
+
+/// Helper: Check if an instruction spills `ra` to the stack
+fn is_ra_spill(insn: &Insn) -> bool {
+    let is_int_store = matches!(
+        insn.mnemonic,
+        Mnemonic::Sd | Mnemonic::Sw | Mnemonic::CSdsp | Mnemonic::CSwsp
+    );
+    is_int_store
+        && insn.operand("rs2") == Some(RA)
+        && insn.mem_access().is_some_and(|access| access.base == SP)
+}
+
+/// Helper: Check if an undecoded 16-bit word is a Zcmp `cm.push` saving `ra`.
+/// Zcmp reuses the Zcd encodings, so it only shows up when Zcd is disabled
+fn is_cm_push(insn: &Insn) -> bool {
+    let rlist = (insn.raw >> 4) & 0xf;
+    insn.mnemonic == Mnemonic::Unknown && insn.len == 2 && insn.raw & 0xff03 == 0xb802 && rlist >= 4
+}
+
+/// Helper: Check if an instruction only pads code between functions
+fn is_padding(insn: &Insn) -> bool {
+    match insn.mnemonic {
+        Mnemonic::CNop | Mnemonic::Unknown => true,
+        Mnemonic::Addi => insn.raw == 0x00000013,
+        _ => false,
+    }
+}
+
+impl SymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Infer function boundaries from decoded code. `roots` are addresses
+    /// known to start functions, such as the entry point.
+    ///
+    /// Zcmp is not decoded, so a `cm.push` is only recognised in code
+    /// disassembled without Zcd (e.g. `rv32imac`); with Zcd enabled the
+    /// same word decodes as `c.fsdsp` and gives no start
+    pub fn discover(insns: &HashMap<u64, Insn>, xlen: Xlen, roots: &[u64]) -> Self {
+        let mut addrs: Vec<u64> = insns.keys().copied().collect();
+        addrs.sort();
+        let mut starts: BTreeMap<u64, BTreeSet<StartReason>> = BTreeMap::new();
+        let mut add = |addr: u64, reason: StartReason| {
+            if insns.contains_key(&addr) {
+                starts.entry(addr).or_default().insert(reason);
+            }
+        };
+        for root in roots {
+            add(*root, StartReason::Root);
+        }
+
+        // anything jumped to from inside a function is not a new function
+        let jump_targets: BTreeSet<u64> = addrs
+            .iter()
+            .filter_map(|addr| {
+                let flow = insns[addr].control_flow(*addr, xlen);
+                match (flow.kind, flow.target) {
+                    (FlowKind::Branch | FlowKind::Jump, Some(Target::Direct(target))) => {
+                        Some(target)
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+
+        for (i, addr) in addrs.iter().enumerate() {
+            let insn = &insns[addr];
+            let flow = insn.control_flow(*addr, xlen);
+            let next = insns.get(&flow.fall_through);
+            if let (FlowKind::Call | FlowKind::TailCall, Some(Target::Direct(target))) =
+                (flow.kind, flow.target)
+            {
+                add(target, StartReason::CallTarget);
+            }
+            // auipc ra + jalr ra (call) and auipc t1 + jalr x0, t1 (tail)
+            if let (Mnemonic::Auipc, Some(next)) = (insn.mnemonic, next) {
+                let link = insn.operand("rd");
+                let is_call = link == Some(RA) && next.operand("rd") == Some(RA);
+                let is_tail = link == Some(T1) && next.operand("rd") == Some(0);
+                if next.mnemonic == Mnemonic::Jalr
+                    && next.operand("rs1") == link
+                    && (is_call || is_tail)
+                {
+                    let target = addr
+                        .wrapping_add(insn.offset as i64 as u64)
+                        .wrapping_add(next.offset as i64 as u64)
+                        & !1;
+                    add(target, StartReason::CallTarget);
+                }
+            }
+            if is_stack_alloc(insn) {
+                let spills_ra = addrs[i + 1..]
+                    .iter()
+                    .take(PROLOGUE_WINDOW)
+                    .map(|addr| &insns[addr])
+                    .any(is_ra_spill);
+                if spills_ra {
+                    add(*addr, StartReason::Prologue);
+                }
+            }
+            if is_cm_push(insn) {
+                add(*addr, StartReason::Push);
+            }
+            if matches!(flow.kind, FlowKind::Return | FlowKind::TailCall) {
+                let mut after = flow.fall_through;
+                while let Some(pad) = insns.get(&after).filter(|insn| is_padding(insn)) {
+                    after += pad.len as u64;
+                }
+                if !jump_targets.contains(&after) {
+                    add(after, StartReason::AfterReturn);
+                }
+            }
+        }
+
+        // each function ends with the last block it reaches before the next one
+        let cfg = Cfg::build(insns, xlen);
+        let bounds: Vec<u64> = starts.keys().copied().collect();
+        let mut table = Self::new();
+        for (i, (start, reasons)) in starts.into_iter().enumerate() {
+            let limit = bounds.get(i + 1).copied().unwrap_or(u64::MAX);
+            let end = cfg
+                .reachable(start)
+                .into_iter()
+                .filter(|block| (start..limit).contains(block))
+                .map(|block| cfg.block(block).unwrap().end)
+                .max()
+                .or_else(|| {
+                    cfg.block_containing(start)
+                        .map(|block| block.end.min(limit))
+                })
+                .unwrap_or(start);
+            table.insert(Function {
+                name: format!("sub_{:x}", start),
+                start,
+                end,
+                reasons,
+            });
+        }
+        table
+    }
+
+    /// Add a function, replacing any function with the same start
+    pub fn insert(&mut self, function: Function) {
+        self.functions.insert(function.start, function);
+    }
+
+    /// Iterate over the functions in address order
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.values()
+    }
+
+    /// Get the function starting at `start`
+    pub fn function(&self, start: u64) -> Option<&Function> {
+        self.functions.get(&start)
+    }
+
+    /// Get the function containing `addr`
+    pub fn function_containing(&self, addr: u64) -> Option<&Function> {
+        self.functions
+            .range(..=addr)
+            .next_back()
+            .map(|(_, function)| function)
+            .filter(|function| function.contains(addr))
+    }
+
+    /// Render an address as `name` or `name+0xoff`
+    pub fn symbolize(&self, addr: u64) -> Option<String> {
+        let function = self.function_containing(addr)?;
+        match addr - function.start {
+            0 => Some(function.name.clone()),
+            off => Some(format!("{}+0x{:x}", function.name, off)),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
@@ -0,0 +1,205 @@
+use crate::args::{Arg, Legality};
+use crate::disassembler::{Disassembler, Xlen};
+use crate::encoder::{field_layout, free_bits, place, spec_operands, specs};
+use crate::insn::Insn;
+use crate::isa::Spec;
+use crate::isa_meta::{Category, Extension};
+use crate::mnemonic::Mnemonic;
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::RangeInclusive;
+
+/// How many words to draw for a spec before its constraints count as
+/// unsatisfiable
+const ATTEMPTS: usize = 256;
+
+/// Rounding modes 5 and 6 are reserved
+const RESERVED_RM: [u32; 2] = [5, 6];
+
+/// A small seedable PRNG (SplitMix64), so a seed gives the same stimulus on
+/// every platform
+#[derive(Debug, Clone)]
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Helper: Get a uniform value below `bound`, which must not be 0
+    fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+/// Produces random legal instructions, for riscv-dv style stimulus
+#[derive(Debug, Clone)]
+pub struct Generator {
+    disassembler: Disassembler,
+    rng: Rng,
+    mnemonics: Option<BTreeSet<Mnemonic>>,
+    weights: BTreeMap<Category, u32>,
+    registers: Option<BTreeSet<u32>>,
+    imm_range: Option<RangeInclusive<i64>>,
+}
+
+impl Generator {
+    pub fn new(xlen: Xlen, seed: u64) -> Self {
+        Self {
+            disassembler: Disassembler::new(xlen),
+            rng: Rng::new(seed),
+            mnemonics: None,
+            weights: BTreeMap::new(),
+            registers: None,
+            imm_range: None,
+        }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.disassembler.xlen()
+    }
+
+    /// Restrict generation to the given extensions, `None` enables all of them
+    pub fn set_extensions(&mut self, extensions: Option<BTreeSet<Extension>>) {
+        self.disassembler.set_extensions(extensions);
+    }
+
+    /// Restrict generation to the given mnemonics, `None` allows all of them
+    pub fn set_mnemonics(&mut self, mnemonics: Option<BTreeSet<Mnemonic>>) {
+        self.mnemonics = mnemonics;
+    }
+
+    /// Scale how likely each instruction of `category` is. Every spec starts
+    /// at weight 1, so by default all instructions are equally likely, and
+    /// weight 0 leaves the category out
+    pub fn set_weight(&mut self, category: Category, weight: u32) {
+        self.weights.insert(category, weight);
+    }
+
+    /// Only use these register numbers for register operands, `None` allows
+    /// every register the encoding can hold
+    pub fn set_registers(&mut self, registers: Option<BTreeSet<u32>>) {
+        self.registers = registers;
+    }
+
+    /// Keep immediates within `range`. Instructions whose immediate can't
+    /// take any value of the range are not generated
+    pub fn set_imm_range(&mut self, range: Option<RangeInclusive<i64>>) {
+        self.imm_range = range;
+    }
+
+    /// Draw a random legal instruction, `None` if the constraints rule out
+    /// every enabled spec. Reserved and HINT encodings are never produced
+    pub fn next_insn(&mut self) -> Option<Insn> {
+        let mut candidates: Vec<(&'static Spec, u64)> = specs(self.xlen())
+            .filter(|spec| {
+                self.disassembler
+                    .extensions()
+                    .is_none_or(|extensions| extensions.contains(&spec.extension))
+            })
+            .filter(|spec| {
+                self.mnemonics
+                    .as_ref()
+                    .is_none_or(|mnemonics| mnemonics.contains(&spec.mnemonic))
+            })
+            .map(|spec| {
+                let weight = self.weights.get(&spec.category).copied().unwrap_or(1);
+                (spec, weight as u64)
+            })
+            .filter(|(_, weight)| *weight > 0)
+            .collect();
+        while !candidates.is_empty() {
+            let total: u64 = candidates.iter().map(|(_, weight)| weight).sum();
+            let mut pick = self.rng.below(total);
+            let index = candidates
+                .iter()
+                .position(|(_, weight)| match pick.checked_sub(*weight) {
+                    Some(rest) => {
+                        pick = rest;
+                        false
+                    }
+                    None => true,
+                })
+                .unwrap_or(candidates.len() - 1);
+            if let Some(insn) = self.draw(candidates[index].0) {
+                return Some(insn);
+            }
+            candidates.swap_remove(index);
+        }
+        None
+    }
+
+    /// Draw `count` instructions, fewer if the constraints run out of them
+    pub fn generate(&mut self, count: usize) -> Vec<Insn> {
+        (0..count).map_while(|_| self.next_insn()).collect()
+    }
+
+    /// Helper: Draw an encoding of `spec` that meets the constraints
+    fn draw(&mut self, spec: &Spec) -> Option<Insn> {
+        let kinds = spec_operands(spec);
+        let fields: Vec<_> = spec
+            .args
+            .iter()
+            .filter_map(|arg| field_layout(spec, *arg))
+            .collect();
+        'attempt: for _ in 0..ATTEMPTS {
+            let mut word = spec.match_bits | (self.rng.next_u64() as u32 & free_bits(spec));
+            // random bits already cover unconstrained operands
+            for field in &fields {
+                let kind = kinds.iter().find(|(tag, _)| *tag == field.tag);
+                let value = match (kind.map(|(_, arg)| arg), &self.registers, &self.imm_range) {
+                    (Some(Arg::DstReg(_) | Arg::SrcReg(_)), Some(registers), _) => {
+                        let index = self.rng.below(registers.len().max(1) as u64);
+                        *registers.iter().nth(index as usize)? as i64
+                    }
+                    (Some(Arg::Imm(_) | Arg::UImm(_)), _, Some(range)) if field.tag == "imm" => {
+                        let span = range.end().saturating_sub(*range.start()) as u64;
+                        range.start() + self.rng.below(span.saturating_add(1)) as i64
+                    }
+                    _ => continue,
+                };
+                let Some(bits) = place(field, value) else {
+                    continue 'attempt;
+                };
+                let mask = field.bits.iter().fold(0, |mask, (bit, _)| mask | 1 << bit);
+                word = (word & !mask) | bits;
+            }
+            let Some(insn) = self.disassembler.disassmeble_one(word) else {
+                continue;
+            };
+            if insn.mnemonic == spec.mnemonic && self.is_legal(&insn) {
+                return Some(insn);
+            }
+        }
+        None
+    }
+
+    /// Helper: Check the constraints on a drawn instruction
+    fn is_legal(&self, insn: &Insn) -> bool {
+        let args = &insn.args;
+        let regs_ok = self.registers.as_ref().is_none_or(|registers| {
+            args.dst
+                .values()
+                .chain(args.src.values())
+                .all(|reg| registers.contains(&reg.get_val()))
+        });
+        let imm_ok = match (&self.imm_range, args.imm) {
+            (Some(range), Some(imm)) => range.contains(&(imm.get_val_signed_imm() as i64)),
+            _ => true,
+        };
+        let rm_ok = args
+            .flags
+            .get("rm")
+            .is_none_or(|rm| !RESERVED_RM.contains(&rm.get_val()));
+        regs_ok && imm_ok && rm_ok && insn.legality == Legality::Legal
+    }
+}
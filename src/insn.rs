@@ -1,16 +1,29 @@
-use crate::args::Arg;
+use crate::args::{Arg, Legality};
+use crate::control_flow::{control_flow, ControlFlow};
+use crate::disassembler::Xlen;
+use crate::isa_meta::{Category, Extension, Format};
+use crate::mem_access::{mem_access, MemAccess};
+use crate::mnemonic::Mnemonic;
+use crate::reg::{implicit_operands, reg_sets, Reg, RegSets};
+use crate::rvc::{compress, expand};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-
-const BRANCH_OPCODES: &[&str] = &[
-    "beq", "bge", "bgeu", "blt", "bltu", "bne", "beqz", "bnez", "bgez", "blez", "bltz", "bgtz",
-    "bgt", "ble", "bgtu", "bleu", "c.beqz", "c.bnez", "c.bltz", "c.bgez",
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+const BRANCH_OPCODES: &[Mnemonic] = &[
+    Mnemonic::Beq,
+    Mnemonic::Bge,
+    Mnemonic::Bgeu,
+    Mnemonic::Blt,
+    Mnemonic::Bltu,
+    Mnemonic::Bne,
+    Mnemonic::CBeqz,
+    Mnemonic::CBnez,
 ];
-const IJ_OPCODES: &[&str] = &["jal", "j", "call", "tail", "c.j", "c.jal"];
-const UJ_OPCODES: &[&str] = &["jalr", "jr", "c.jr", "c.jalr", "ret"];
+const IJ_OPCODES: &[Mnemonic] = &[Mnemonic::Jal, Mnemonic::CJ, Mnemonic::CJal];
+const UJ_OPCODES: &[Mnemonic] = &[Mnemonic::Jalr, Mnemonic::CJr, Mnemonic::CJalr];
 
 const BRANCH_MASK: u8 = 0x01;
-const BRANCH_OFFSET: u8 = 0;
 const IJ_MASK: u8 = 0x02;
 const IJ_OFFSET: u8 = 1;
 const UJ_MASK: u8 = 0x04;
@@ -24,6 +37,12 @@ pub struct InsnArgs {
     pub dst: HashMap<String, Arg>,
     pub flags: HashMap<String, Arg>,
     pub csr: Option<Arg>,
+    /// Registers read but not encoded, e.g. `sp` for `c.lwsp`
+    #[serde(default)]
+    pub implicit_src: Vec<Reg>,
+    /// Registers written but not encoded, e.g. `ra` for `c.jal`
+    #[serde(default)]
+    pub implicit_dst: Vec<Reg>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,7 +52,11 @@ pub struct Insn {
     pub offset: i32,
     pub raw: u32,
     pub name: String,
+    pub mnemonic: Mnemonic,
     pub args: Box<InsnArgs>,
+    /// Whether the encoding is legal, a HINT, reserved or illegal
+    #[serde(default)]
+    pub legality: Legality,
 }
 
 /// Helper: Get the size of the instruction in bytes
@@ -42,7 +65,7 @@ fn get_insn_size(raw: u32) -> u8 {
 }
 
 /// Helper: Convert a tag to a string
-fn tag_to_string(tag: &str) -> String {
+pub(crate) fn tag_to_string(tag: &str) -> String {
     match tag {
         "rd" | "rs1" | "rs2" | "rs3" => "x".to_string(),
         "fd" | "fs1" | "fs2" | "fs3" => "f".to_string(),
@@ -52,42 +75,47 @@ fn tag_to_string(tag: &str) -> String {
 }
 
 impl Insn {
+    /// Takes the mnemonic rather than its name, which is derived from it
     pub fn new(
         raw: u32,
-        name: &str,
+        mnemonic: Mnemonic,
         src: HashMap<String, Arg>,
         imm: Option<Arg>,
         dst: HashMap<String, Arg>,
         flags: HashMap<String, Arg>,
         csr: Option<Arg>,
     ) -> Self {
-        
         let offset = match imm {
             Some(imm) => imm.get_val_signed_imm(),
             None => 0,
         };
 
-        let is_branch = BRANCH_OPCODES.contains(&name);
-        let is_direct_jump = IJ_OPCODES.contains(&name);
-        let is_indirect_jump = UJ_OPCODES.contains(&name);
+        let is_branch = BRANCH_OPCODES.contains(&mnemonic);
+        let is_direct_jump = IJ_OPCODES.contains(&mnemonic);
+        let is_indirect_jump = UJ_OPCODES.contains(&mnemonic);
         let kind_mask = (is_branch as u8)
             | ((is_direct_jump as u8) << IJ_OFFSET)
             | ((is_indirect_jump as u8) << UJ_OFFSET);
-        
+
+        let (implicit_src, implicit_dst) = implicit_operands(mnemonic, &flags);
         let args = InsnArgs {
             imm,
             src,
             dst,
             flags,
             csr,
+            implicit_src,
+            implicit_dst,
         };
         Self {
             len: get_insn_size(raw),
             offset,
             kind_mask,
             raw,
-            name: name.to_string(),
+            name: mnemonic.to_string(),
+            mnemonic,
             args: Box::new(args),
+            legality: Legality::Legal,
         }
     }
 
@@ -103,18 +131,83 @@ impl Insn {
         self.name.clone()
     }
 
+    pub fn get_mnemonic(&self) -> Mnemonic {
+        self.mnemonic
+    }
+
+    /// Helper: Get the extension the instruction is defined in
+    pub fn extension(&self) -> Extension {
+        self.mnemonic.extension()
+    }
+
+    /// Helper: Get the encoding format of the instruction
+    pub fn format(&self) -> Format {
+        self.mnemonic.format()
+    }
+
+    /// Helper: Get the functional category of the instruction
+    pub fn category(&self) -> Category {
+        self.mnemonic.category()
+    }
+
+    /// Helper: Get the memory access performed by the instruction, if any
+    pub fn mem_access(&self) -> Option<MemAccess> {
+        mem_access(self)
+    }
+
+    /// Helper: Resolve the control-flow effect of the instruction at `pc`
+    pub fn control_flow(&self, pc: u64, xlen: Xlen) -> ControlFlow {
+        control_flow(self, pc, xlen)
+    }
+
+    /// Helper: Expand a compressed instruction into its 32-bit equivalent
+    pub fn expand(&self, xlen: Xlen) -> Option<Insn> {
+        expand(self, xlen)
+    }
+
+    /// Helper: Get the 16-bit encoding of a 32-bit instruction, if one of
+    /// the enabled compressed extensions has it
+    pub fn compress(&self, xlen: Xlen, extensions: Option<&BTreeSet<Extension>>) -> Option<u16> {
+        compress(self, xlen, extensions)
+    }
+
+    /// Helper: Get the registers read and written, expanding vector
+    /// register groups by `lmul` when it is known
+    pub fn reg_sets(&self, lmul: Option<u32>) -> RegSets {
+        reg_sets(self, lmul)
+    }
+
+    /// Helper: Get the registers read, assuming LMUL=1
+    pub fn regs_read(&self) -> BTreeSet<Reg> {
+        reg_sets(self, None).read
+    }
+
+    /// Helper: Get the registers written, assuming LMUL=1
+    pub fn regs_written(&self) -> BTreeSet<Reg> {
+        reg_sets(self, None).written
+    }
+
     pub fn get_src(&self) -> HashMap<String, Arg> {
         self.args.src.clone()
     }
 
     pub fn get_imm(&self) -> Option<Arg> {
-        self.args.imm.clone()
+        self.args.imm
     }
 
     pub fn get_dst(&self) -> HashMap<String, Arg> {
         self.args.dst.clone()
     }
 
+    /// Helper: Get the register number of a source or destination operand
+    pub fn operand(&self, tag: &str) -> Option<u32> {
+        self.args
+            .dst
+            .get(tag)
+            .or_else(|| self.args.src.get(tag))
+            .map(|arg| arg.get_val())
+    }
+
     pub fn is_branch(&self) -> bool {
         self.kind_mask & BRANCH_MASK != 0
     }
@@ -132,7 +225,7 @@ impl Insn {
     }
 
     /// Helper: Format the instruction to a string representation
-    pub fn to_string(&self) -> String {
+    fn format_operands(&self) -> String {
         // Format the instruction name
         let mut parts = vec![self.name.clone()];
 
@@ -142,18 +235,14 @@ impl Insn {
 
         // Add dst args
         for (k, v) in &self.args.dst {
-            operands.push(format!("{}{}", tag_to_string(k), v.to_string()));
+            operands.push(format!("{}{}", tag_to_string(k), v));
         }
 
         // Add src args - sort by tag, not by value
         let mut src_tags = self.args.src.keys().collect::<Vec<&String>>();
         src_tags.sort();
         for tag in src_tags {
-            operands.push(format!(
-                "{}{}",
-                tag_to_string(tag),
-                self.args.src[tag].to_string()
-            ));
+            operands.push(format!("{}{}", tag_to_string(tag), self.args.src[tag]));
         }
 
         // Add imm arg
@@ -163,7 +252,7 @@ impl Insn {
 
         // Add csr arg
         if let Some(csr) = &self.args.csr {
-            operands.push(format!("CSR#{}", csr.to_string()));
+            operands.push(format!("CSR#{}", csr));
         }
 
         // Join all operands with commas
@@ -177,6 +266,17 @@ impl Insn {
 
     /// Helper: Format the instruction to a canonicalized string representation
     pub fn to_canonical(&self) -> String {
+        self.canonical(false)
+    }
+
+    /// Like `to_canonical`, also listing flags such as `RM` and `VM`, so
+    /// that `CanonicalParser` reads the line back losslessly
+    pub fn to_canonical_with_flags(&self) -> String {
+        self.canonical(true)
+    }
+
+    /// Helper: Format the canonical form, optionally with the flags
+    fn canonical(&self, with_flags: bool) -> String {
         // Format the instruction name
         let mut parts = vec![self.name.clone()];
 
@@ -185,12 +285,7 @@ impl Insn {
 
         // Add dst args
         for (k, v) in &self.args.dst {
-            operands.push(format!(
-                "{} {}{}",
-                k.to_uppercase(),
-                tag_to_string(k),
-                v.to_string()
-            ));
+            operands.push(format!("{} {}{}", k.to_uppercase(), tag_to_string(k), v));
         }
 
         // Add src args - sort by tag
@@ -201,21 +296,28 @@ impl Insn {
                 "{} {}{}",
                 tag.to_uppercase(),
                 tag_to_string(tag),
-                self.args.src[tag].to_string()
+                self.args.src[tag]
             ));
         }
 
         // Add imm arg
         if let Some(imm) = &self.args.imm {
-            operands.push(format!("{} {}", "IMM", imm.to_string()));
+            operands.push(format!("{} {}", "IMM", imm));
         }
 
         // Add csr arg
         if let Some(csr) = &self.args.csr {
-            operands.push(format!("{} {}", "CSR", csr.to_string()));
+            operands.push(format!("{} {}", "CSR", csr));
         }
 
-        // TODO: add flags
+        // Add flags - sorted by tag, so the line re-encodes losslessly
+        if with_flags {
+            let mut flag_tags = self.args.flags.keys().collect::<Vec<&String>>();
+            flag_tags.sort();
+            for tag in flag_tags {
+                operands.push(format!("{} {}", tag.to_uppercase(), self.args.flags[tag]));
+            }
+        }
 
         // Join all operands with commas
         if !operands.is_empty() {
@@ -226,3 +328,9 @@ impl Insn {
         parts.join(" ")
     }
 }
+
+impl fmt::Display for Insn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.format_operands())
+    }
+}
@@ -0,0 +1,1976 @@
+/* Maintained by hand: give every Mnemonic its extension, format and category.
+ * This deliberately departs from generating them out of the riscv-opcodes
+ * extension files, which are not vendored here. tests/isa_meta.rs checks
+ * them against the spec tables instead: XLEN-specific extensions only in
+ * their XLEN's tables, compressed formats and extensions only on 16-bit
+ * specs, and no variant left Unknown
+ */
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The riscv-opcodes extension file an instruction is defined in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Extension {
+    RvI,
+    Rv64I,
+    RvM,
+    Rv64M,
+    RvA,
+    Rv64A,
+    RvF,
+    Rv64F,
+    RvD,
+    Rv64D,
+    RvC,
+    Rv32C,
+    Rv64C,
+    RvCD,
+    Rv32CF,
+    RvZcb,
+    RvV,
+    RvZicsr,
+    RvS,
+    RvSystem,
+    Unknown,
+}
+
+/// The encoding format of an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Format {
+    R,
+    R4,
+    I,
+    S,
+    B,
+    U,
+    J,
+    Cr,
+    Ci,
+    Css,
+    Ciw,
+    Cl,
+    Cs,
+    Ca,
+    Cb,
+    Cj,
+    Vl,
+    Vs,
+    Va,
+    Vset,
+    Unknown,
+}
+
+/// The functional category of an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Category {
+    Load,
+    Store,
+    Alu,
+    MulDiv,
+    Fp,
+    Vector,
+    Csr,
+    System,
+    Fence,
+    Atomic,
+    Branch,
+    Jump,
+    Unknown,
+}
+
+/// All extensions known to the decoder, excluding `Unknown`
+pub const ALL_EXTENSIONS: &[Extension] = &[
+    Extension::RvI,
+    Extension::Rv64I,
+    Extension::RvM,
+    Extension::Rv64M,
+    Extension::RvA,
+    Extension::Rv64A,
+    Extension::RvF,
+    Extension::Rv64F,
+    Extension::RvD,
+    Extension::Rv64D,
+    Extension::RvC,
+    Extension::Rv32C,
+    Extension::Rv64C,
+    Extension::RvCD,
+    Extension::Rv32CF,
+    Extension::RvZcb,
+    Extension::RvV,
+    Extension::RvZicsr,
+    Extension::RvS,
+    Extension::RvSystem,
+];
+
+impl Extension {
+    /// Helper: Get the riscv-opcodes name of the extension
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Extension::RvI => "rv_i",
+            Extension::Rv64I => "rv64_i",
+            Extension::RvM => "rv_m",
+            Extension::Rv64M => "rv64_m",
+            Extension::RvA => "rv_a",
+            Extension::Rv64A => "rv64_a",
+            Extension::RvF => "rv_f",
+            Extension::Rv64F => "rv64_f",
+            Extension::RvD => "rv_d",
+            Extension::Rv64D => "rv64_d",
+            Extension::RvC => "rv_c",
+            Extension::Rv32C => "rv32_c",
+            Extension::Rv64C => "rv64_c",
+            Extension::RvCD => "rv_c_d",
+            Extension::Rv32CF => "rv32_c_f",
+            Extension::RvZcb => "rv_zcb",
+            Extension::RvV => "rv_v",
+            Extension::RvZicsr => "rv_zicsr",
+            Extension::RvS => "rv_s",
+            Extension::RvSystem => "rv_system",
+            Extension::Unknown => "unknown",
+        }
+    }
+}
+
+impl Format {
+    /// Helper: Get the conventional name of the format
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Format::R => "R",
+            Format::R4 => "R4",
+            Format::I => "I",
+            Format::S => "S",
+            Format::B => "B",
+            Format::U => "U",
+            Format::J => "J",
+            Format::Cr => "CR",
+            Format::Ci => "CI",
+            Format::Css => "CSS",
+            Format::Ciw => "CIW",
+            Format::Cl => "CL",
+            Format::Cs => "CS",
+            Format::Ca => "CA",
+            Format::Cb => "CB",
+            Format::Cj => "CJ",
+            Format::Vl => "VL",
+            Format::Vs => "VS",
+            Format::Va => "VA",
+            Format::Vset => "VSET",
+            Format::Unknown => "unknown",
+        }
+    }
+}
+
+impl Category {
+    /// Helper: Get the name of the category
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Load => "load",
+            Category::Store => "store",
+            Category::Alu => "alu",
+            Category::MulDiv => "mul_div",
+            Category::Fp => "fp",
+            Category::Vector => "vector",
+            Category::Csr => "csr",
+            Category::System => "system",
+            Category::Fence => "fence",
+            Category::Atomic => "atomic",
+            Category::Branch => "branch",
+            Category::Jump => "jump",
+            Category::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Extension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Mnemonic {
+    /// Helper: Get the extension of the instruction
+    pub fn extension(&self) -> Extension {
+        match self {
+            Mnemonic::Add
+            | Mnemonic::Addi
+            | Mnemonic::And
+            | Mnemonic::Andi
+            | Mnemonic::Auipc
+            | Mnemonic::Beq
+            | Mnemonic::Bge
+            | Mnemonic::Bgeu
+            | Mnemonic::Blt
+            | Mnemonic::Bltu
+            | Mnemonic::Bne
+            | Mnemonic::Ebreak
+            | Mnemonic::Ecall
+            | Mnemonic::Fence
+            | Mnemonic::Jal
+            | Mnemonic::Jalr
+            | Mnemonic::Lb
+            | Mnemonic::Lbu
+            | Mnemonic::Lh
+            | Mnemonic::Lhu
+            | Mnemonic::Lui
+            | Mnemonic::Lw
+            | Mnemonic::Or
+            | Mnemonic::Ori
+            | Mnemonic::Sb
+            | Mnemonic::Sh
+            | Mnemonic::Sll
+            | Mnemonic::Slli
+            | Mnemonic::Slt
+            | Mnemonic::Slti
+            | Mnemonic::Sltiu
+            | Mnemonic::Sltu
+            | Mnemonic::Sra
+            | Mnemonic::Srai
+            | Mnemonic::Srl
+            | Mnemonic::Srli
+            | Mnemonic::Sub
+            | Mnemonic::Sw
+            | Mnemonic::Xor
+            | Mnemonic::Xori => Extension::RvI,
+            Mnemonic::Addiw
+            | Mnemonic::Addw
+            | Mnemonic::Ld
+            | Mnemonic::Lwu
+            | Mnemonic::Sd
+            | Mnemonic::Slliw
+            | Mnemonic::Sllw
+            | Mnemonic::Sraiw
+            | Mnemonic::Sraw
+            | Mnemonic::Srliw
+            | Mnemonic::Srlw
+            | Mnemonic::Subw => Extension::Rv64I,
+            Mnemonic::AmoaddD
+            | Mnemonic::AmoandD
+            | Mnemonic::AmomaxD
+            | Mnemonic::AmomaxuD
+            | Mnemonic::AmominD
+            | Mnemonic::AmominuD
+            | Mnemonic::AmoorD
+            | Mnemonic::AmoswapD
+            | Mnemonic::AmoxorD
+            | Mnemonic::LrD
+            | Mnemonic::ScD => Extension::Rv64A,
+            Mnemonic::AmoaddW
+            | Mnemonic::AmoandW
+            | Mnemonic::AmomaxW
+            | Mnemonic::AmomaxuW
+            | Mnemonic::AmominW
+            | Mnemonic::AmominuW
+            | Mnemonic::AmoorW
+            | Mnemonic::AmoswapW
+            | Mnemonic::AmoxorW
+            | Mnemonic::LrW
+            | Mnemonic::ScW => Extension::RvA,
+            Mnemonic::CAdd
+            | Mnemonic::CAddi
+            | Mnemonic::CAddi16sp
+            | Mnemonic::CAddi4spn
+            | Mnemonic::CAnd
+            | Mnemonic::CAndi
+            | Mnemonic::CBeqz
+            | Mnemonic::CBnez
+            | Mnemonic::CEbreak
+            | Mnemonic::CJ
+            | Mnemonic::CJalr
+            | Mnemonic::CJr
+            | Mnemonic::CLi
+            | Mnemonic::CLui
+            | Mnemonic::CLw
+            | Mnemonic::CLwsp
+            | Mnemonic::CMv
+            | Mnemonic::CNop
+            | Mnemonic::COr
+            | Mnemonic::CSlli
+            | Mnemonic::CSrai
+            | Mnemonic::CSrli
+            | Mnemonic::CSub
+            | Mnemonic::CSw
+            | Mnemonic::CSwsp
+            | Mnemonic::CXor => Extension::RvC,
+            Mnemonic::CAddiw
+            | Mnemonic::CAddw
+            | Mnemonic::CLd
+            | Mnemonic::CLdsp
+            | Mnemonic::CSd
+            | Mnemonic::CSdsp
+            | Mnemonic::CSubw => Extension::Rv64C,
+            Mnemonic::CFld | Mnemonic::CFldsp | Mnemonic::CFsd | Mnemonic::CFsdsp => {
+                Extension::RvCD
+            }
+            Mnemonic::CFlw | Mnemonic::CFlwsp | Mnemonic::CFsw | Mnemonic::CFswsp => {
+                Extension::Rv32CF
+            }
+            Mnemonic::CJal => Extension::Rv32C,
+            Mnemonic::CLbu
+            | Mnemonic::CLh
+            | Mnemonic::CLhu
+            | Mnemonic::CMul
+            | Mnemonic::CNot
+            | Mnemonic::CSb
+            | Mnemonic::CSextB
+            | Mnemonic::CSextH
+            | Mnemonic::CSh
+            | Mnemonic::CZextB
+            | Mnemonic::CZextH
+            | Mnemonic::CZextW => Extension::RvZcb,
+            Mnemonic::Csrrc
+            | Mnemonic::Csrrci
+            | Mnemonic::Csrrs
+            | Mnemonic::Csrrsi
+            | Mnemonic::Csrrw
+            | Mnemonic::Csrrwi => Extension::RvZicsr,
+            Mnemonic::Div
+            | Mnemonic::Divu
+            | Mnemonic::Mul
+            | Mnemonic::Mulh
+            | Mnemonic::Mulhsu
+            | Mnemonic::Mulhu
+            | Mnemonic::Rem
+            | Mnemonic::Remu => Extension::RvM,
+            Mnemonic::Divuw
+            | Mnemonic::Divw
+            | Mnemonic::Mulw
+            | Mnemonic::Remuw
+            | Mnemonic::Remw => Extension::Rv64M,
+            Mnemonic::FaddD
+            | Mnemonic::FclassD
+            | Mnemonic::FcvtDS
+            | Mnemonic::FcvtDW
+            | Mnemonic::FcvtDWu
+            | Mnemonic::FcvtSD
+            | Mnemonic::FcvtWD
+            | Mnemonic::FcvtWuD
+            | Mnemonic::FdivD
+            | Mnemonic::FeqD
+            | Mnemonic::Fld
+            | Mnemonic::FleD
+            | Mnemonic::FltD
+            | Mnemonic::FmaddD
+            | Mnemonic::FmaxD
+            | Mnemonic::FminD
+            | Mnemonic::FmsubD
+            | Mnemonic::FmulD
+            | Mnemonic::FnmaddD
+            | Mnemonic::FnmsubD
+            | Mnemonic::Fsd
+            | Mnemonic::FsgnjD
+            | Mnemonic::FsgnjnD
+            | Mnemonic::FsgnjxD
+            | Mnemonic::FsqrtD
+            | Mnemonic::FsubD => Extension::RvD,
+            Mnemonic::FaddS
+            | Mnemonic::FclassS
+            | Mnemonic::FcvtSW
+            | Mnemonic::FcvtSWu
+            | Mnemonic::FcvtWS
+            | Mnemonic::FcvtWuS
+            | Mnemonic::FdivS
+            | Mnemonic::FeqS
+            | Mnemonic::FleS
+            | Mnemonic::FltS
+            | Mnemonic::Flw
+            | Mnemonic::FmaddS
+            | Mnemonic::FmaxS
+            | Mnemonic::FminS
+            | Mnemonic::FmsubS
+            | Mnemonic::FmulS
+            | Mnemonic::FmvWX
+            | Mnemonic::FmvXW
+            | Mnemonic::FnmaddS
+            | Mnemonic::FnmsubS
+            | Mnemonic::FsgnjS
+            | Mnemonic::FsgnjnS
+            | Mnemonic::FsgnjxS
+            | Mnemonic::FsqrtS
+            | Mnemonic::FsubS
+            | Mnemonic::Fsw => Extension::RvF,
+            Mnemonic::FcvtDL
+            | Mnemonic::FcvtDLu
+            | Mnemonic::FcvtLD
+            | Mnemonic::FcvtLuD
+            | Mnemonic::FmvDX
+            | Mnemonic::FmvXD => Extension::Rv64D,
+            Mnemonic::FcvtLS | Mnemonic::FcvtLuS | Mnemonic::FcvtSL | Mnemonic::FcvtSLu => {
+                Extension::Rv64F
+            }
+            Mnemonic::Mret | Mnemonic::Sret | Mnemonic::Wfi => Extension::RvSystem,
+            Mnemonic::SfenceVma => Extension::RvS,
+            Mnemonic::VaaddVv
+            | Mnemonic::VaaddVx
+            | Mnemonic::VaadduVv
+            | Mnemonic::VaadduVx
+            | Mnemonic::VadcVim
+            | Mnemonic::VadcVvm
+            | Mnemonic::VadcVxm
+            | Mnemonic::VaddVi
+            | Mnemonic::VaddVv
+            | Mnemonic::VaddVx
+            | Mnemonic::VandVi
+            | Mnemonic::VandVv
+            | Mnemonic::VandVx
+            | Mnemonic::VasubVv
+            | Mnemonic::VasubVx
+            | Mnemonic::VasubuVv
+            | Mnemonic::VasubuVx
+            | Mnemonic::VcompressVm
+            | Mnemonic::VcpopM
+            | Mnemonic::VdivVv
+            | Mnemonic::VdivVx
+            | Mnemonic::VdivuVv
+            | Mnemonic::VdivuVx
+            | Mnemonic::VfaddVf
+            | Mnemonic::VfaddVv
+            | Mnemonic::VfclassV
+            | Mnemonic::VfcvtFXV
+            | Mnemonic::VfcvtFXuV
+            | Mnemonic::VfcvtRtzXFV
+            | Mnemonic::VfcvtRtzXuFV
+            | Mnemonic::VfcvtXFV
+            | Mnemonic::VfcvtXuFV
+            | Mnemonic::VfdivVf
+            | Mnemonic::VfdivVv
+            | Mnemonic::VfirstM
+            | Mnemonic::VfmaccVf
+            | Mnemonic::VfmaccVv
+            | Mnemonic::VfmaddVf
+            | Mnemonic::VfmaddVv
+            | Mnemonic::VfmaxVf
+            | Mnemonic::VfmaxVv
+            | Mnemonic::VfmergeVfm
+            | Mnemonic::VfminVf
+            | Mnemonic::VfminVv
+            | Mnemonic::VfmsacVf
+            | Mnemonic::VfmsacVv
+            | Mnemonic::VfmsubVf
+            | Mnemonic::VfmsubVv
+            | Mnemonic::VfmulVf
+            | Mnemonic::VfmulVv
+            | Mnemonic::VfmvFS
+            | Mnemonic::VfmvSF
+            | Mnemonic::VfmvVF
+            | Mnemonic::VfncvtFFW
+            | Mnemonic::VfncvtFXW
+            | Mnemonic::VfncvtFXuW
+            | Mnemonic::VfncvtRodFFW
+            | Mnemonic::VfncvtRtzXFW
+            | Mnemonic::VfncvtRtzXuFW
+            | Mnemonic::VfncvtXFW
+            | Mnemonic::VfncvtXuFW
+            | Mnemonic::VfnmaccVf
+            | Mnemonic::VfnmaccVv
+            | Mnemonic::VfnmaddVf
+            | Mnemonic::VfnmaddVv
+            | Mnemonic::VfnmsacVf
+            | Mnemonic::VfnmsacVv
+            | Mnemonic::VfnmsubVf
+            | Mnemonic::VfnmsubVv
+            | Mnemonic::VfrdivVf
+            | Mnemonic::Vfrec7V
+            | Mnemonic::VfredmaxVs
+            | Mnemonic::VfredminVs
+            | Mnemonic::VfredosumVs
+            | Mnemonic::VfredusumVs
+            | Mnemonic::Vfrsqrt7V
+            | Mnemonic::VfrsubVf
+            | Mnemonic::VfsgnjVf
+            | Mnemonic::VfsgnjVv
+            | Mnemonic::VfsgnjnVf
+            | Mnemonic::VfsgnjnVv
+            | Mnemonic::VfsgnjxVf
+            | Mnemonic::VfsgnjxVv
+            | Mnemonic::Vfslide1downVf
+            | Mnemonic::Vfslide1upVf
+            | Mnemonic::VfsqrtV
+            | Mnemonic::VfsubVf
+            | Mnemonic::VfsubVv
+            | Mnemonic::VfwaddVf
+            | Mnemonic::VfwaddVv
+            | Mnemonic::VfwaddWf
+            | Mnemonic::VfwaddWv
+            | Mnemonic::VfwcvtFFV
+            | Mnemonic::VfwcvtFXV
+            | Mnemonic::VfwcvtFXuV
+            | Mnemonic::VfwcvtRtzXFV
+            | Mnemonic::VfwcvtRtzXuFV
+            | Mnemonic::VfwcvtXFV
+            | Mnemonic::VfwcvtXuFV
+            | Mnemonic::VfwmaccVf
+            | Mnemonic::VfwmaccVv
+            | Mnemonic::VfwmsacVf
+            | Mnemonic::VfwmsacVv
+            | Mnemonic::VfwmulVf
+            | Mnemonic::VfwmulVv
+            | Mnemonic::VfwnmaccVf
+            | Mnemonic::VfwnmaccVv
+            | Mnemonic::VfwnmsacVf
+            | Mnemonic::VfwnmsacVv
+            | Mnemonic::VfwredosumVs
+            | Mnemonic::VfwredusumVs
+            | Mnemonic::VfwsubVf
+            | Mnemonic::VfwsubVv
+            | Mnemonic::VfwsubWf
+            | Mnemonic::VfwsubWv
+            | Mnemonic::VidV
+            | Mnemonic::ViotaM
+            | Mnemonic::Vl1re16V
+            | Mnemonic::Vl1re32V
+            | Mnemonic::Vl1re64V
+            | Mnemonic::Vl1re8V
+            | Mnemonic::Vl2re16V
+            | Mnemonic::Vl2re32V
+            | Mnemonic::Vl2re64V
+            | Mnemonic::Vl2re8V
+            | Mnemonic::Vl4re16V
+            | Mnemonic::Vl4re32V
+            | Mnemonic::Vl4re64V
+            | Mnemonic::Vl4re8V
+            | Mnemonic::Vl8re16V
+            | Mnemonic::Vl8re32V
+            | Mnemonic::Vl8re64V
+            | Mnemonic::Vl8re8V
+            | Mnemonic::Vle16V
+            | Mnemonic::Vle16ffV
+            | Mnemonic::Vle32V
+            | Mnemonic::Vle32ffV
+            | Mnemonic::Vle64V
+            | Mnemonic::Vle64ffV
+            | Mnemonic::Vle8V
+            | Mnemonic::Vle8ffV
+            | Mnemonic::VlmV
+            | Mnemonic::Vloxei16V
+            | Mnemonic::Vloxei32V
+            | Mnemonic::Vloxei64V
+            | Mnemonic::Vloxei8V
+            | Mnemonic::Vlse16V
+            | Mnemonic::Vlse32V
+            | Mnemonic::Vlse64V
+            | Mnemonic::Vlse8V
+            | Mnemonic::Vluxei16V
+            | Mnemonic::Vluxei32V
+            | Mnemonic::Vluxei64V
+            | Mnemonic::Vluxei8V
+            | Mnemonic::VmaccVv
+            | Mnemonic::VmaccVx
+            | Mnemonic::VmadcVi
+            | Mnemonic::VmadcVim
+            | Mnemonic::VmadcVv
+            | Mnemonic::VmadcVvm
+            | Mnemonic::VmadcVx
+            | Mnemonic::VmadcVxm
+            | Mnemonic::VmaddVv
+            | Mnemonic::VmaddVx
+            | Mnemonic::VmandMm
+            | Mnemonic::VmandnMm
+            | Mnemonic::VmaxVv
+            | Mnemonic::VmaxVx
+            | Mnemonic::VmaxuVv
+            | Mnemonic::VmaxuVx
+            | Mnemonic::VmergeVim
+            | Mnemonic::VmergeVvm
+            | Mnemonic::VmergeVxm
+            | Mnemonic::VmfeqVf
+            | Mnemonic::VmfeqVv
+            | Mnemonic::VmfgeVf
+            | Mnemonic::VmfgtVf
+            | Mnemonic::VmfleVf
+            | Mnemonic::VmfleVv
+            | Mnemonic::VmfltVf
+            | Mnemonic::VmfltVv
+            | Mnemonic::VmfneVf
+            | Mnemonic::VmfneVv
+            | Mnemonic::VminVv
+            | Mnemonic::VminVx
+            | Mnemonic::VminuVv
+            | Mnemonic::VminuVx
+            | Mnemonic::VmnandMm
+            | Mnemonic::VmnorMm
+            | Mnemonic::VmorMm
+            | Mnemonic::VmornMm
+            | Mnemonic::VmsbcVv
+            | Mnemonic::VmsbcVvm
+            | Mnemonic::VmsbcVx
+            | Mnemonic::VmsbcVxm
+            | Mnemonic::VmsbfM
+            | Mnemonic::VmseqVi
+            | Mnemonic::VmseqVv
+            | Mnemonic::VmseqVx
+            | Mnemonic::VmsgtVi
+            | Mnemonic::VmsgtVx
+            | Mnemonic::VmsgtuVi
+            | Mnemonic::VmsgtuVx
+            | Mnemonic::VmsifM
+            | Mnemonic::VmsleVi
+            | Mnemonic::VmsleVv
+            | Mnemonic::VmsleVx
+            | Mnemonic::VmsleuVi
+            | Mnemonic::VmsleuVv
+            | Mnemonic::VmsleuVx
+            | Mnemonic::VmsltVv
+            | Mnemonic::VmsltVx
+            | Mnemonic::VmsltuVv
+            | Mnemonic::VmsltuVx
+            | Mnemonic::VmsneVi
+            | Mnemonic::VmsneVv
+            | Mnemonic::VmsneVx
+            | Mnemonic::VmsofM
+            | Mnemonic::VmulVv
+            | Mnemonic::VmulVx
+            | Mnemonic::VmulhVv
+            | Mnemonic::VmulhVx
+            | Mnemonic::VmulhsuVv
+            | Mnemonic::VmulhsuVx
+            | Mnemonic::VmulhuVv
+            | Mnemonic::VmulhuVx
+            | Mnemonic::VmvSX
+            | Mnemonic::VmvVI
+            | Mnemonic::VmvVV
+            | Mnemonic::VmvVX
+            | Mnemonic::VmvXS
+            | Mnemonic::Vmv1rV
+            | Mnemonic::Vmv2rV
+            | Mnemonic::Vmv4rV
+            | Mnemonic::Vmv8rV
+            | Mnemonic::VmxnorMm
+            | Mnemonic::VmxorMm
+            | Mnemonic::VnclipWi
+            | Mnemonic::VnclipWv
+            | Mnemonic::VnclipWx
+            | Mnemonic::VnclipuWi
+            | Mnemonic::VnclipuWv
+            | Mnemonic::VnclipuWx
+            | Mnemonic::VnmsacVv
+            | Mnemonic::VnmsacVx
+            | Mnemonic::VnmsubVv
+            | Mnemonic::VnmsubVx
+            | Mnemonic::VnsraWi
+            | Mnemonic::VnsraWv
+            | Mnemonic::VnsraWx
+            | Mnemonic::VnsrlWi
+            | Mnemonic::VnsrlWv
+            | Mnemonic::VnsrlWx
+            | Mnemonic::VorVi
+            | Mnemonic::VorVv
+            | Mnemonic::VorVx
+            | Mnemonic::VredandVs
+            | Mnemonic::VredmaxVs
+            | Mnemonic::VredmaxuVs
+            | Mnemonic::VredminVs
+            | Mnemonic::VredminuVs
+            | Mnemonic::VredorVs
+            | Mnemonic::VredsumVs
+            | Mnemonic::VredxorVs
+            | Mnemonic::VremVv
+            | Mnemonic::VremVx
+            | Mnemonic::VremuVv
+            | Mnemonic::VremuVx
+            | Mnemonic::VrgatherVi
+            | Mnemonic::VrgatherVv
+            | Mnemonic::VrgatherVx
+            | Mnemonic::Vrgatherei16Vv
+            | Mnemonic::VrsubVi
+            | Mnemonic::VrsubVx
+            | Mnemonic::Vs1rV
+            | Mnemonic::Vs2rV
+            | Mnemonic::Vs4rV
+            | Mnemonic::Vs8rV
+            | Mnemonic::VsaddVi
+            | Mnemonic::VsaddVv
+            | Mnemonic::VsaddVx
+            | Mnemonic::VsadduVi
+            | Mnemonic::VsadduVv
+            | Mnemonic::VsadduVx
+            | Mnemonic::VsbcVvm
+            | Mnemonic::VsbcVxm
+            | Mnemonic::Vse16V
+            | Mnemonic::Vse32V
+            | Mnemonic::Vse64V
+            | Mnemonic::Vse8V
+            | Mnemonic::Vsetivli
+            | Mnemonic::Vsetvl
+            | Mnemonic::Vsetvli
+            | Mnemonic::VsextVf2
+            | Mnemonic::VsextVf4
+            | Mnemonic::VsextVf8
+            | Mnemonic::Vslide1downVx
+            | Mnemonic::Vslide1upVx
+            | Mnemonic::VslidedownVi
+            | Mnemonic::VslidedownVx
+            | Mnemonic::VslideupVi
+            | Mnemonic::VslideupVx
+            | Mnemonic::VsllVi
+            | Mnemonic::VsllVv
+            | Mnemonic::VsllVx
+            | Mnemonic::VsmV
+            | Mnemonic::VsmulVv
+            | Mnemonic::VsmulVx
+            | Mnemonic::Vsoxei16V
+            | Mnemonic::Vsoxei32V
+            | Mnemonic::Vsoxei64V
+            | Mnemonic::Vsoxei8V
+            | Mnemonic::VsraVi
+            | Mnemonic::VsraVv
+            | Mnemonic::VsraVx
+            | Mnemonic::VsrlVi
+            | Mnemonic::VsrlVv
+            | Mnemonic::VsrlVx
+            | Mnemonic::Vsse16V
+            | Mnemonic::Vsse32V
+            | Mnemonic::Vsse64V
+            | Mnemonic::Vsse8V
+            | Mnemonic::VssraVi
+            | Mnemonic::VssraVv
+            | Mnemonic::VssraVx
+            | Mnemonic::VssrlVi
+            | Mnemonic::VssrlVv
+            | Mnemonic::VssrlVx
+            | Mnemonic::VssubVv
+            | Mnemonic::VssubVx
+            | Mnemonic::VssubuVv
+            | Mnemonic::VssubuVx
+            | Mnemonic::VsubVv
+            | Mnemonic::VsubVx
+            | Mnemonic::Vsuxei16V
+            | Mnemonic::Vsuxei32V
+            | Mnemonic::Vsuxei64V
+            | Mnemonic::Vsuxei8V
+            | Mnemonic::VwaddVv
+            | Mnemonic::VwaddVx
+            | Mnemonic::VwaddWv
+            | Mnemonic::VwaddWx
+            | Mnemonic::VwadduVv
+            | Mnemonic::VwadduVx
+            | Mnemonic::VwadduWv
+            | Mnemonic::VwadduWx
+            | Mnemonic::VwmaccVv
+            | Mnemonic::VwmaccVx
+            | Mnemonic::VwmaccsuVv
+            | Mnemonic::VwmaccsuVx
+            | Mnemonic::VwmaccuVv
+            | Mnemonic::VwmaccuVx
+            | Mnemonic::VwmaccusVx
+            | Mnemonic::VwmulVv
+            | Mnemonic::VwmulVx
+            | Mnemonic::VwmulsuVv
+            | Mnemonic::VwmulsuVx
+            | Mnemonic::VwmuluVv
+            | Mnemonic::VwmuluVx
+            | Mnemonic::VwredsumVs
+            | Mnemonic::VwredsumuVs
+            | Mnemonic::VwsubVv
+            | Mnemonic::VwsubVx
+            | Mnemonic::VwsubWv
+            | Mnemonic::VwsubWx
+            | Mnemonic::VwsubuVv
+            | Mnemonic::VwsubuVx
+            | Mnemonic::VwsubuWv
+            | Mnemonic::VwsubuWx
+            | Mnemonic::VxorVi
+            | Mnemonic::VxorVv
+            | Mnemonic::VxorVx
+            | Mnemonic::VzextVf2
+            | Mnemonic::VzextVf4
+            | Mnemonic::VzextVf8 => Extension::RvV,
+            Mnemonic::Unknown => Extension::Unknown,
+        }
+    }
+
+    /// Helper: Get the format of the instruction
+    pub fn format(&self) -> Format {
+        match self {
+            Mnemonic::Add
+            | Mnemonic::Addw
+            | Mnemonic::AmoaddD
+            | Mnemonic::AmoaddW
+            | Mnemonic::AmoandD
+            | Mnemonic::AmoandW
+            | Mnemonic::AmomaxD
+            | Mnemonic::AmomaxW
+            | Mnemonic::AmomaxuD
+            | Mnemonic::AmomaxuW
+            | Mnemonic::AmominD
+            | Mnemonic::AmominW
+            | Mnemonic::AmominuD
+            | Mnemonic::AmominuW
+            | Mnemonic::AmoorD
+            | Mnemonic::AmoorW
+            | Mnemonic::AmoswapD
+            | Mnemonic::AmoswapW
+            | Mnemonic::AmoxorD
+            | Mnemonic::AmoxorW
+            | Mnemonic::And
+            | Mnemonic::Div
+            | Mnemonic::Divu
+            | Mnemonic::Divuw
+            | Mnemonic::Divw
+            | Mnemonic::FaddD
+            | Mnemonic::FaddS
+            | Mnemonic::FclassD
+            | Mnemonic::FclassS
+            | Mnemonic::FcvtDL
+            | Mnemonic::FcvtDLu
+            | Mnemonic::FcvtDS
+            | Mnemonic::FcvtDW
+            | Mnemonic::FcvtDWu
+            | Mnemonic::FcvtLD
+            | Mnemonic::FcvtLS
+            | Mnemonic::FcvtLuD
+            | Mnemonic::FcvtLuS
+            | Mnemonic::FcvtSD
+            | Mnemonic::FcvtSL
+            | Mnemonic::FcvtSLu
+            | Mnemonic::FcvtSW
+            | Mnemonic::FcvtSWu
+            | Mnemonic::FcvtWD
+            | Mnemonic::FcvtWS
+            | Mnemonic::FcvtWuD
+            | Mnemonic::FcvtWuS
+            | Mnemonic::FdivD
+            | Mnemonic::FdivS
+            | Mnemonic::FeqD
+            | Mnemonic::FeqS
+            | Mnemonic::FleD
+            | Mnemonic::FleS
+            | Mnemonic::FltD
+            | Mnemonic::FltS
+            | Mnemonic::FmaxD
+            | Mnemonic::FmaxS
+            | Mnemonic::FminD
+            | Mnemonic::FminS
+            | Mnemonic::FmulD
+            | Mnemonic::FmulS
+            | Mnemonic::FmvDX
+            | Mnemonic::FmvWX
+            | Mnemonic::FmvXD
+            | Mnemonic::FmvXW
+            | Mnemonic::FsgnjD
+            | Mnemonic::FsgnjS
+            | Mnemonic::FsgnjnD
+            | Mnemonic::FsgnjnS
+            | Mnemonic::FsgnjxD
+            | Mnemonic::FsgnjxS
+            | Mnemonic::FsqrtD
+            | Mnemonic::FsqrtS
+            | Mnemonic::FsubD
+            | Mnemonic::FsubS
+            | Mnemonic::LrD
+            | Mnemonic::LrW
+            | Mnemonic::Mul
+            | Mnemonic::Mulh
+            | Mnemonic::Mulhsu
+            | Mnemonic::Mulhu
+            | Mnemonic::Mulw
+            | Mnemonic::Or
+            | Mnemonic::Rem
+            | Mnemonic::Remu
+            | Mnemonic::Remuw
+            | Mnemonic::Remw
+            | Mnemonic::ScD
+            | Mnemonic::ScW
+            | Mnemonic::SfenceVma
+            | Mnemonic::Sll
+            | Mnemonic::Sllw
+            | Mnemonic::Slt
+            | Mnemonic::Sltu
+            | Mnemonic::Sra
+            | Mnemonic::Sraw
+            | Mnemonic::Srl
+            | Mnemonic::Srlw
+            | Mnemonic::Sub
+            | Mnemonic::Subw
+            | Mnemonic::Xor => Format::R,
+            Mnemonic::Addi
+            | Mnemonic::Addiw
+            | Mnemonic::Andi
+            | Mnemonic::Csrrc
+            | Mnemonic::Csrrci
+            | Mnemonic::Csrrs
+            | Mnemonic::Csrrsi
+            | Mnemonic::Csrrw
+            | Mnemonic::Csrrwi
+            | Mnemonic::Ebreak
+            | Mnemonic::Ecall
+            | Mnemonic::Fence
+            | Mnemonic::Fld
+            | Mnemonic::Flw
+            | Mnemonic::Jalr
+            | Mnemonic::Lb
+            | Mnemonic::Lbu
+            | Mnemonic::Ld
+            | Mnemonic::Lh
+            | Mnemonic::Lhu
+            | Mnemonic::Lw
+            | Mnemonic::Lwu
+            | Mnemonic::Mret
+            | Mnemonic::Ori
+            | Mnemonic::Slli
+            | Mnemonic::Slliw
+            | Mnemonic::Slti
+            | Mnemonic::Sltiu
+            | Mnemonic::Srai
+            | Mnemonic::Sraiw
+            | Mnemonic::Sret
+            | Mnemonic::Srli
+            | Mnemonic::Srliw
+            | Mnemonic::Wfi
+            | Mnemonic::Xori => Format::I,
+            Mnemonic::Auipc | Mnemonic::Lui => Format::U,
+            Mnemonic::Beq
+            | Mnemonic::Bge
+            | Mnemonic::Bgeu
+            | Mnemonic::Blt
+            | Mnemonic::Bltu
+            | Mnemonic::Bne => Format::B,
+            Mnemonic::CAdd
+            | Mnemonic::CEbreak
+            | Mnemonic::CJalr
+            | Mnemonic::CJr
+            | Mnemonic::CMv => Format::Cr,
+            Mnemonic::CAddi
+            | Mnemonic::CAddi16sp
+            | Mnemonic::CAddiw
+            | Mnemonic::CFldsp
+            | Mnemonic::CFlwsp
+            | Mnemonic::CLdsp
+            | Mnemonic::CLi
+            | Mnemonic::CLui
+            | Mnemonic::CLwsp
+            | Mnemonic::CNop
+            | Mnemonic::CSlli => Format::Ci,
+            Mnemonic::CAddi4spn => Format::Ciw,
+            Mnemonic::CAddw
+            | Mnemonic::CAnd
+            | Mnemonic::CMul
+            | Mnemonic::CNot
+            | Mnemonic::COr
+            | Mnemonic::CSextB
+            | Mnemonic::CSextH
+            | Mnemonic::CSub
+            | Mnemonic::CSubw
+            | Mnemonic::CXor
+            | Mnemonic::CZextB
+            | Mnemonic::CZextH
+            | Mnemonic::CZextW => Format::Ca,
+            Mnemonic::CAndi
+            | Mnemonic::CBeqz
+            | Mnemonic::CBnez
+            | Mnemonic::CSrai
+            | Mnemonic::CSrli => Format::Cb,
+            Mnemonic::CFld
+            | Mnemonic::CFlw
+            | Mnemonic::CLbu
+            | Mnemonic::CLd
+            | Mnemonic::CLh
+            | Mnemonic::CLhu
+            | Mnemonic::CLw => Format::Cl,
+            Mnemonic::CFsd
+            | Mnemonic::CFsw
+            | Mnemonic::CSb
+            | Mnemonic::CSd
+            | Mnemonic::CSh
+            | Mnemonic::CSw => Format::Cs,
+            Mnemonic::CFsdsp | Mnemonic::CFswsp | Mnemonic::CSdsp | Mnemonic::CSwsp => Format::Css,
+            Mnemonic::CJ | Mnemonic::CJal => Format::Cj,
+            Mnemonic::FmaddD
+            | Mnemonic::FmaddS
+            | Mnemonic::FmsubD
+            | Mnemonic::FmsubS
+            | Mnemonic::FnmaddD
+            | Mnemonic::FnmaddS
+            | Mnemonic::FnmsubD
+            | Mnemonic::FnmsubS => Format::R4,
+            Mnemonic::Fsd
+            | Mnemonic::Fsw
+            | Mnemonic::Sb
+            | Mnemonic::Sd
+            | Mnemonic::Sh
+            | Mnemonic::Sw => Format::S,
+            Mnemonic::Jal => Format::J,
+            Mnemonic::VaaddVv
+            | Mnemonic::VaaddVx
+            | Mnemonic::VaadduVv
+            | Mnemonic::VaadduVx
+            | Mnemonic::VadcVim
+            | Mnemonic::VadcVvm
+            | Mnemonic::VadcVxm
+            | Mnemonic::VaddVi
+            | Mnemonic::VaddVv
+            | Mnemonic::VaddVx
+            | Mnemonic::VandVi
+            | Mnemonic::VandVv
+            | Mnemonic::VandVx
+            | Mnemonic::VasubVv
+            | Mnemonic::VasubVx
+            | Mnemonic::VasubuVv
+            | Mnemonic::VasubuVx
+            | Mnemonic::VcompressVm
+            | Mnemonic::VcpopM
+            | Mnemonic::VdivVv
+            | Mnemonic::VdivVx
+            | Mnemonic::VdivuVv
+            | Mnemonic::VdivuVx
+            | Mnemonic::VfaddVf
+            | Mnemonic::VfaddVv
+            | Mnemonic::VfclassV
+            | Mnemonic::VfcvtFXV
+            | Mnemonic::VfcvtFXuV
+            | Mnemonic::VfcvtRtzXFV
+            | Mnemonic::VfcvtRtzXuFV
+            | Mnemonic::VfcvtXFV
+            | Mnemonic::VfcvtXuFV
+            | Mnemonic::VfdivVf
+            | Mnemonic::VfdivVv
+            | Mnemonic::VfirstM
+            | Mnemonic::VfmaccVf
+            | Mnemonic::VfmaccVv
+            | Mnemonic::VfmaddVf
+            | Mnemonic::VfmaddVv
+            | Mnemonic::VfmaxVf
+            | Mnemonic::VfmaxVv
+            | Mnemonic::VfmergeVfm
+            | Mnemonic::VfminVf
+            | Mnemonic::VfminVv
+            | Mnemonic::VfmsacVf
+            | Mnemonic::VfmsacVv
+            | Mnemonic::VfmsubVf
+            | Mnemonic::VfmsubVv
+            | Mnemonic::VfmulVf
+            | Mnemonic::VfmulVv
+            | Mnemonic::VfmvFS
+            | Mnemonic::VfmvSF
+            | Mnemonic::VfmvVF
+            | Mnemonic::VfncvtFFW
+            | Mnemonic::VfncvtFXW
+            | Mnemonic::VfncvtFXuW
+            | Mnemonic::VfncvtRodFFW
+            | Mnemonic::VfncvtRtzXFW
+            | Mnemonic::VfncvtRtzXuFW
+            | Mnemonic::VfncvtXFW
+            | Mnemonic::VfncvtXuFW
+            | Mnemonic::VfnmaccVf
+            | Mnemonic::VfnmaccVv
+            | Mnemonic::VfnmaddVf
+            | Mnemonic::VfnmaddVv
+            | Mnemonic::VfnmsacVf
+            | Mnemonic::VfnmsacVv
+            | Mnemonic::VfnmsubVf
+            | Mnemonic::VfnmsubVv
+            | Mnemonic::VfrdivVf
+            | Mnemonic::Vfrec7V
+            | Mnemonic::VfredmaxVs
+            | Mnemonic::VfredminVs
+            | Mnemonic::VfredosumVs
+            | Mnemonic::VfredusumVs
+            | Mnemonic::Vfrsqrt7V
+            | Mnemonic::VfrsubVf
+            | Mnemonic::VfsgnjVf
+            | Mnemonic::VfsgnjVv
+            | Mnemonic::VfsgnjnVf
+            | Mnemonic::VfsgnjnVv
+            | Mnemonic::VfsgnjxVf
+            | Mnemonic::VfsgnjxVv
+            | Mnemonic::Vfslide1downVf
+            | Mnemonic::Vfslide1upVf
+            | Mnemonic::VfsqrtV
+            | Mnemonic::VfsubVf
+            | Mnemonic::VfsubVv
+            | Mnemonic::VfwaddVf
+            | Mnemonic::VfwaddVv
+            | Mnemonic::VfwaddWf
+            | Mnemonic::VfwaddWv
+            | Mnemonic::VfwcvtFFV
+            | Mnemonic::VfwcvtFXV
+            | Mnemonic::VfwcvtFXuV
+            | Mnemonic::VfwcvtRtzXFV
+            | Mnemonic::VfwcvtRtzXuFV
+            | Mnemonic::VfwcvtXFV
+            | Mnemonic::VfwcvtXuFV
+            | Mnemonic::VfwmaccVf
+            | Mnemonic::VfwmaccVv
+            | Mnemonic::VfwmsacVf
+            | Mnemonic::VfwmsacVv
+            | Mnemonic::VfwmulVf
+            | Mnemonic::VfwmulVv
+            | Mnemonic::VfwnmaccVf
+            | Mnemonic::VfwnmaccVv
+            | Mnemonic::VfwnmsacVf
+            | Mnemonic::VfwnmsacVv
+            | Mnemonic::VfwredosumVs
+            | Mnemonic::VfwredusumVs
+            | Mnemonic::VfwsubVf
+            | Mnemonic::VfwsubVv
+            | Mnemonic::VfwsubWf
+            | Mnemonic::VfwsubWv
+            | Mnemonic::VidV
+            | Mnemonic::ViotaM
+            | Mnemonic::VmaccVv
+            | Mnemonic::VmaccVx
+            | Mnemonic::VmadcVi
+            | Mnemonic::VmadcVim
+            | Mnemonic::VmadcVv
+            | Mnemonic::VmadcVvm
+            | Mnemonic::VmadcVx
+            | Mnemonic::VmadcVxm
+            | Mnemonic::VmaddVv
+            | Mnemonic::VmaddVx
+            | Mnemonic::VmandMm
+            | Mnemonic::VmandnMm
+            | Mnemonic::VmaxVv
+            | Mnemonic::VmaxVx
+            | Mnemonic::VmaxuVv
+            | Mnemonic::VmaxuVx
+            | Mnemonic::VmergeVim
+            | Mnemonic::VmergeVvm
+            | Mnemonic::VmergeVxm
+            | Mnemonic::VmfeqVf
+            | Mnemonic::VmfeqVv
+            | Mnemonic::VmfgeVf
+            | Mnemonic::VmfgtVf
+            | Mnemonic::VmfleVf
+            | Mnemonic::VmfleVv
+            | Mnemonic::VmfltVf
+            | Mnemonic::VmfltVv
+            | Mnemonic::VmfneVf
+            | Mnemonic::VmfneVv
+            | Mnemonic::VminVv
+            | Mnemonic::VminVx
+            | Mnemonic::VminuVv
+            | Mnemonic::VminuVx
+            | Mnemonic::VmnandMm
+            | Mnemonic::VmnorMm
+            | Mnemonic::VmorMm
+            | Mnemonic::VmornMm
+            | Mnemonic::VmsbcVv
+            | Mnemonic::VmsbcVvm
+            | Mnemonic::VmsbcVx
+            | Mnemonic::VmsbcVxm
+            | Mnemonic::VmsbfM
+            | Mnemonic::VmseqVi
+            | Mnemonic::VmseqVv
+            | Mnemonic::VmseqVx
+            | Mnemonic::VmsgtVi
+            | Mnemonic::VmsgtVx
+            | Mnemonic::VmsgtuVi
+            | Mnemonic::VmsgtuVx
+            | Mnemonic::VmsifM
+            | Mnemonic::VmsleVi
+            | Mnemonic::VmsleVv
+            | Mnemonic::VmsleVx
+            | Mnemonic::VmsleuVi
+            | Mnemonic::VmsleuVv
+            | Mnemonic::VmsleuVx
+            | Mnemonic::VmsltVv
+            | Mnemonic::VmsltVx
+            | Mnemonic::VmsltuVv
+            | Mnemonic::VmsltuVx
+            | Mnemonic::VmsneVi
+            | Mnemonic::VmsneVv
+            | Mnemonic::VmsneVx
+            | Mnemonic::VmsofM
+            | Mnemonic::VmulVv
+            | Mnemonic::VmulVx
+            | Mnemonic::VmulhVv
+            | Mnemonic::VmulhVx
+            | Mnemonic::VmulhsuVv
+            | Mnemonic::VmulhsuVx
+            | Mnemonic::VmulhuVv
+            | Mnemonic::VmulhuVx
+            | Mnemonic::VmvSX
+            | Mnemonic::VmvVI
+            | Mnemonic::VmvVV
+            | Mnemonic::VmvVX
+            | Mnemonic::VmvXS
+            | Mnemonic::Vmv1rV
+            | Mnemonic::Vmv2rV
+            | Mnemonic::Vmv4rV
+            | Mnemonic::Vmv8rV
+            | Mnemonic::VmxnorMm
+            | Mnemonic::VmxorMm
+            | Mnemonic::VnclipWi
+            | Mnemonic::VnclipWv
+            | Mnemonic::VnclipWx
+            | Mnemonic::VnclipuWi
+            | Mnemonic::VnclipuWv
+            | Mnemonic::VnclipuWx
+            | Mnemonic::VnmsacVv
+            | Mnemonic::VnmsacVx
+            | Mnemonic::VnmsubVv
+            | Mnemonic::VnmsubVx
+            | Mnemonic::VnsraWi
+            | Mnemonic::VnsraWv
+            | Mnemonic::VnsraWx
+            | Mnemonic::VnsrlWi
+            | Mnemonic::VnsrlWv
+            | Mnemonic::VnsrlWx
+            | Mnemonic::VorVi
+            | Mnemonic::VorVv
+            | Mnemonic::VorVx
+            | Mnemonic::VredandVs
+            | Mnemonic::VredmaxVs
+            | Mnemonic::VredmaxuVs
+            | Mnemonic::VredminVs
+            | Mnemonic::VredminuVs
+            | Mnemonic::VredorVs
+            | Mnemonic::VredsumVs
+            | Mnemonic::VredxorVs
+            | Mnemonic::VremVv
+            | Mnemonic::VremVx
+            | Mnemonic::VremuVv
+            | Mnemonic::VremuVx
+            | Mnemonic::VrgatherVi
+            | Mnemonic::VrgatherVv
+            | Mnemonic::VrgatherVx
+            | Mnemonic::Vrgatherei16Vv
+            | Mnemonic::VrsubVi
+            | Mnemonic::VrsubVx
+            | Mnemonic::VsaddVi
+            | Mnemonic::VsaddVv
+            | Mnemonic::VsaddVx
+            | Mnemonic::VsadduVi
+            | Mnemonic::VsadduVv
+            | Mnemonic::VsadduVx
+            | Mnemonic::VsbcVvm
+            | Mnemonic::VsbcVxm
+            | Mnemonic::VsextVf2
+            | Mnemonic::VsextVf4
+            | Mnemonic::VsextVf8
+            | Mnemonic::Vslide1downVx
+            | Mnemonic::Vslide1upVx
+            | Mnemonic::VslidedownVi
+            | Mnemonic::VslidedownVx
+            | Mnemonic::VslideupVi
+            | Mnemonic::VslideupVx
+            | Mnemonic::VsllVi
+            | Mnemonic::VsllVv
+            | Mnemonic::VsllVx
+            | Mnemonic::VsmulVv
+            | Mnemonic::VsmulVx
+            | Mnemonic::VsraVi
+            | Mnemonic::VsraVv
+            | Mnemonic::VsraVx
+            | Mnemonic::VsrlVi
+            | Mnemonic::VsrlVv
+            | Mnemonic::VsrlVx
+            | Mnemonic::VssraVi
+            | Mnemonic::VssraVv
+            | Mnemonic::VssraVx
+            | Mnemonic::VssrlVi
+            | Mnemonic::VssrlVv
+            | Mnemonic::VssrlVx
+            | Mnemonic::VssubVv
+            | Mnemonic::VssubVx
+            | Mnemonic::VssubuVv
+            | Mnemonic::VssubuVx
+            | Mnemonic::VsubVv
+            | Mnemonic::VsubVx
+            | Mnemonic::VwaddVv
+            | Mnemonic::VwaddVx
+            | Mnemonic::VwaddWv
+            | Mnemonic::VwaddWx
+            | Mnemonic::VwadduVv
+            | Mnemonic::VwadduVx
+            | Mnemonic::VwadduWv
+            | Mnemonic::VwadduWx
+            | Mnemonic::VwmaccVv
+            | Mnemonic::VwmaccVx
+            | Mnemonic::VwmaccsuVv
+            | Mnemonic::VwmaccsuVx
+            | Mnemonic::VwmaccuVv
+            | Mnemonic::VwmaccuVx
+            | Mnemonic::VwmaccusVx
+            | Mnemonic::VwmulVv
+            | Mnemonic::VwmulVx
+            | Mnemonic::VwmulsuVv
+            | Mnemonic::VwmulsuVx
+            | Mnemonic::VwmuluVv
+            | Mnemonic::VwmuluVx
+            | Mnemonic::VwredsumVs
+            | Mnemonic::VwredsumuVs
+            | Mnemonic::VwsubVv
+            | Mnemonic::VwsubVx
+            | Mnemonic::VwsubWv
+            | Mnemonic::VwsubWx
+            | Mnemonic::VwsubuVv
+            | Mnemonic::VwsubuVx
+            | Mnemonic::VwsubuWv
+            | Mnemonic::VwsubuWx
+            | Mnemonic::VxorVi
+            | Mnemonic::VxorVv
+            | Mnemonic::VxorVx
+            | Mnemonic::VzextVf2
+            | Mnemonic::VzextVf4
+            | Mnemonic::VzextVf8 => Format::Va,
+            Mnemonic::Vl1re16V
+            | Mnemonic::Vl1re32V
+            | Mnemonic::Vl1re64V
+            | Mnemonic::Vl1re8V
+            | Mnemonic::Vl2re16V
+            | Mnemonic::Vl2re32V
+            | Mnemonic::Vl2re64V
+            | Mnemonic::Vl2re8V
+            | Mnemonic::Vl4re16V
+            | Mnemonic::Vl4re32V
+            | Mnemonic::Vl4re64V
+            | Mnemonic::Vl4re8V
+            | Mnemonic::Vl8re16V
+            | Mnemonic::Vl8re32V
+            | Mnemonic::Vl8re64V
+            | Mnemonic::Vl8re8V
+            | Mnemonic::Vle16V
+            | Mnemonic::Vle16ffV
+            | Mnemonic::Vle32V
+            | Mnemonic::Vle32ffV
+            | Mnemonic::Vle64V
+            | Mnemonic::Vle64ffV
+            | Mnemonic::Vle8V
+            | Mnemonic::Vle8ffV
+            | Mnemonic::VlmV
+            | Mnemonic::Vloxei16V
+            | Mnemonic::Vloxei32V
+            | Mnemonic::Vloxei64V
+            | Mnemonic::Vloxei8V
+            | Mnemonic::Vlse16V
+            | Mnemonic::Vlse32V
+            | Mnemonic::Vlse64V
+            | Mnemonic::Vlse8V
+            | Mnemonic::Vluxei16V
+            | Mnemonic::Vluxei32V
+            | Mnemonic::Vluxei64V
+            | Mnemonic::Vluxei8V => Format::Vl,
+            Mnemonic::Vs1rV
+            | Mnemonic::Vs2rV
+            | Mnemonic::Vs4rV
+            | Mnemonic::Vs8rV
+            | Mnemonic::Vse16V
+            | Mnemonic::Vse32V
+            | Mnemonic::Vse64V
+            | Mnemonic::Vse8V
+            | Mnemonic::VsmV
+            | Mnemonic::Vsoxei16V
+            | Mnemonic::Vsoxei32V
+            | Mnemonic::Vsoxei64V
+            | Mnemonic::Vsoxei8V
+            | Mnemonic::Vsse16V
+            | Mnemonic::Vsse32V
+            | Mnemonic::Vsse64V
+            | Mnemonic::Vsse8V
+            | Mnemonic::Vsuxei16V
+            | Mnemonic::Vsuxei32V
+            | Mnemonic::Vsuxei64V
+            | Mnemonic::Vsuxei8V => Format::Vs,
+            Mnemonic::Vsetivli | Mnemonic::Vsetvl | Mnemonic::Vsetvli => Format::Vset,
+            Mnemonic::Unknown => Format::Unknown,
+        }
+    }
+
+    /// Helper: Get the category of the instruction
+    pub fn category(&self) -> Category {
+        match self {
+            Mnemonic::Add
+            | Mnemonic::Addi
+            | Mnemonic::Addiw
+            | Mnemonic::Addw
+            | Mnemonic::And
+            | Mnemonic::Andi
+            | Mnemonic::Auipc
+            | Mnemonic::CAdd
+            | Mnemonic::CAddi
+            | Mnemonic::CAddi16sp
+            | Mnemonic::CAddi4spn
+            | Mnemonic::CAddiw
+            | Mnemonic::CAddw
+            | Mnemonic::CAnd
+            | Mnemonic::CAndi
+            | Mnemonic::CLi
+            | Mnemonic::CLui
+            | Mnemonic::CMv
+            | Mnemonic::CNop
+            | Mnemonic::CNot
+            | Mnemonic::COr
+            | Mnemonic::CSextB
+            | Mnemonic::CSextH
+            | Mnemonic::CSlli
+            | Mnemonic::CSrai
+            | Mnemonic::CSrli
+            | Mnemonic::CSub
+            | Mnemonic::CSubw
+            | Mnemonic::CXor
+            | Mnemonic::CZextB
+            | Mnemonic::CZextH
+            | Mnemonic::CZextW
+            | Mnemonic::Lui
+            | Mnemonic::Or
+            | Mnemonic::Ori
+            | Mnemonic::Sll
+            | Mnemonic::Slli
+            | Mnemonic::Slliw
+            | Mnemonic::Sllw
+            | Mnemonic::Slt
+            | Mnemonic::Slti
+            | Mnemonic::Sltiu
+            | Mnemonic::Sltu
+            | Mnemonic::Sra
+            | Mnemonic::Srai
+            | Mnemonic::Sraiw
+            | Mnemonic::Sraw
+            | Mnemonic::Srl
+            | Mnemonic::Srli
+            | Mnemonic::Srliw
+            | Mnemonic::Srlw
+            | Mnemonic::Sub
+            | Mnemonic::Subw
+            | Mnemonic::Xor
+            | Mnemonic::Xori => Category::Alu,
+            Mnemonic::AmoaddD
+            | Mnemonic::AmoaddW
+            | Mnemonic::AmoandD
+            | Mnemonic::AmoandW
+            | Mnemonic::AmomaxD
+            | Mnemonic::AmomaxW
+            | Mnemonic::AmomaxuD
+            | Mnemonic::AmomaxuW
+            | Mnemonic::AmominD
+            | Mnemonic::AmominW
+            | Mnemonic::AmominuD
+            | Mnemonic::AmominuW
+            | Mnemonic::AmoorD
+            | Mnemonic::AmoorW
+            | Mnemonic::AmoswapD
+            | Mnemonic::AmoswapW
+            | Mnemonic::AmoxorD
+            | Mnemonic::AmoxorW
+            | Mnemonic::LrD
+            | Mnemonic::LrW
+            | Mnemonic::ScD
+            | Mnemonic::ScW => Category::Atomic,
+            Mnemonic::Beq
+            | Mnemonic::Bge
+            | Mnemonic::Bgeu
+            | Mnemonic::Blt
+            | Mnemonic::Bltu
+            | Mnemonic::Bne
+            | Mnemonic::CBeqz
+            | Mnemonic::CBnez => Category::Branch,
+            Mnemonic::CEbreak
+            | Mnemonic::Ebreak
+            | Mnemonic::Ecall
+            | Mnemonic::Mret
+            | Mnemonic::SfenceVma
+            | Mnemonic::Sret
+            | Mnemonic::Wfi => Category::System,
+            Mnemonic::CFld
+            | Mnemonic::CFldsp
+            | Mnemonic::CFlw
+            | Mnemonic::CFlwsp
+            | Mnemonic::CLbu
+            | Mnemonic::CLd
+            | Mnemonic::CLdsp
+            | Mnemonic::CLh
+            | Mnemonic::CLhu
+            | Mnemonic::CLw
+            | Mnemonic::CLwsp
+            | Mnemonic::Fld
+            | Mnemonic::Flw
+            | Mnemonic::Lb
+            | Mnemonic::Lbu
+            | Mnemonic::Ld
+            | Mnemonic::Lh
+            | Mnemonic::Lhu
+            | Mnemonic::Lw
+            | Mnemonic::Lwu
+            | Mnemonic::Vl1re16V
+            | Mnemonic::Vl1re32V
+            | Mnemonic::Vl1re64V
+            | Mnemonic::Vl1re8V
+            | Mnemonic::Vl2re16V
+            | Mnemonic::Vl2re32V
+            | Mnemonic::Vl2re64V
+            | Mnemonic::Vl2re8V
+            | Mnemonic::Vl4re16V
+            | Mnemonic::Vl4re32V
+            | Mnemonic::Vl4re64V
+            | Mnemonic::Vl4re8V
+            | Mnemonic::Vl8re16V
+            | Mnemonic::Vl8re32V
+            | Mnemonic::Vl8re64V
+            | Mnemonic::Vl8re8V
+            | Mnemonic::Vle16V
+            | Mnemonic::Vle16ffV
+            | Mnemonic::Vle32V
+            | Mnemonic::Vle32ffV
+            | Mnemonic::Vle64V
+            | Mnemonic::Vle64ffV
+            | Mnemonic::Vle8V
+            | Mnemonic::Vle8ffV
+            | Mnemonic::VlmV
+            | Mnemonic::Vloxei16V
+            | Mnemonic::Vloxei32V
+            | Mnemonic::Vloxei64V
+            | Mnemonic::Vloxei8V
+            | Mnemonic::Vlse16V
+            | Mnemonic::Vlse32V
+            | Mnemonic::Vlse64V
+            | Mnemonic::Vlse8V
+            | Mnemonic::Vluxei16V
+            | Mnemonic::Vluxei32V
+            | Mnemonic::Vluxei64V
+            | Mnemonic::Vluxei8V => Category::Load,
+            Mnemonic::CFsd
+            | Mnemonic::CFsdsp
+            | Mnemonic::CFsw
+            | Mnemonic::CFswsp
+            | Mnemonic::CSb
+            | Mnemonic::CSd
+            | Mnemonic::CSdsp
+            | Mnemonic::CSh
+            | Mnemonic::CSw
+            | Mnemonic::CSwsp
+            | Mnemonic::Fsd
+            | Mnemonic::Fsw
+            | Mnemonic::Sb
+            | Mnemonic::Sd
+            | Mnemonic::Sh
+            | Mnemonic::Sw
+            | Mnemonic::Vs1rV
+            | Mnemonic::Vs2rV
+            | Mnemonic::Vs4rV
+            | Mnemonic::Vs8rV
+            | Mnemonic::Vse16V
+            | Mnemonic::Vse32V
+            | Mnemonic::Vse64V
+            | Mnemonic::Vse8V
+            | Mnemonic::VsmV
+            | Mnemonic::Vsoxei16V
+            | Mnemonic::Vsoxei32V
+            | Mnemonic::Vsoxei64V
+            | Mnemonic::Vsoxei8V
+            | Mnemonic::Vsse16V
+            | Mnemonic::Vsse32V
+            | Mnemonic::Vsse64V
+            | Mnemonic::Vsse8V
+            | Mnemonic::Vsuxei16V
+            | Mnemonic::Vsuxei32V
+            | Mnemonic::Vsuxei64V
+            | Mnemonic::Vsuxei8V => Category::Store,
+            Mnemonic::CJ
+            | Mnemonic::CJal
+            | Mnemonic::CJalr
+            | Mnemonic::CJr
+            | Mnemonic::Jal
+            | Mnemonic::Jalr => Category::Jump,
+            Mnemonic::Csrrc
+            | Mnemonic::Csrrci
+            | Mnemonic::Csrrs
+            | Mnemonic::Csrrsi
+            | Mnemonic::Csrrw
+            | Mnemonic::Csrrwi => Category::Csr,
+            Mnemonic::CMul
+            | Mnemonic::Div
+            | Mnemonic::Divu
+            | Mnemonic::Divuw
+            | Mnemonic::Divw
+            | Mnemonic::Mul
+            | Mnemonic::Mulh
+            | Mnemonic::Mulhsu
+            | Mnemonic::Mulhu
+            | Mnemonic::Mulw
+            | Mnemonic::Rem
+            | Mnemonic::Remu
+            | Mnemonic::Remuw
+            | Mnemonic::Remw => Category::MulDiv,
+            Mnemonic::FaddD
+            | Mnemonic::FaddS
+            | Mnemonic::FclassD
+            | Mnemonic::FclassS
+            | Mnemonic::FcvtDL
+            | Mnemonic::FcvtDLu
+            | Mnemonic::FcvtDS
+            | Mnemonic::FcvtDW
+            | Mnemonic::FcvtDWu
+            | Mnemonic::FcvtLD
+            | Mnemonic::FcvtLS
+            | Mnemonic::FcvtLuD
+            | Mnemonic::FcvtLuS
+            | Mnemonic::FcvtSD
+            | Mnemonic::FcvtSL
+            | Mnemonic::FcvtSLu
+            | Mnemonic::FcvtSW
+            | Mnemonic::FcvtSWu
+            | Mnemonic::FcvtWD
+            | Mnemonic::FcvtWS
+            | Mnemonic::FcvtWuD
+            | Mnemonic::FcvtWuS
+            | Mnemonic::FdivD
+            | Mnemonic::FdivS
+            | Mnemonic::FeqD
+            | Mnemonic::FeqS
+            | Mnemonic::FleD
+            | Mnemonic::FleS
+            | Mnemonic::FltD
+            | Mnemonic::FltS
+            | Mnemonic::FmaddD
+            | Mnemonic::FmaddS
+            | Mnemonic::FmaxD
+            | Mnemonic::FmaxS
+            | Mnemonic::FminD
+            | Mnemonic::FminS
+            | Mnemonic::FmsubD
+            | Mnemonic::FmsubS
+            | Mnemonic::FmulD
+            | Mnemonic::FmulS
+            | Mnemonic::FmvDX
+            | Mnemonic::FmvWX
+            | Mnemonic::FmvXD
+            | Mnemonic::FmvXW
+            | Mnemonic::FnmaddD
+            | Mnemonic::FnmaddS
+            | Mnemonic::FnmsubD
+            | Mnemonic::FnmsubS
+            | Mnemonic::FsgnjD
+            | Mnemonic::FsgnjS
+            | Mnemonic::FsgnjnD
+            | Mnemonic::FsgnjnS
+            | Mnemonic::FsgnjxD
+            | Mnemonic::FsgnjxS
+            | Mnemonic::FsqrtD
+            | Mnemonic::FsqrtS
+            | Mnemonic::FsubD
+            | Mnemonic::FsubS => Category::Fp,
+            Mnemonic::Fence => Category::Fence,
+            Mnemonic::VaaddVv
+            | Mnemonic::VaaddVx
+            | Mnemonic::VaadduVv
+            | Mnemonic::VaadduVx
+            | Mnemonic::VadcVim
+            | Mnemonic::VadcVvm
+            | Mnemonic::VadcVxm
+            | Mnemonic::VaddVi
+            | Mnemonic::VaddVv
+            | Mnemonic::VaddVx
+            | Mnemonic::VandVi
+            | Mnemonic::VandVv
+            | Mnemonic::VandVx
+            | Mnemonic::VasubVv
+            | Mnemonic::VasubVx
+            | Mnemonic::VasubuVv
+            | Mnemonic::VasubuVx
+            | Mnemonic::VcompressVm
+            | Mnemonic::VcpopM
+            | Mnemonic::VdivVv
+            | Mnemonic::VdivVx
+            | Mnemonic::VdivuVv
+            | Mnemonic::VdivuVx
+            | Mnemonic::VfaddVf
+            | Mnemonic::VfaddVv
+            | Mnemonic::VfclassV
+            | Mnemonic::VfcvtFXV
+            | Mnemonic::VfcvtFXuV
+            | Mnemonic::VfcvtRtzXFV
+            | Mnemonic::VfcvtRtzXuFV
+            | Mnemonic::VfcvtXFV
+            | Mnemonic::VfcvtXuFV
+            | Mnemonic::VfdivVf
+            | Mnemonic::VfdivVv
+            | Mnemonic::VfirstM
+            | Mnemonic::VfmaccVf
+            | Mnemonic::VfmaccVv
+            | Mnemonic::VfmaddVf
+            | Mnemonic::VfmaddVv
+            | Mnemonic::VfmaxVf
+            | Mnemonic::VfmaxVv
+            | Mnemonic::VfmergeVfm
+            | Mnemonic::VfminVf
+            | Mnemonic::VfminVv
+            | Mnemonic::VfmsacVf
+            | Mnemonic::VfmsacVv
+            | Mnemonic::VfmsubVf
+            | Mnemonic::VfmsubVv
+            | Mnemonic::VfmulVf
+            | Mnemonic::VfmulVv
+            | Mnemonic::VfmvFS
+            | Mnemonic::VfmvSF
+            | Mnemonic::VfmvVF
+            | Mnemonic::VfncvtFFW
+            | Mnemonic::VfncvtFXW
+            | Mnemonic::VfncvtFXuW
+            | Mnemonic::VfncvtRodFFW
+            | Mnemonic::VfncvtRtzXFW
+            | Mnemonic::VfncvtRtzXuFW
+            | Mnemonic::VfncvtXFW
+            | Mnemonic::VfncvtXuFW
+            | Mnemonic::VfnmaccVf
+            | Mnemonic::VfnmaccVv
+            | Mnemonic::VfnmaddVf
+            | Mnemonic::VfnmaddVv
+            | Mnemonic::VfnmsacVf
+            | Mnemonic::VfnmsacVv
+            | Mnemonic::VfnmsubVf
+            | Mnemonic::VfnmsubVv
+            | Mnemonic::VfrdivVf
+            | Mnemonic::Vfrec7V
+            | Mnemonic::VfredmaxVs
+            | Mnemonic::VfredminVs
+            | Mnemonic::VfredosumVs
+            | Mnemonic::VfredusumVs
+            | Mnemonic::Vfrsqrt7V
+            | Mnemonic::VfrsubVf
+            | Mnemonic::VfsgnjVf
+            | Mnemonic::VfsgnjVv
+            | Mnemonic::VfsgnjnVf
+            | Mnemonic::VfsgnjnVv
+            | Mnemonic::VfsgnjxVf
+            | Mnemonic::VfsgnjxVv
+            | Mnemonic::Vfslide1downVf
+            | Mnemonic::Vfslide1upVf
+            | Mnemonic::VfsqrtV
+            | Mnemonic::VfsubVf
+            | Mnemonic::VfsubVv
+            | Mnemonic::VfwaddVf
+            | Mnemonic::VfwaddVv
+            | Mnemonic::VfwaddWf
+            | Mnemonic::VfwaddWv
+            | Mnemonic::VfwcvtFFV
+            | Mnemonic::VfwcvtFXV
+            | Mnemonic::VfwcvtFXuV
+            | Mnemonic::VfwcvtRtzXFV
+            | Mnemonic::VfwcvtRtzXuFV
+            | Mnemonic::VfwcvtXFV
+            | Mnemonic::VfwcvtXuFV
+            | Mnemonic::VfwmaccVf
+            | Mnemonic::VfwmaccVv
+            | Mnemonic::VfwmsacVf
+            | Mnemonic::VfwmsacVv
+            | Mnemonic::VfwmulVf
+            | Mnemonic::VfwmulVv
+            | Mnemonic::VfwnmaccVf
+            | Mnemonic::VfwnmaccVv
+            | Mnemonic::VfwnmsacVf
+            | Mnemonic::VfwnmsacVv
+            | Mnemonic::VfwredosumVs
+            | Mnemonic::VfwredusumVs
+            | Mnemonic::VfwsubVf
+            | Mnemonic::VfwsubVv
+            | Mnemonic::VfwsubWf
+            | Mnemonic::VfwsubWv
+            | Mnemonic::VidV
+            | Mnemonic::ViotaM
+            | Mnemonic::VmaccVv
+            | Mnemonic::VmaccVx
+            | Mnemonic::VmadcVi
+            | Mnemonic::VmadcVim
+            | Mnemonic::VmadcVv
+            | Mnemonic::VmadcVvm
+            | Mnemonic::VmadcVx
+            | Mnemonic::VmadcVxm
+            | Mnemonic::VmaddVv
+            | Mnemonic::VmaddVx
+            | Mnemonic::VmandMm
+            | Mnemonic::VmandnMm
+            | Mnemonic::VmaxVv
+            | Mnemonic::VmaxVx
+            | Mnemonic::VmaxuVv
+            | Mnemonic::VmaxuVx
+            | Mnemonic::VmergeVim
+            | Mnemonic::VmergeVvm
+            | Mnemonic::VmergeVxm
+            | Mnemonic::VmfeqVf
+            | Mnemonic::VmfeqVv
+            | Mnemonic::VmfgeVf
+            | Mnemonic::VmfgtVf
+            | Mnemonic::VmfleVf
+            | Mnemonic::VmfleVv
+            | Mnemonic::VmfltVf
+            | Mnemonic::VmfltVv
+            | Mnemonic::VmfneVf
+            | Mnemonic::VmfneVv
+            | Mnemonic::VminVv
+            | Mnemonic::VminVx
+            | Mnemonic::VminuVv
+            | Mnemonic::VminuVx
+            | Mnemonic::VmnandMm
+            | Mnemonic::VmnorMm
+            | Mnemonic::VmorMm
+            | Mnemonic::VmornMm
+            | Mnemonic::VmsbcVv
+            | Mnemonic::VmsbcVvm
+            | Mnemonic::VmsbcVx
+            | Mnemonic::VmsbcVxm
+            | Mnemonic::VmsbfM
+            | Mnemonic::VmseqVi
+            | Mnemonic::VmseqVv
+            | Mnemonic::VmseqVx
+            | Mnemonic::VmsgtVi
+            | Mnemonic::VmsgtVx
+            | Mnemonic::VmsgtuVi
+            | Mnemonic::VmsgtuVx
+            | Mnemonic::VmsifM
+            | Mnemonic::VmsleVi
+            | Mnemonic::VmsleVv
+            | Mnemonic::VmsleVx
+            | Mnemonic::VmsleuVi
+            | Mnemonic::VmsleuVv
+            | Mnemonic::VmsleuVx
+            | Mnemonic::VmsltVv
+            | Mnemonic::VmsltVx
+            | Mnemonic::VmsltuVv
+            | Mnemonic::VmsltuVx
+            | Mnemonic::VmsneVi
+            | Mnemonic::VmsneVv
+            | Mnemonic::VmsneVx
+            | Mnemonic::VmsofM
+            | Mnemonic::VmulVv
+            | Mnemonic::VmulVx
+            | Mnemonic::VmulhVv
+            | Mnemonic::VmulhVx
+            | Mnemonic::VmulhsuVv
+            | Mnemonic::VmulhsuVx
+            | Mnemonic::VmulhuVv
+            | Mnemonic::VmulhuVx
+            | Mnemonic::VmvSX
+            | Mnemonic::VmvVI
+            | Mnemonic::VmvVV
+            | Mnemonic::VmvVX
+            | Mnemonic::VmvXS
+            | Mnemonic::Vmv1rV
+            | Mnemonic::Vmv2rV
+            | Mnemonic::Vmv4rV
+            | Mnemonic::Vmv8rV
+            | Mnemonic::VmxnorMm
+            | Mnemonic::VmxorMm
+            | Mnemonic::VnclipWi
+            | Mnemonic::VnclipWv
+            | Mnemonic::VnclipWx
+            | Mnemonic::VnclipuWi
+            | Mnemonic::VnclipuWv
+            | Mnemonic::VnclipuWx
+            | Mnemonic::VnmsacVv
+            | Mnemonic::VnmsacVx
+            | Mnemonic::VnmsubVv
+            | Mnemonic::VnmsubVx
+            | Mnemonic::VnsraWi
+            | Mnemonic::VnsraWv
+            | Mnemonic::VnsraWx
+            | Mnemonic::VnsrlWi
+            | Mnemonic::VnsrlWv
+            | Mnemonic::VnsrlWx
+            | Mnemonic::VorVi
+            | Mnemonic::VorVv
+            | Mnemonic::VorVx
+            | Mnemonic::VredandVs
+            | Mnemonic::VredmaxVs
+            | Mnemonic::VredmaxuVs
+            | Mnemonic::VredminVs
+            | Mnemonic::VredminuVs
+            | Mnemonic::VredorVs
+            | Mnemonic::VredsumVs
+            | Mnemonic::VredxorVs
+            | Mnemonic::VremVv
+            | Mnemonic::VremVx
+            | Mnemonic::VremuVv
+            | Mnemonic::VremuVx
+            | Mnemonic::VrgatherVi
+            | Mnemonic::VrgatherVv
+            | Mnemonic::VrgatherVx
+            | Mnemonic::Vrgatherei16Vv
+            | Mnemonic::VrsubVi
+            | Mnemonic::VrsubVx
+            | Mnemonic::VsaddVi
+            | Mnemonic::VsaddVv
+            | Mnemonic::VsaddVx
+            | Mnemonic::VsadduVi
+            | Mnemonic::VsadduVv
+            | Mnemonic::VsadduVx
+            | Mnemonic::VsbcVvm
+            | Mnemonic::VsbcVxm
+            | Mnemonic::Vsetivli
+            | Mnemonic::Vsetvl
+            | Mnemonic::Vsetvli
+            | Mnemonic::VsextVf2
+            | Mnemonic::VsextVf4
+            | Mnemonic::VsextVf8
+            | Mnemonic::Vslide1downVx
+            | Mnemonic::Vslide1upVx
+            | Mnemonic::VslidedownVi
+            | Mnemonic::VslidedownVx
+            | Mnemonic::VslideupVi
+            | Mnemonic::VslideupVx
+            | Mnemonic::VsllVi
+            | Mnemonic::VsllVv
+            | Mnemonic::VsllVx
+            | Mnemonic::VsmulVv
+            | Mnemonic::VsmulVx
+            | Mnemonic::VsraVi
+            | Mnemonic::VsraVv
+            | Mnemonic::VsraVx
+            | Mnemonic::VsrlVi
+            | Mnemonic::VsrlVv
+            | Mnemonic::VsrlVx
+            | Mnemonic::VssraVi
+            | Mnemonic::VssraVv
+            | Mnemonic::VssraVx
+            | Mnemonic::VssrlVi
+            | Mnemonic::VssrlVv
+            | Mnemonic::VssrlVx
+            | Mnemonic::VssubVv
+            | Mnemonic::VssubVx
+            | Mnemonic::VssubuVv
+            | Mnemonic::VssubuVx
+            | Mnemonic::VsubVv
+            | Mnemonic::VsubVx
+            | Mnemonic::VwaddVv
+            | Mnemonic::VwaddVx
+            | Mnemonic::VwaddWv
+            | Mnemonic::VwaddWx
+            | Mnemonic::VwadduVv
+            | Mnemonic::VwadduVx
+            | Mnemonic::VwadduWv
+            | Mnemonic::VwadduWx
+            | Mnemonic::VwmaccVv
+            | Mnemonic::VwmaccVx
+            | Mnemonic::VwmaccsuVv
+            | Mnemonic::VwmaccsuVx
+            | Mnemonic::VwmaccuVv
+            | Mnemonic::VwmaccuVx
+            | Mnemonic::VwmaccusVx
+            | Mnemonic::VwmulVv
+            | Mnemonic::VwmulVx
+            | Mnemonic::VwmulsuVv
+            | Mnemonic::VwmulsuVx
+            | Mnemonic::VwmuluVv
+            | Mnemonic::VwmuluVx
+            | Mnemonic::VwredsumVs
+            | Mnemonic::VwredsumuVs
+            | Mnemonic::VwsubVv
+            | Mnemonic::VwsubVx
+            | Mnemonic::VwsubWv
+            | Mnemonic::VwsubWx
+            | Mnemonic::VwsubuVv
+            | Mnemonic::VwsubuVx
+            | Mnemonic::VwsubuWv
+            | Mnemonic::VwsubuWx
+            | Mnemonic::VxorVi
+            | Mnemonic::VxorVv
+            | Mnemonic::VxorVx
+            | Mnemonic::VzextVf2
+            | Mnemonic::VzextVf4
+            | Mnemonic::VzextVf8 => Category::Vector,
+            Mnemonic::Unknown => Category::Unknown,
+        }
+    }
+}
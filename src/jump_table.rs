@@ -0,0 +1,266 @@
+use crate::cfg::{BasicBlock, Cfg, EdgeKind};
+use crate::const_prop::ConstProp;
+use crate::control_flow::{FlowKind, Target};
+use crate::disassembler::{Disassembler, Traversal, Xlen};
+use crate::insn::Insn;
+use crate::memory::MemoryImage;
+use crate::mnemonic::Mnemonic;
+use crate::reg::{operand_reg, Reg};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Upper bound on entries read when no bounds check guards the table
+const MAX_ENTRIES: u64 = 1024;
+/// How many times newly reached code is searched for more tables
+const MAX_ROUNDS: usize = 16;
+
+/// A switch table recovered for an indirect jump
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JumpTable {
+    /// Address of the indirect jump
+    pub jump: u64,
+    /// Address of the first entry
+    pub table: u64,
+    /// Entry size in bytes
+    pub entry_size: u8,
+    pub signed: bool,
+    /// Entries are offsets from this address instead of absolute targets
+    pub base: Option<u64>,
+    /// Target of each entry, in table order
+    pub targets: Vec<u64>,
+    /// Whether the entry count comes from a bounds check
+    pub bounded: bool,
+}
+
+/// Helper: Truncate an address to XLEN bits
+fn wrap(addr: u64, xlen: Xlen) -> u64 {
+    match xlen {
+        Xlen::XLEN32 => addr & 0xffff_ffff,
+        Xlen::XLEN64 => addr,
+    }
+}
+
+/// Helper: Get the GPR an instruction writes, if any
+fn def_reg(insn: &Insn) -> Option<u32> {
+    let rd = insn.operand("rd")?;
+    match operand_reg(insn.mnemonic, "rd", rd) {
+        Reg::Gpr(rd) => Some(rd),
+        _ => None,
+    }
+}
+
+/// Helper: Find the last instruction before `i` that writes `reg`
+fn find_def(insns: &[(u64, Insn)], i: usize, reg: u32) -> Option<usize> {
+    insns[..i]
+        .iter()
+        .rposition(|(_, insn)| def_reg(insn) == Some(reg))
+}
+
+/// Helper: Get the value of `reg` just before the instruction at `pc`
+fn const_value(consts: &ConstProp, pc: u64, reg: u32) -> Option<u64> {
+    match reg {
+        0 => Some(0),
+        reg => consts.get(pc)?.before.get(&reg).copied(),
+    }
+}
+
+/// Helper: Check if a load can fetch a jump table entry
+fn is_entry_load(insn: &Insn) -> bool {
+    matches!(
+        insn.mnemonic,
+        Mnemonic::Lb
+            | Mnemonic::Lbu
+            | Mnemonic::Lh
+            | Mnemonic::Lhu
+            | Mnemonic::Lw
+            | Mnemonic::Lwu
+            | Mnemonic::Ld
+            | Mnemonic::CLbu
+            | Mnemonic::CLh
+            | Mnemonic::CLhu
+            | Mnemonic::CLw
+            | Mnemonic::CLd
+    )
+}
+
+/// Helper: Split `add rd, a, b` into the operand holding a constant and the other one
+fn split_add(consts: &ConstProp, pc: u64, insn: &Insn) -> Option<(u64, u32)> {
+    if !matches!(insn.mnemonic, Mnemonic::Add | Mnemonic::CAdd) {
+        return None;
+    }
+    let (a, b) = (insn.operand("rs1")?, insn.operand("rs2")?);
+    if let Some(val) = const_value(consts, pc, b) {
+        return Some((val, a));
+    }
+    const_value(consts, pc, a).map(|val| (val, b))
+}
+
+/// Helper: Derive the number of entries from the bounds check on `index`
+/// in a predecessor of `block`
+fn entry_count(cfg: &Cfg, consts: &ConstProp, block: &BasicBlock, index: u32) -> Option<u64> {
+    for edge in cfg.predecessors(block.start) {
+        if !edge.kind.is_intraprocedural() {
+            continue;
+        }
+        let pred = cfg.block(edge.from)?;
+        let i = pred.insns.len() - 1;
+        let (pc, branch) = &pred.insns[i];
+        // whether the branch condition holds on the way to the jump
+        let taken = edge.kind == EdgeKind::Taken;
+        let (a, b) = match (branch.operand("rs1"), branch.operand("rs2")) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None) => (a, 0),
+            _ => continue,
+        };
+        let value = |reg| const_value(consts, *pc, reg);
+        let count = match (branch.mnemonic, taken) {
+            // index < N
+            (Mnemonic::Bltu, true) if a == index => value(b),
+            (Mnemonic::Bgeu, false) if a == index => value(b),
+            // index <= N
+            (Mnemonic::Bltu, false) if b == index => value(a).map(|n| n + 1),
+            (Mnemonic::Bgeu, true) if b == index => value(a).map(|n| n + 1),
+            // sltiu t, index, N guarding a beqz/bnez on t
+            (Mnemonic::Beq | Mnemonic::CBeqz, false) | (Mnemonic::Bne | Mnemonic::CBnez, true)
+                if b == 0 =>
+            {
+                let def = find_def(&pred.insns, i, a)?;
+                let (at, slt) = &pred.insns[def];
+                match slt.mnemonic {
+                    Mnemonic::Sltiu if slt.operand("rs1") == Some(index) => {
+                        Some(slt.offset as i64 as u64)
+                    }
+                    Mnemonic::Sltu if slt.operand("rs1") == Some(index) => {
+                        const_value(consts, *at, slt.operand("rs2")?)
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+        if count.is_some() {
+            return count;
+        }
+    }
+    None
+}
+
+/// Helper: Recognise the table lookup feeding the indirect jump ending `block`
+fn recover(
+    cfg: &Cfg,
+    consts: &ConstProp,
+    block: &BasicBlock,
+    memory: &MemoryImage,
+) -> Option<JumpTable> {
+    let insns = &block.insns;
+    let (jump, jr) = block.terminator();
+    let flow = jr.control_flow(*jump, cfg.xlen);
+    if flow.kind != FlowKind::Jump || flow.target != Some(Target::Indirect) {
+        return None;
+    }
+    // the jump target is either the entry itself or the entry plus a base
+    let def = find_def(insns, insns.len() - 1, jr.operand("rs1")?)?;
+    let (load, base) = if is_entry_load(&insns[def].1) {
+        (def, None)
+    } else {
+        let (base, entry) = split_add(consts, insns[def].0, &insns[def].1)?;
+        (find_def(insns, def, entry)?, Some(base))
+    };
+    let access = insns[load].1.mem_access()?;
+    if !is_entry_load(&insns[load].1) {
+        return None;
+    }
+    let entry_size = access.width?;
+    // entry address = table + (index << log2(size))
+    let addr = find_def(insns, load, access.base)?;
+    let (table, scaled) = split_add(consts, insns[addr].0, &insns[addr].1)?;
+    let table = wrap(table.wrapping_add(access.offset as i64 as u64), cfg.xlen);
+    let index = match find_def(insns, addr, scaled).map(|i| &insns[i].1) {
+        Some(shift)
+            if matches!(shift.mnemonic, Mnemonic::Slli | Mnemonic::CSlli)
+                && 1u64.checked_shl(shift.offset as u32) == Some(entry_size as u64) =>
+        {
+            shift.operand("rs1")?
+        }
+        _ if entry_size == 1 => scaled,
+        _ => return None,
+    };
+
+    let count = entry_count(cfg, consts, block, index);
+    let mut targets = Vec::new();
+    for i in 0..count.unwrap_or(MAX_ENTRIES).min(MAX_ENTRIES) {
+        let at = table + i * entry_size as u64;
+        let Some(entry) = memory.read_int(at, entry_size, access.signed) else {
+            break;
+        };
+        let target = wrap(
+            base.map_or(entry, |base| base.wrapping_add(entry)),
+            cfg.xlen,
+        );
+        // without a bound, stop at the first entry that cannot be code
+        if count.is_none() && (target & 1 != 0 || !memory.contains(target)) {
+            break;
+        }
+        targets.push(target);
+    }
+    if targets.is_empty() {
+        return None;
+    }
+    Some(JumpTable {
+        jump: *jump,
+        table,
+        entry_size,
+        signed: access.signed,
+        base,
+        targets,
+        bounded: count.is_some(),
+    })
+}
+
+/// Find the jump tables behind the indirect jumps of a control-flow graph,
+/// reading their entries from `memory`
+pub fn find_jump_tables(cfg: &Cfg, memory: &MemoryImage) -> Vec<JumpTable> {
+    let consts = ConstProp::within_blocks(cfg);
+    cfg.blocks()
+        .filter_map(|block| recover(cfg, &consts, block, memory))
+        .collect()
+}
+
+/// Collect the distinct targets of each table keyed by the jump's address,
+/// as taken by `Cfg::build_with_targets`
+pub fn indirect_targets(tables: &[JumpTable]) -> HashMap<u64, Vec<u64>> {
+    tables
+        .iter()
+        .map(|table| {
+            let mut targets = table.targets.clone();
+            targets.sort();
+            targets.dedup();
+            (table.jump, targets)
+        })
+        .collect()
+}
+
+/// Recursive-traversal disassembly that also follows recovered jump tables,
+/// repeating until no new tables are found
+pub fn disassemble_recursive_with_jump_tables(
+    disassembler: &Disassembler,
+    code: &[u8],
+    base: u64,
+    roots: &[u64],
+    memory: &MemoryImage,
+) -> (Traversal, Vec<JumpTable>) {
+    let mut indirect = HashMap::new();
+    let mut round = 0;
+    loop {
+        let traversal =
+            disassembler.disassemble_recursive_with_targets(code, base, roots, &indirect);
+        let cfg = Cfg::build_with_targets(&traversal.insns, disassembler.xlen(), &indirect);
+        let tables = find_jump_tables(&cfg, memory);
+        let found = indirect_targets(&tables);
+        round += 1;
+        if found == indirect || round == MAX_ROUNDS {
+            return (traversal, tables);
+        }
+        indirect = found;
+    }
+}
@@ -1,6 +1,31 @@
 //! A RISC-V disassembler written in Rust
 
 pub mod args;
+pub mod assembler;
+pub mod canonical;
+pub mod cfg;
+pub mod cfg_export;
+pub mod const_prop;
+pub mod control_flow;
+pub mod csr;
+pub mod density;
 pub mod disassembler;
+pub mod encoder;
+pub mod functions;
+pub mod generator;
 pub mod insn;
+// the spec tables keep the layout of their generated form
+#[rustfmt::skip]
 pub mod isa;
+pub mod isa_meta;
+pub mod jump_table;
+pub mod mapping;
+pub mod mem_access;
+pub mod memory;
+pub mod mnemonic;
+pub mod overlap;
+pub mod patch;
+pub mod reg;
+pub mod reloc;
+pub mod rvc;
+pub mod xref;
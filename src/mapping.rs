@@ -0,0 +1,99 @@
+use crate::insn::Insn;
+use std::fmt;
+
+/// What a mapping symbol says about the bytes that follow it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingKind {
+    /// `$x`, or `$x<isa>` which also switches the ISA
+    Code { isa: Option<String> },
+    /// `$d`
+    Data,
+}
+
+/// A RISC-V psABI mapping symbol
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingSymbol {
+    pub addr: u64,
+    pub kind: MappingKind,
+}
+
+impl MappingSymbol {
+    /// Parse a symbol name, `None` if it is not a mapping symbol
+    pub fn parse(name: &str, addr: u64) -> Option<Self> {
+        // some assemblers append `.<n>` to keep local names unique
+        let kind = if name == "$d" || name.starts_with("$d.") {
+            MappingKind::Data
+        } else if name == "$x" || name.starts_with("$x.") {
+            MappingKind::Code { isa: None }
+        } else if let Some(isa) = name.strip_prefix("$x") {
+            MappingKind::Code {
+                isa: Some(isa.to_string()),
+            }
+        } else {
+            return None;
+        };
+        Some(Self { addr, kind })
+    }
+}
+
+/// A data directive covering bytes in a `$d` region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Directive {
+    /// Size in bytes: 1, 2 or 4
+    pub size: u8,
+    pub value: u32,
+}
+
+impl fmt::Display for Directive {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.size {
+            4 => write!(f, ".word 0x{:08x}", self.value),
+            2 => write!(f, ".half 0x{:04x}", self.value),
+            _ => write!(f, ".byte 0x{:02x}", self.value),
+        }
+    }
+}
+
+/// Split data bytes starting at `addr` into the widest naturally aligned directives
+pub fn data_directives(bytes: &[u8], addr: u64) -> Vec<(u64, Directive)> {
+    let mut directives = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let pc = addr + i as u64;
+        let left = bytes.len() - i;
+        let size = if pc.is_multiple_of(4) && left >= 4 {
+            4
+        } else if pc.is_multiple_of(2) && left >= 2 {
+            2
+        } else {
+            1
+        };
+        let mut word = [0u8; 4];
+        word[..size].copy_from_slice(&bytes[i..i + size]);
+        directives.push((
+            pc,
+            Directive {
+                size: size as u8,
+                value: u32::from_le_bytes(word),
+            },
+        ));
+        i += size;
+    }
+    directives
+}
+
+/// A line of mapping-symbol aware output
+#[derive(Debug, Clone)]
+pub enum Line {
+    Insn(Insn),
+    Data(Directive),
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Line::Insn(insn) => write!(f, "{}", insn),
+            Line::Data(directive) => write!(f, "{}", directive),
+        }
+    }
+}
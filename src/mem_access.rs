@@ -0,0 +1,179 @@
+use crate::insn::Insn;
+use crate::isa_meta::Category;
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+
+/// Stack pointer, the implicit base of the `c.*sp` forms
+const SP: u32 = 2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MemAccessKind {
+    Load,
+    Store,
+    /// Atomic memory operations read and write the same location
+    ReadModifyWrite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VectorAddressing {
+    UnitStride,
+    /// Byte stride taken from the `stride` GPR
+    Strided {
+        stride: u32,
+    },
+    /// Offsets taken from the `index` vector register
+    Indexed {
+        index: u32,
+        ordered: bool,
+    },
+    FaultOnlyFirst,
+    /// `vl<nf>re<eew>.v` / `vs<nf>r.v`, ignores `vl` and `vtype`
+    WholeRegister,
+    /// `vlm.v` / `vsm.v`, one bit per element
+    Mask,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorMemAccess {
+    pub addressing: VectorAddressing,
+    /// Number of fields per segment, or registers for whole-register accesses
+    pub nf: u8,
+    /// Width of the index elements in bytes, for indexed accesses
+    pub index_width: Option<u8>,
+}
+
+impl VectorMemAccess {
+    /// Helper: Check if the access is a segment access
+    pub fn is_segment(&self) -> bool {
+        self.nf > 1 && self.addressing != VectorAddressing::WholeRegister
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemAccess {
+    pub kind: MemAccessKind,
+    /// Base address register (GPR number)
+    pub base: u32,
+    pub offset: i32,
+    /// Access width in bytes, `None` when it is the dynamic SEW
+    pub width: Option<u8>,
+    /// Whether the loaded value is sign-extended into the destination
+    pub signed: bool,
+    pub vector: Option<VectorMemAccess>,
+}
+
+/// Helper: Get the width and signedness of a scalar access
+fn scalar_width(mnemonic: Mnemonic) -> (u8, bool) {
+    match mnemonic {
+        Mnemonic::Lb => (1, true),
+        Mnemonic::Lbu | Mnemonic::Sb | Mnemonic::CLbu | Mnemonic::CSb => (1, false),
+        Mnemonic::Lh | Mnemonic::CLh => (2, true),
+        Mnemonic::Lhu | Mnemonic::Sh | Mnemonic::CLhu | Mnemonic::CSh => (2, false),
+        Mnemonic::Lw | Mnemonic::CLw | Mnemonic::CLwsp | Mnemonic::LrW => (4, true),
+        Mnemonic::Lwu
+        | Mnemonic::Sw
+        | Mnemonic::CSw
+        | Mnemonic::CSwsp
+        | Mnemonic::Flw
+        | Mnemonic::Fsw
+        | Mnemonic::CFlw
+        | Mnemonic::CFlwsp
+        | Mnemonic::CFsw
+        | Mnemonic::CFswsp
+        | Mnemonic::ScW => (4, false),
+        Mnemonic::AmoaddW
+        | Mnemonic::AmoandW
+        | Mnemonic::AmomaxW
+        | Mnemonic::AmomaxuW
+        | Mnemonic::AmominW
+        | Mnemonic::AmominuW
+        | Mnemonic::AmoorW
+        | Mnemonic::AmoswapW
+        | Mnemonic::AmoxorW => (4, true),
+        _ => (8, false),
+    }
+}
+
+/// Helper: Parse the element width in bytes out of a vector mnemonic suffix
+fn vector_eew(name: &str) -> Option<u8> {
+    let digits: String = name
+        .trim_end_matches(".v")
+        .trim_end_matches("ff")
+        .chars()
+        .rev()
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    let bits: u8 = digits.chars().rev().collect::<String>().parse().ok()?;
+    Some(bits / 8)
+}
+
+/// Helper: Describe a vector load or store
+fn vector_access(insn: &Insn, kind: MemAccessKind, base: u32) -> MemAccess {
+    let name = insn.name.as_str();
+    let nf = ((insn.raw >> 29) & 0x7) as u8 + 1;
+    let eew = vector_eew(name);
+    let (addressing, width, index_width) = if name == "vlm.v" || name == "vsm.v" {
+        (VectorAddressing::Mask, Some(1), None)
+    } else if name.starts_with("vlse") || name.starts_with("vsse") {
+        let stride = insn.args.src["rs2"].get_val();
+        (VectorAddressing::Strided { stride }, eew, None)
+    } else if name.contains("xei") {
+        let index = insn.args.src["vs2"].get_val();
+        let ordered = name.starts_with("vlox") || name.starts_with("vsox");
+        (VectorAddressing::Indexed { index, ordered }, None, eew)
+    } else if name.ends_with("ff.v") {
+        (VectorAddressing::FaultOnlyFirst, eew, None)
+    } else if name.starts_with("vle") || name.starts_with("vse") {
+        (VectorAddressing::UnitStride, eew, None)
+    } else {
+        // whole-register stores are encoded with an element width of 8
+        (VectorAddressing::WholeRegister, eew.or(Some(1)), None)
+    };
+    MemAccess {
+        kind,
+        base,
+        offset: 0,
+        width,
+        signed: false,
+        vector: Some(VectorMemAccess {
+            addressing,
+            nf,
+            index_width,
+        }),
+    }
+}
+
+/// Derive the memory access performed by an instruction, if any
+pub fn mem_access(insn: &Insn) -> Option<MemAccess> {
+    let kind = match insn.category() {
+        Category::Load => MemAccessKind::Load,
+        Category::Store => MemAccessKind::Store,
+        Category::Atomic => match insn.mnemonic {
+            Mnemonic::LrW | Mnemonic::LrD => MemAccessKind::Load,
+            Mnemonic::ScW | Mnemonic::ScD => MemAccessKind::Store,
+            _ => MemAccessKind::ReadModifyWrite,
+        },
+        _ => return None,
+    };
+    // the c.*sp forms address relative to sp without encoding it
+    let base = match insn.args.src.get("rs1") {
+        Some(rs1) => rs1.get_val(),
+        None => SP,
+    };
+    if insn.name.starts_with('v') {
+        return Some(vector_access(insn, kind, base));
+    }
+    let offset = match insn.category() {
+        Category::Atomic => 0,
+        _ => insn.offset,
+    };
+    let (width, signed) = scalar_width(insn.mnemonic);
+    Some(MemAccess {
+        kind,
+        base,
+        offset,
+        width: Some(width),
+        signed,
+        vector: None,
+    })
+}
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+/// A sparse little-endian view of a program's memory, built from the
+/// loaded sections of an image
+#[derive(Debug, Clone, Default)]
+pub struct MemoryImage {
+    segments: BTreeMap<u64, Vec<u8>>,
+}
+
+impl MemoryImage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Map `bytes` at `addr`, replacing any segment starting there
+    pub fn add_segment(&mut self, addr: u64, bytes: &[u8]) {
+        self.segments.insert(addr, bytes.to_vec());
+    }
+
+    /// Iterate over the segments in address order
+    pub fn segments(&self) -> impl Iterator<Item = (u64, &[u8])> {
+        self.segments
+            .iter()
+            .map(|(addr, bytes)| (*addr, bytes.as_slice()))
+    }
+
+    /// Check if `addr` is mapped
+    pub fn contains(&self, addr: u64) -> bool {
+        self.read(addr, 1).is_some()
+    }
+
+    /// Read `len` bytes at `addr`, `None` unless one segment holds all of them
+    pub fn read(&self, addr: u64, len: usize) -> Option<&[u8]> {
+        let (start, bytes) = self.segments.range(..=addr).next_back()?;
+        let offset = (addr - start) as usize;
+        bytes.get(offset..offset.checked_add(len)?)
+    }
+
+    pub fn read_u8(&self, addr: u64) -> Option<u8> {
+        self.read(addr, 1).map(|bytes| bytes[0])
+    }
+
+    pub fn read_u16(&self, addr: u64) -> Option<u16> {
+        let bytes = self.read(addr, 2)?;
+        Some(u16::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn read_u32(&self, addr: u64) -> Option<u32> {
+        let bytes = self.read(addr, 4)?;
+        Some(u32::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub fn read_u64(&self, addr: u64) -> Option<u64> {
+        let bytes = self.read(addr, 8)?;
+        Some(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Read a 1, 2, 4 or 8 byte integer, sign- or zero-extended to 64 bits
+    pub fn read_int(&self, addr: u64, size: u8, signed: bool) -> Option<u64> {
+        let (val, bits) = match size {
+            1 => (self.read_u8(addr)? as u64, 8),
+            2 => (self.read_u16(addr)? as u64, 16),
+            4 => (self.read_u32(addr)? as u64, 32),
+            8 => return self.read_u64(addr),
+            _ => return None,
+        };
+        if signed {
+            let shift = 64 - bits;
+            Some((((val << shift) as i64) >> shift) as u64)
+        } else {
+            Some(val)
+        }
+    }
+}
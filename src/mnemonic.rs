@@ -0,0 +1,2402 @@
+/* Maintained by hand: add a variant for every spec added to isa.rs */
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Mnemonic {
+    Add,
+    Addi,
+    Addiw,
+    Addw,
+    AmoaddD,
+    AmoaddW,
+    AmoandD,
+    AmoandW,
+    AmomaxD,
+    AmomaxW,
+    AmomaxuD,
+    AmomaxuW,
+    AmominD,
+    AmominW,
+    AmominuD,
+    AmominuW,
+    AmoorD,
+    AmoorW,
+    AmoswapD,
+    AmoswapW,
+    AmoxorD,
+    AmoxorW,
+    And,
+    Andi,
+    Auipc,
+    Beq,
+    Bge,
+    Bgeu,
+    Blt,
+    Bltu,
+    Bne,
+    CAdd,
+    CAddi,
+    CAddi16sp,
+    CAddi4spn,
+    CAddiw,
+    CAddw,
+    CAnd,
+    CAndi,
+    CBeqz,
+    CBnez,
+    CEbreak,
+    CFld,
+    CFldsp,
+    CFlw,
+    CFlwsp,
+    CFsd,
+    CFsdsp,
+    CFsw,
+    CFswsp,
+    CJ,
+    CJal,
+    CJalr,
+    CJr,
+    CLbu,
+    CLd,
+    CLdsp,
+    CLh,
+    CLhu,
+    CLi,
+    CLui,
+    CLw,
+    CLwsp,
+    CMul,
+    CMv,
+    CNop,
+    CNot,
+    COr,
+    CSb,
+    CSd,
+    CSdsp,
+    CSextB,
+    CSextH,
+    CSh,
+    CSlli,
+    CSrai,
+    CSrli,
+    CSub,
+    CSubw,
+    CSw,
+    CSwsp,
+    CXor,
+    CZextB,
+    CZextH,
+    CZextW,
+    Csrrc,
+    Csrrci,
+    Csrrs,
+    Csrrsi,
+    Csrrw,
+    Csrrwi,
+    Div,
+    Divu,
+    Divuw,
+    Divw,
+    Ebreak,
+    Ecall,
+    FaddD,
+    FaddS,
+    FclassD,
+    FclassS,
+    FcvtDL,
+    FcvtDLu,
+    FcvtDS,
+    FcvtDW,
+    FcvtDWu,
+    FcvtLD,
+    FcvtLS,
+    FcvtLuD,
+    FcvtLuS,
+    FcvtSD,
+    FcvtSL,
+    FcvtSLu,
+    FcvtSW,
+    FcvtSWu,
+    FcvtWD,
+    FcvtWS,
+    FcvtWuD,
+    FcvtWuS,
+    FdivD,
+    FdivS,
+    Fence,
+    FeqD,
+    FeqS,
+    Fld,
+    FleD,
+    FleS,
+    FltD,
+    FltS,
+    Flw,
+    FmaddD,
+    FmaddS,
+    FmaxD,
+    FmaxS,
+    FminD,
+    FminS,
+    FmsubD,
+    FmsubS,
+    FmulD,
+    FmulS,
+    FmvDX,
+    FmvWX,
+    FmvXD,
+    FmvXW,
+    FnmaddD,
+    FnmaddS,
+    FnmsubD,
+    FnmsubS,
+    Fsd,
+    FsgnjD,
+    FsgnjS,
+    FsgnjnD,
+    FsgnjnS,
+    FsgnjxD,
+    FsgnjxS,
+    FsqrtD,
+    FsqrtS,
+    FsubD,
+    FsubS,
+    Fsw,
+    Jal,
+    Jalr,
+    Lb,
+    Lbu,
+    Ld,
+    Lh,
+    Lhu,
+    LrD,
+    LrW,
+    Lui,
+    Lw,
+    Lwu,
+    Mret,
+    Mul,
+    Mulh,
+    Mulhsu,
+    Mulhu,
+    Mulw,
+    Or,
+    Ori,
+    Rem,
+    Remu,
+    Remuw,
+    Remw,
+    Sb,
+    ScD,
+    ScW,
+    Sd,
+    SfenceVma,
+    Sh,
+    Sll,
+    Slli,
+    Slliw,
+    Sllw,
+    Slt,
+    Slti,
+    Sltiu,
+    Sltu,
+    Sra,
+    Srai,
+    Sraiw,
+    Sraw,
+    Sret,
+    Srl,
+    Srli,
+    Srliw,
+    Srlw,
+    Sub,
+    Subw,
+    Sw,
+    VaaddVv,
+    VaaddVx,
+    VaadduVv,
+    VaadduVx,
+    VadcVim,
+    VadcVvm,
+    VadcVxm,
+    VaddVi,
+    VaddVv,
+    VaddVx,
+    VandVi,
+    VandVv,
+    VandVx,
+    VasubVv,
+    VasubVx,
+    VasubuVv,
+    VasubuVx,
+    VcompressVm,
+    VcpopM,
+    VdivVv,
+    VdivVx,
+    VdivuVv,
+    VdivuVx,
+    VfaddVf,
+    VfaddVv,
+    VfclassV,
+    VfcvtFXV,
+    VfcvtFXuV,
+    VfcvtRtzXFV,
+    VfcvtRtzXuFV,
+    VfcvtXFV,
+    VfcvtXuFV,
+    VfdivVf,
+    VfdivVv,
+    VfirstM,
+    VfmaccVf,
+    VfmaccVv,
+    VfmaddVf,
+    VfmaddVv,
+    VfmaxVf,
+    VfmaxVv,
+    VfmergeVfm,
+    VfminVf,
+    VfminVv,
+    VfmsacVf,
+    VfmsacVv,
+    VfmsubVf,
+    VfmsubVv,
+    VfmulVf,
+    VfmulVv,
+    VfmvFS,
+    VfmvSF,
+    VfmvVF,
+    VfncvtFFW,
+    VfncvtFXW,
+    VfncvtFXuW,
+    VfncvtRodFFW,
+    VfncvtRtzXFW,
+    VfncvtRtzXuFW,
+    VfncvtXFW,
+    VfncvtXuFW,
+    VfnmaccVf,
+    VfnmaccVv,
+    VfnmaddVf,
+    VfnmaddVv,
+    VfnmsacVf,
+    VfnmsacVv,
+    VfnmsubVf,
+    VfnmsubVv,
+    VfrdivVf,
+    Vfrec7V,
+    VfredmaxVs,
+    VfredminVs,
+    VfredosumVs,
+    VfredusumVs,
+    Vfrsqrt7V,
+    VfrsubVf,
+    VfsgnjVf,
+    VfsgnjVv,
+    VfsgnjnVf,
+    VfsgnjnVv,
+    VfsgnjxVf,
+    VfsgnjxVv,
+    Vfslide1downVf,
+    Vfslide1upVf,
+    VfsqrtV,
+    VfsubVf,
+    VfsubVv,
+    VfwaddVf,
+    VfwaddVv,
+    VfwaddWf,
+    VfwaddWv,
+    VfwcvtFFV,
+    VfwcvtFXV,
+    VfwcvtFXuV,
+    VfwcvtRtzXFV,
+    VfwcvtRtzXuFV,
+    VfwcvtXFV,
+    VfwcvtXuFV,
+    VfwmaccVf,
+    VfwmaccVv,
+    VfwmsacVf,
+    VfwmsacVv,
+    VfwmulVf,
+    VfwmulVv,
+    VfwnmaccVf,
+    VfwnmaccVv,
+    VfwnmsacVf,
+    VfwnmsacVv,
+    VfwredosumVs,
+    VfwredusumVs,
+    VfwsubVf,
+    VfwsubVv,
+    VfwsubWf,
+    VfwsubWv,
+    VidV,
+    ViotaM,
+    Vl1re16V,
+    Vl1re32V,
+    Vl1re64V,
+    Vl1re8V,
+    Vl2re16V,
+    Vl2re32V,
+    Vl2re64V,
+    Vl2re8V,
+    Vl4re16V,
+    Vl4re32V,
+    Vl4re64V,
+    Vl4re8V,
+    Vl8re16V,
+    Vl8re32V,
+    Vl8re64V,
+    Vl8re8V,
+    Vle16V,
+    Vle16ffV,
+    Vle32V,
+    Vle32ffV,
+    Vle64V,
+    Vle64ffV,
+    Vle8V,
+    Vle8ffV,
+    VlmV,
+    Vloxei16V,
+    Vloxei32V,
+    Vloxei64V,
+    Vloxei8V,
+    Vlse16V,
+    Vlse32V,
+    Vlse64V,
+    Vlse8V,
+    Vluxei16V,
+    Vluxei32V,
+    Vluxei64V,
+    Vluxei8V,
+    VmaccVv,
+    VmaccVx,
+    VmadcVi,
+    VmadcVim,
+    VmadcVv,
+    VmadcVvm,
+    VmadcVx,
+    VmadcVxm,
+    VmaddVv,
+    VmaddVx,
+    VmandMm,
+    VmandnMm,
+    VmaxVv,
+    VmaxVx,
+    VmaxuVv,
+    VmaxuVx,
+    VmergeVim,
+    VmergeVvm,
+    VmergeVxm,
+    VmfeqVf,
+    VmfeqVv,
+    VmfgeVf,
+    VmfgtVf,
+    VmfleVf,
+    VmfleVv,
+    VmfltVf,
+    VmfltVv,
+    VmfneVf,
+    VmfneVv,
+    VminVv,
+    VminVx,
+    VminuVv,
+    VminuVx,
+    VmnandMm,
+    VmnorMm,
+    VmorMm,
+    VmornMm,
+    VmsbcVv,
+    VmsbcVvm,
+    VmsbcVx,
+    VmsbcVxm,
+    VmsbfM,
+    VmseqVi,
+    VmseqVv,
+    VmseqVx,
+    VmsgtVi,
+    VmsgtVx,
+    VmsgtuVi,
+    VmsgtuVx,
+    VmsifM,
+    VmsleVi,
+    VmsleVv,
+    VmsleVx,
+    VmsleuVi,
+    VmsleuVv,
+    VmsleuVx,
+    VmsltVv,
+    VmsltVx,
+    VmsltuVv,
+    VmsltuVx,
+    VmsneVi,
+    VmsneVv,
+    VmsneVx,
+    VmsofM,
+    VmulVv,
+    VmulVx,
+    VmulhVv,
+    VmulhVx,
+    VmulhsuVv,
+    VmulhsuVx,
+    VmulhuVv,
+    VmulhuVx,
+    VmvSX,
+    VmvVI,
+    VmvVV,
+    VmvVX,
+    VmvXS,
+    Vmv1rV,
+    Vmv2rV,
+    Vmv4rV,
+    Vmv8rV,
+    VmxnorMm,
+    VmxorMm,
+    VnclipWi,
+    VnclipWv,
+    VnclipWx,
+    VnclipuWi,
+    VnclipuWv,
+    VnclipuWx,
+    VnmsacVv,
+    VnmsacVx,
+    VnmsubVv,
+    VnmsubVx,
+    VnsraWi,
+    VnsraWv,
+    VnsraWx,
+    VnsrlWi,
+    VnsrlWv,
+    VnsrlWx,
+    VorVi,
+    VorVv,
+    VorVx,
+    VredandVs,
+    VredmaxVs,
+    VredmaxuVs,
+    VredminVs,
+    VredminuVs,
+    VredorVs,
+    VredsumVs,
+    VredxorVs,
+    VremVv,
+    VremVx,
+    VremuVv,
+    VremuVx,
+    VrgatherVi,
+    VrgatherVv,
+    VrgatherVx,
+    Vrgatherei16Vv,
+    VrsubVi,
+    VrsubVx,
+    Vs1rV,
+    Vs2rV,
+    Vs4rV,
+    Vs8rV,
+    VsaddVi,
+    VsaddVv,
+    VsaddVx,
+    VsadduVi,
+    VsadduVv,
+    VsadduVx,
+    VsbcVvm,
+    VsbcVxm,
+    Vse16V,
+    Vse32V,
+    Vse64V,
+    Vse8V,
+    Vsetivli,
+    Vsetvl,
+    Vsetvli,
+    VsextVf2,
+    VsextVf4,
+    VsextVf8,
+    Vslide1downVx,
+    Vslide1upVx,
+    VslidedownVi,
+    VslidedownVx,
+    VslideupVi,
+    VslideupVx,
+    VsllVi,
+    VsllVv,
+    VsllVx,
+    VsmV,
+    VsmulVv,
+    VsmulVx,
+    Vsoxei16V,
+    Vsoxei32V,
+    Vsoxei64V,
+    Vsoxei8V,
+    VsraVi,
+    VsraVv,
+    VsraVx,
+    VsrlVi,
+    VsrlVv,
+    VsrlVx,
+    Vsse16V,
+    Vsse32V,
+    Vsse64V,
+    Vsse8V,
+    VssraVi,
+    VssraVv,
+    VssraVx,
+    VssrlVi,
+    VssrlVv,
+    VssrlVx,
+    VssubVv,
+    VssubVx,
+    VssubuVv,
+    VssubuVx,
+    VsubVv,
+    VsubVx,
+    Vsuxei16V,
+    Vsuxei32V,
+    Vsuxei64V,
+    Vsuxei8V,
+    VwaddVv,
+    VwaddVx,
+    VwaddWv,
+    VwaddWx,
+    VwadduVv,
+    VwadduVx,
+    VwadduWv,
+    VwadduWx,
+    VwmaccVv,
+    VwmaccVx,
+    VwmaccsuVv,
+    VwmaccsuVx,
+    VwmaccuVv,
+    VwmaccuVx,
+    VwmaccusVx,
+    VwmulVv,
+    VwmulVx,
+    VwmulsuVv,
+    VwmulsuVx,
+    VwmuluVv,
+    VwmuluVx,
+    VwredsumVs,
+    VwredsumuVs,
+    VwsubVv,
+    VwsubVx,
+    VwsubWv,
+    VwsubWx,
+    VwsubuVv,
+    VwsubuVx,
+    VwsubuWv,
+    VwsubuWx,
+    VxorVi,
+    VxorVv,
+    VxorVx,
+    VzextVf2,
+    VzextVf4,
+    VzextVf8,
+    Wfi,
+    Xor,
+    Xori,
+    Unknown,
+}
+
+/// All mnemonics known to the decoder, excluding `Unknown`
+pub const ALL_MNEMONICS: &[Mnemonic] = &[
+    Mnemonic::Add,
+    Mnemonic::Addi,
+    Mnemonic::Addiw,
+    Mnemonic::Addw,
+    Mnemonic::AmoaddD,
+    Mnemonic::AmoaddW,
+    Mnemonic::AmoandD,
+    Mnemonic::AmoandW,
+    Mnemonic::AmomaxD,
+    Mnemonic::AmomaxW,
+    Mnemonic::AmomaxuD,
+    Mnemonic::AmomaxuW,
+    Mnemonic::AmominD,
+    Mnemonic::AmominW,
+    Mnemonic::AmominuD,
+    Mnemonic::AmominuW,
+    Mnemonic::AmoorD,
+    Mnemonic::AmoorW,
+    Mnemonic::AmoswapD,
+    Mnemonic::AmoswapW,
+    Mnemonic::AmoxorD,
+    Mnemonic::AmoxorW,
+    Mnemonic::And,
+    Mnemonic::Andi,
+    Mnemonic::Auipc,
+    Mnemonic::Beq,
+    Mnemonic::Bge,
+    Mnemonic::Bgeu,
+    Mnemonic::Blt,
+    Mnemonic::Bltu,
+    Mnemonic::Bne,
+    Mnemonic::CAdd,
+    Mnemonic::CAddi,
+    Mnemonic::CAddi16sp,
+    Mnemonic::CAddi4spn,
+    Mnemonic::CAddiw,
+    Mnemonic::CAddw,
+    Mnemonic::CAnd,
+    Mnemonic::CAndi,
+    Mnemonic::CBeqz,
+    Mnemonic::CBnez,
+    Mnemonic::CEbreak,
+    Mnemonic::CFld,
+    Mnemonic::CFldsp,
+    Mnemonic::CFlw,
+    Mnemonic::CFlwsp,
+    Mnemonic::CFsd,
+    Mnemonic::CFsdsp,
+    Mnemonic::CFsw,
+    Mnemonic::CFswsp,
+    Mnemonic::CJ,
+    Mnemonic::CJal,
+    Mnemonic::CJalr,
+    Mnemonic::CJr,
+    Mnemonic::CLbu,
+    Mnemonic::CLd,
+    Mnemonic::CLdsp,
+    Mnemonic::CLh,
+    Mnemonic::CLhu,
+    Mnemonic::CLi,
+    Mnemonic::CLui,
+    Mnemonic::CLw,
+    Mnemonic::CLwsp,
+    Mnemonic::CMul,
+    Mnemonic::CMv,
+    Mnemonic::CNop,
+    Mnemonic::CNot,
+    Mnemonic::COr,
+    Mnemonic::CSb,
+    Mnemonic::CSd,
+    Mnemonic::CSdsp,
+    Mnemonic::CSextB,
+    Mnemonic::CSextH,
+    Mnemonic::CSh,
+    Mnemonic::CSlli,
+    Mnemonic::CSrai,
+    Mnemonic::CSrli,
+    Mnemonic::CSub,
+    Mnemonic::CSubw,
+    Mnemonic::CSw,
+    Mnemonic::CSwsp,
+    Mnemonic::CXor,
+    Mnemonic::CZextB,
+    Mnemonic::CZextH,
+    Mnemonic::CZextW,
+    Mnemonic::Csrrc,
+    Mnemonic::Csrrci,
+    Mnemonic::Csrrs,
+    Mnemonic::Csrrsi,
+    Mnemonic::Csrrw,
+    Mnemonic::Csrrwi,
+    Mnemonic::Div,
+    Mnemonic::Divu,
+    Mnemonic::Divuw,
+    Mnemonic::Divw,
+    Mnemonic::Ebreak,
+    Mnemonic::Ecall,
+    Mnemonic::FaddD,
+    Mnemonic::FaddS,
+    Mnemonic::FclassD,
+    Mnemonic::FclassS,
+    Mnemonic::FcvtDL,
+    Mnemonic::FcvtDLu,
+    Mnemonic::FcvtDS,
+    Mnemonic::FcvtDW,
+    Mnemonic::FcvtDWu,
+    Mnemonic::FcvtLD,
+    Mnemonic::FcvtLS,
+    Mnemonic::FcvtLuD,
+    Mnemonic::FcvtLuS,
+    Mnemonic::FcvtSD,
+    Mnemonic::FcvtSL,
+    Mnemonic::FcvtSLu,
+    Mnemonic::FcvtSW,
+    Mnemonic::FcvtSWu,
+    Mnemonic::FcvtWD,
+    Mnemonic::FcvtWS,
+    Mnemonic::FcvtWuD,
+    Mnemonic::FcvtWuS,
+    Mnemonic::FdivD,
+    Mnemonic::FdivS,
+    Mnemonic::Fence,
+    Mnemonic::FeqD,
+    Mnemonic::FeqS,
+    Mnemonic::Fld,
+    Mnemonic::FleD,
+    Mnemonic::FleS,
+    Mnemonic::FltD,
+    Mnemonic::FltS,
+    Mnemonic::Flw,
+    Mnemonic::FmaddD,
+    Mnemonic::FmaddS,
+    Mnemonic::FmaxD,
+    Mnemonic::FmaxS,
+    Mnemonic::FminD,
+    Mnemonic::FminS,
+    Mnemonic::FmsubD,
+    Mnemonic::FmsubS,
+    Mnemonic::FmulD,
+    Mnemonic::FmulS,
+    Mnemonic::FmvDX,
+    Mnemonic::FmvWX,
+    Mnemonic::FmvXD,
+    Mnemonic::FmvXW,
+    Mnemonic::FnmaddD,
+    Mnemonic::FnmaddS,
+    Mnemonic::FnmsubD,
+    Mnemonic::FnmsubS,
+    Mnemonic::Fsd,
+    Mnemonic::FsgnjD,
+    Mnemonic::FsgnjS,
+    Mnemonic::FsgnjnD,
+    Mnemonic::FsgnjnS,
+    Mnemonic::FsgnjxD,
+    Mnemonic::FsgnjxS,
+    Mnemonic::FsqrtD,
+    Mnemonic::FsqrtS,
+    Mnemonic::FsubD,
+    Mnemonic::FsubS,
+    Mnemonic::Fsw,
+    Mnemonic::Jal,
+    Mnemonic::Jalr,
+    Mnemonic::Lb,
+    Mnemonic::Lbu,
+    Mnemonic::Ld,
+    Mnemonic::Lh,
+    Mnemonic::Lhu,
+    Mnemonic::LrD,
+    Mnemonic::LrW,
+    Mnemonic::Lui,
+    Mnemonic::Lw,
+    Mnemonic::Lwu,
+    Mnemonic::Mret,
+    Mnemonic::Mul,
+    Mnemonic::Mulh,
+    Mnemonic::Mulhsu,
+    Mnemonic::Mulhu,
+    Mnemonic::Mulw,
+    Mnemonic::Or,
+    Mnemonic::Ori,
+    Mnemonic::Rem,
+    Mnemonic::Remu,
+    Mnemonic::Remuw,
+    Mnemonic::Remw,
+    Mnemonic::Sb,
+    Mnemonic::ScD,
+    Mnemonic::ScW,
+    Mnemonic::Sd,
+    Mnemonic::SfenceVma,
+    Mnemonic::Sh,
+    Mnemonic::Sll,
+    Mnemonic::Slli,
+    Mnemonic::Slliw,
+    Mnemonic::Sllw,
+    Mnemonic::Slt,
+    Mnemonic::Slti,
+    Mnemonic::Sltiu,
+    Mnemonic::Sltu,
+    Mnemonic::Sra,
+    Mnemonic::Srai,
+    Mnemonic::Sraiw,
+    Mnemonic::Sraw,
+    Mnemonic::Sret,
+    Mnemonic::Srl,
+    Mnemonic::Srli,
+    Mnemonic::Srliw,
+    Mnemonic::Srlw,
+    Mnemonic::Sub,
+    Mnemonic::Subw,
+    Mnemonic::Sw,
+    Mnemonic::VaaddVv,
+    Mnemonic::VaaddVx,
+    Mnemonic::VaadduVv,
+    Mnemonic::VaadduVx,
+    Mnemonic::VadcVim,
+    Mnemonic::VadcVvm,
+    Mnemonic::VadcVxm,
+    Mnemonic::VaddVi,
+    Mnemonic::VaddVv,
+    Mnemonic::VaddVx,
+    Mnemonic::VandVi,
+    Mnemonic::VandVv,
+    Mnemonic::VandVx,
+    Mnemonic::VasubVv,
+    Mnemonic::VasubVx,
+    Mnemonic::VasubuVv,
+    Mnemonic::VasubuVx,
+    Mnemonic::VcompressVm,
+    Mnemonic::VcpopM,
+    Mnemonic::VdivVv,
+    Mnemonic::VdivVx,
+    Mnemonic::VdivuVv,
+    Mnemonic::VdivuVx,
+    Mnemonic::VfaddVf,
+    Mnemonic::VfaddVv,
+    Mnemonic::VfclassV,
+    Mnemonic::VfcvtFXV,
+    Mnemonic::VfcvtFXuV,
+    Mnemonic::VfcvtRtzXFV,
+    Mnemonic::VfcvtRtzXuFV,
+    Mnemonic::VfcvtXFV,
+    Mnemonic::VfcvtXuFV,
+    Mnemonic::VfdivVf,
+    Mnemonic::VfdivVv,
+    Mnemonic::VfirstM,
+    Mnemonic::VfmaccVf,
+    Mnemonic::VfmaccVv,
+    Mnemonic::VfmaddVf,
+    Mnemonic::VfmaddVv,
+    Mnemonic::VfmaxVf,
+    Mnemonic::VfmaxVv,
+    Mnemonic::VfmergeVfm,
+    Mnemonic::VfminVf,
+    Mnemonic::VfminVv,
+    Mnemonic::VfmsacVf,
+    Mnemonic::VfmsacVv,
+    Mnemonic::VfmsubVf,
+    Mnemonic::VfmsubVv,
+    Mnemonic::VfmulVf,
+    Mnemonic::VfmulVv,
+    Mnemonic::VfmvFS,
+    Mnemonic::VfmvSF,
+    Mnemonic::VfmvVF,
+    Mnemonic::VfncvtFFW,
+    Mnemonic::VfncvtFXW,
+    Mnemonic::VfncvtFXuW,
+    Mnemonic::VfncvtRodFFW,
+    Mnemonic::VfncvtRtzXFW,
+    Mnemonic::VfncvtRtzXuFW,
+    Mnemonic::VfncvtXFW,
+    Mnemonic::VfncvtXuFW,
+    Mnemonic::VfnmaccVf,
+    Mnemonic::VfnmaccVv,
+    Mnemonic::VfnmaddVf,
+    Mnemonic::VfnmaddVv,
+    Mnemonic::VfnmsacVf,
+    Mnemonic::VfnmsacVv,
+    Mnemonic::VfnmsubVf,
+    Mnemonic::VfnmsubVv,
+    Mnemonic::VfrdivVf,
+    Mnemonic::Vfrec7V,
+    Mnemonic::VfredmaxVs,
+    Mnemonic::VfredminVs,
+    Mnemonic::VfredosumVs,
+    Mnemonic::VfredusumVs,
+    Mnemonic::Vfrsqrt7V,
+    Mnemonic::VfrsubVf,
+    Mnemonic::VfsgnjVf,
+    Mnemonic::VfsgnjVv,
+    Mnemonic::VfsgnjnVf,
+    Mnemonic::VfsgnjnVv,
+    Mnemonic::VfsgnjxVf,
+    Mnemonic::VfsgnjxVv,
+    Mnemonic::Vfslide1downVf,
+    Mnemonic::Vfslide1upVf,
+    Mnemonic::VfsqrtV,
+    Mnemonic::VfsubVf,
+    Mnemonic::VfsubVv,
+    Mnemonic::VfwaddVf,
+    Mnemonic::VfwaddVv,
+    Mnemonic::VfwaddWf,
+    Mnemonic::VfwaddWv,
+    Mnemonic::VfwcvtFFV,
+    Mnemonic::VfwcvtFXV,
+    Mnemonic::VfwcvtFXuV,
+    Mnemonic::VfwcvtRtzXFV,
+    Mnemonic::VfwcvtRtzXuFV,
+    Mnemonic::VfwcvtXFV,
+    Mnemonic::VfwcvtXuFV,
+    Mnemonic::VfwmaccVf,
+    Mnemonic::VfwmaccVv,
+    Mnemonic::VfwmsacVf,
+    Mnemonic::VfwmsacVv,
+    Mnemonic::VfwmulVf,
+    Mnemonic::VfwmulVv,
+    Mnemonic::VfwnmaccVf,
+    Mnemonic::VfwnmaccVv,
+    Mnemonic::VfwnmsacVf,
+    Mnemonic::VfwnmsacVv,
+    Mnemonic::VfwredosumVs,
+    Mnemonic::VfwredusumVs,
+    Mnemonic::VfwsubVf,
+    Mnemonic::VfwsubVv,
+    Mnemonic::VfwsubWf,
+    Mnemonic::VfwsubWv,
+    Mnemonic::VidV,
+    Mnemonic::ViotaM,
+    Mnemonic::Vl1re16V,
+    Mnemonic::Vl1re32V,
+    Mnemonic::Vl1re64V,
+    Mnemonic::Vl1re8V,
+    Mnemonic::Vl2re16V,
+    Mnemonic::Vl2re32V,
+    Mnemonic::Vl2re64V,
+    Mnemonic::Vl2re8V,
+    Mnemonic::Vl4re16V,
+    Mnemonic::Vl4re32V,
+    Mnemonic::Vl4re64V,
+    Mnemonic::Vl4re8V,
+    Mnemonic::Vl8re16V,
+    Mnemonic::Vl8re32V,
+    Mnemonic::Vl8re64V,
+    Mnemonic::Vl8re8V,
+    Mnemonic::Vle16V,
+    Mnemonic::Vle16ffV,
+    Mnemonic::Vle32V,
+    Mnemonic::Vle32ffV,
+    Mnemonic::Vle64V,
+    Mnemonic::Vle64ffV,
+    Mnemonic::Vle8V,
+    Mnemonic::Vle8ffV,
+    Mnemonic::VlmV,
+    Mnemonic::Vloxei16V,
+    Mnemonic::Vloxei32V,
+    Mnemonic::Vloxei64V,
+    Mnemonic::Vloxei8V,
+    Mnemonic::Vlse16V,
+    Mnemonic::Vlse32V,
+    Mnemonic::Vlse64V,
+    Mnemonic::Vlse8V,
+    Mnemonic::Vluxei16V,
+    Mnemonic::Vluxei32V,
+    Mnemonic::Vluxei64V,
+    Mnemonic::Vluxei8V,
+    Mnemonic::VmaccVv,
+    Mnemonic::VmaccVx,
+    Mnemonic::VmadcVi,
+    Mnemonic::VmadcVim,
+    Mnemonic::VmadcVv,
+    Mnemonic::VmadcVvm,
+    Mnemonic::VmadcVx,
+    Mnemonic::VmadcVxm,
+    Mnemonic::VmaddVv,
+    Mnemonic::VmaddVx,
+    Mnemonic::VmandMm,
+    Mnemonic::VmandnMm,
+    Mnemonic::VmaxVv,
+    Mnemonic::VmaxVx,
+    Mnemonic::VmaxuVv,
+    Mnemonic::VmaxuVx,
+    Mnemonic::VmergeVim,
+    Mnemonic::VmergeVvm,
+    Mnemonic::VmergeVxm,
+    Mnemonic::VmfeqVf,
+    Mnemonic::VmfeqVv,
+    Mnemonic::VmfgeVf,
+    Mnemonic::VmfgtVf,
+    Mnemonic::VmfleVf,
+    Mnemonic::VmfleVv,
+    Mnemonic::VmfltVf,
+    Mnemonic::VmfltVv,
+    Mnemonic::VmfneVf,
+    Mnemonic::VmfneVv,
+    Mnemonic::VminVv,
+    Mnemonic::VminVx,
+    Mnemonic::VminuVv,
+    Mnemonic::VminuVx,
+    Mnemonic::VmnandMm,
+    Mnemonic::VmnorMm,
+    Mnemonic::VmorMm,
+    Mnemonic::VmornMm,
+    Mnemonic::VmsbcVv,
+    Mnemonic::VmsbcVvm,
+    Mnemonic::VmsbcVx,
+    Mnemonic::VmsbcVxm,
+    Mnemonic::VmsbfM,
+    Mnemonic::VmseqVi,
+    Mnemonic::VmseqVv,
+    Mnemonic::VmseqVx,
+    Mnemonic::VmsgtVi,
+    Mnemonic::VmsgtVx,
+    Mnemonic::VmsgtuVi,
+    Mnemonic::VmsgtuVx,
+    Mnemonic::VmsifM,
+    Mnemonic::VmsleVi,
+    Mnemonic::VmsleVv,
+    Mnemonic::VmsleVx,
+    Mnemonic::VmsleuVi,
+    Mnemonic::VmsleuVv,
+    Mnemonic::VmsleuVx,
+    Mnemonic::VmsltVv,
+    Mnemonic::VmsltVx,
+    Mnemonic::VmsltuVv,
+    Mnemonic::VmsltuVx,
+    Mnemonic::VmsneVi,
+    Mnemonic::VmsneVv,
+    Mnemonic::VmsneVx,
+    Mnemonic::VmsofM,
+    Mnemonic::VmulVv,
+    Mnemonic::VmulVx,
+    Mnemonic::VmulhVv,
+    Mnemonic::VmulhVx,
+    Mnemonic::VmulhsuVv,
+    Mnemonic::VmulhsuVx,
+    Mnemonic::VmulhuVv,
+    Mnemonic::VmulhuVx,
+    Mnemonic::VmvSX,
+    Mnemonic::VmvVI,
+    Mnemonic::VmvVV,
+    Mnemonic::VmvVX,
+    Mnemonic::VmvXS,
+    Mnemonic::Vmv1rV,
+    Mnemonic::Vmv2rV,
+    Mnemonic::Vmv4rV,
+    Mnemonic::Vmv8rV,
+    Mnemonic::VmxnorMm,
+    Mnemonic::VmxorMm,
+    Mnemonic::VnclipWi,
+    Mnemonic::VnclipWv,
+    Mnemonic::VnclipWx,
+    Mnemonic::VnclipuWi,
+    Mnemonic::VnclipuWv,
+    Mnemonic::VnclipuWx,
+    Mnemonic::VnmsacVv,
+    Mnemonic::VnmsacVx,
+    Mnemonic::VnmsubVv,
+    Mnemonic::VnmsubVx,
+    Mnemonic::VnsraWi,
+    Mnemonic::VnsraWv,
+    Mnemonic::VnsraWx,
+    Mnemonic::VnsrlWi,
+    Mnemonic::VnsrlWv,
+    Mnemonic::VnsrlWx,
+    Mnemonic::VorVi,
+    Mnemonic::VorVv,
+    Mnemonic::VorVx,
+    Mnemonic::VredandVs,
+    Mnemonic::VredmaxVs,
+    Mnemonic::VredmaxuVs,
+    Mnemonic::VredminVs,
+    Mnemonic::VredminuVs,
+    Mnemonic::VredorVs,
+    Mnemonic::VredsumVs,
+    Mnemonic::VredxorVs,
+    Mnemonic::VremVv,
+    Mnemonic::VremVx,
+    Mnemonic::VremuVv,
+    Mnemonic::VremuVx,
+    Mnemonic::VrgatherVi,
+    Mnemonic::VrgatherVv,
+    Mnemonic::VrgatherVx,
+    Mnemonic::Vrgatherei16Vv,
+    Mnemonic::VrsubVi,
+    Mnemonic::VrsubVx,
+    Mnemonic::Vs1rV,
+    Mnemonic::Vs2rV,
+    Mnemonic::Vs4rV,
+    Mnemonic::Vs8rV,
+    Mnemonic::VsaddVi,
+    Mnemonic::VsaddVv,
+    Mnemonic::VsaddVx,
+    Mnemonic::VsadduVi,
+    Mnemonic::VsadduVv,
+    Mnemonic::VsadduVx,
+    Mnemonic::VsbcVvm,
+    Mnemonic::VsbcVxm,
+    Mnemonic::Vse16V,
+    Mnemonic::Vse32V,
+    Mnemonic::Vse64V,
+    Mnemonic::Vse8V,
+    Mnemonic::Vsetivli,
+    Mnemonic::Vsetvl,
+    Mnemonic::Vsetvli,
+    Mnemonic::VsextVf2,
+    Mnemonic::VsextVf4,
+    Mnemonic::VsextVf8,
+    Mnemonic::Vslide1downVx,
+    Mnemonic::Vslide1upVx,
+    Mnemonic::VslidedownVi,
+    Mnemonic::VslidedownVx,
+    Mnemonic::VslideupVi,
+    Mnemonic::VslideupVx,
+    Mnemonic::VsllVi,
+    Mnemonic::VsllVv,
+    Mnemonic::VsllVx,
+    Mnemonic::VsmV,
+    Mnemonic::VsmulVv,
+    Mnemonic::VsmulVx,
+    Mnemonic::Vsoxei16V,
+    Mnemonic::Vsoxei32V,
+    Mnemonic::Vsoxei64V,
+    Mnemonic::Vsoxei8V,
+    Mnemonic::VsraVi,
+    Mnemonic::VsraVv,
+    Mnemonic::VsraVx,
+    Mnemonic::VsrlVi,
+    Mnemonic::VsrlVv,
+    Mnemonic::VsrlVx,
+    Mnemonic::Vsse16V,
+    Mnemonic::Vsse32V,
+    Mnemonic::Vsse64V,
+    Mnemonic::Vsse8V,
+    Mnemonic::VssraVi,
+    Mnemonic::VssraVv,
+    Mnemonic::VssraVx,
+    Mnemonic::VssrlVi,
+    Mnemonic::VssrlVv,
+    Mnemonic::VssrlVx,
+    Mnemonic::VssubVv,
+    Mnemonic::VssubVx,
+    Mnemonic::VssubuVv,
+    Mnemonic::VssubuVx,
+    Mnemonic::VsubVv,
+    Mnemonic::VsubVx,
+    Mnemonic::Vsuxei16V,
+    Mnemonic::Vsuxei32V,
+    Mnemonic::Vsuxei64V,
+    Mnemonic::Vsuxei8V,
+    Mnemonic::VwaddVv,
+    Mnemonic::VwaddVx,
+    Mnemonic::VwaddWv,
+    Mnemonic::VwaddWx,
+    Mnemonic::VwadduVv,
+    Mnemonic::VwadduVx,
+    Mnemonic::VwadduWv,
+    Mnemonic::VwadduWx,
+    Mnemonic::VwmaccVv,
+    Mnemonic::VwmaccVx,
+    Mnemonic::VwmaccsuVv,
+    Mnemonic::VwmaccsuVx,
+    Mnemonic::VwmaccuVv,
+    Mnemonic::VwmaccuVx,
+    Mnemonic::VwmaccusVx,
+    Mnemonic::VwmulVv,
+    Mnemonic::VwmulVx,
+    Mnemonic::VwmulsuVv,
+    Mnemonic::VwmulsuVx,
+    Mnemonic::VwmuluVv,
+    Mnemonic::VwmuluVx,
+    Mnemonic::VwredsumVs,
+    Mnemonic::VwredsumuVs,
+    Mnemonic::VwsubVv,
+    Mnemonic::VwsubVx,
+    Mnemonic::VwsubWv,
+    Mnemonic::VwsubWx,
+    Mnemonic::VwsubuVv,
+    Mnemonic::VwsubuVx,
+    Mnemonic::VwsubuWv,
+    Mnemonic::VwsubuWx,
+    Mnemonic::VxorVi,
+    Mnemonic::VxorVv,
+    Mnemonic::VxorVx,
+    Mnemonic::VzextVf2,
+    Mnemonic::VzextVf4,
+    Mnemonic::VzextVf8,
+    Mnemonic::Wfi,
+    Mnemonic::Xor,
+    Mnemonic::Xori,
+];
+
+impl Mnemonic {
+    /// Helper: Get the assembly name of the mnemonic
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Mnemonic::Add => "add",
+            Mnemonic::Addi => "addi",
+            Mnemonic::Addiw => "addiw",
+            Mnemonic::Addw => "addw",
+            Mnemonic::AmoaddD => "amoadd.d",
+            Mnemonic::AmoaddW => "amoadd.w",
+            Mnemonic::AmoandD => "amoand.d",
+            Mnemonic::AmoandW => "amoand.w",
+            Mnemonic::AmomaxD => "amomax.d",
+            Mnemonic::AmomaxW => "amomax.w",
+            Mnemonic::AmomaxuD => "amomaxu.d",
+            Mnemonic::AmomaxuW => "amomaxu.w",
+            Mnemonic::AmominD => "amomin.d",
+            Mnemonic::AmominW => "amomin.w",
+            Mnemonic::AmominuD => "amominu.d",
+            Mnemonic::AmominuW => "amominu.w",
+            Mnemonic::AmoorD => "amoor.d",
+            Mnemonic::AmoorW => "amoor.w",
+            Mnemonic::AmoswapD => "amoswap.d",
+            Mnemonic::AmoswapW => "amoswap.w",
+            Mnemonic::AmoxorD => "amoxor.d",
+            Mnemonic::AmoxorW => "amoxor.w",
+            Mnemonic::And => "and",
+            Mnemonic::Andi => "andi",
+            Mnemonic::Auipc => "auipc",
+            Mnemonic::Beq => "beq",
+            Mnemonic::Bge => "bge",
+            Mnemonic::Bgeu => "bgeu",
+            Mnemonic::Blt => "blt",
+            Mnemonic::Bltu => "bltu",
+            Mnemonic::Bne => "bne",
+            Mnemonic::CAdd => "c.add",
+            Mnemonic::CAddi => "c.addi",
+            Mnemonic::CAddi16sp => "c.addi16sp",
+            Mnemonic::CAddi4spn => "c.addi4spn",
+            Mnemonic::CAddiw => "c.addiw",
+            Mnemonic::CAddw => "c.addw",
+            Mnemonic::CAnd => "c.and",
+            Mnemonic::CAndi => "c.andi",
+            Mnemonic::CBeqz => "c.beqz",
+            Mnemonic::CBnez => "c.bnez",
+            Mnemonic::CEbreak => "c.ebreak",
+            Mnemonic::CFld => "c.fld",
+            Mnemonic::CFldsp => "c.fldsp",
+            Mnemonic::CFlw => "c.flw",
+            Mnemonic::CFlwsp => "c.flwsp",
+            Mnemonic::CFsd => "c.fsd",
+            Mnemonic::CFsdsp => "c.fsdsp",
+            Mnemonic::CFsw => "c.fsw",
+            Mnemonic::CFswsp => "c.fswsp",
+            Mnemonic::CJ => "c.j",
+            Mnemonic::CJal => "c.jal",
+            Mnemonic::CJalr => "c.jalr",
+            Mnemonic::CJr => "c.jr",
+            Mnemonic::CLbu => "c.lbu",
+            Mnemonic::CLd => "c.ld",
+            Mnemonic::CLdsp => "c.ldsp",
+            Mnemonic::CLh => "c.lh",
+            Mnemonic::CLhu => "c.lhu",
+            Mnemonic::CLi => "c.li",
+            Mnemonic::CLui => "c.lui",
+            Mnemonic::CLw => "c.lw",
+            Mnemonic::CLwsp => "c.lwsp",
+            Mnemonic::CMul => "c.mul",
+            Mnemonic::CMv => "c.mv",
+            Mnemonic::CNop => "c.nop",
+            Mnemonic::CNot => "c.not",
+            Mnemonic::COr => "c.or",
+            Mnemonic::CSb => "c.sb",
+            Mnemonic::CSd => "c.sd",
+            Mnemonic::CSdsp => "c.sdsp",
+            Mnemonic::CSextB => "c.sext.b",
+            Mnemonic::CSextH => "c.sext.h",
+            Mnemonic::CSh => "c.sh",
+            Mnemonic::CSlli => "c.slli",
+            Mnemonic::CSrai => "c.srai",
+            Mnemonic::CSrli => "c.srli",
+            Mnemonic::CSub => "c.sub",
+            Mnemonic::CSubw => "c.subw",
+            Mnemonic::CSw => "c.sw",
+            Mnemonic::CSwsp => "c.swsp",
+            Mnemonic::CXor => "c.xor",
+            Mnemonic::CZextB => "c.zext.b",
+            Mnemonic::CZextH => "c.zext.h",
+            Mnemonic::CZextW => "c.zext.w",
+            Mnemonic::Csrrc => "csrrc",
+            Mnemonic::Csrrci => "csrrci",
+            Mnemonic::Csrrs => "csrrs",
+            Mnemonic::Csrrsi => "csrrsi",
+            Mnemonic::Csrrw => "csrrw",
+            Mnemonic::Csrrwi => "csrrwi",
+            Mnemonic::Div => "div",
+            Mnemonic::Divu => "divu",
+            Mnemonic::Divuw => "divuw",
+            Mnemonic::Divw => "divw",
+            Mnemonic::Ebreak => "ebreak",
+            Mnemonic::Ecall => "ecall",
+            Mnemonic::FaddD => "fadd.d",
+            Mnemonic::FaddS => "fadd.s",
+            Mnemonic::FclassD => "fclass.d",
+            Mnemonic::FclassS => "fclass.s",
+            Mnemonic::FcvtDL => "fcvt.d.l",
+            Mnemonic::FcvtDLu => "fcvt.d.lu",
+            Mnemonic::FcvtDS => "fcvt.d.s",
+            Mnemonic::FcvtDW => "fcvt.d.w",
+            Mnemonic::FcvtDWu => "fcvt.d.wu",
+            Mnemonic::FcvtLD => "fcvt.l.d",
+            Mnemonic::FcvtLS => "fcvt.l.s",
+            Mnemonic::FcvtLuD => "fcvt.lu.d",
+            Mnemonic::FcvtLuS => "fcvt.lu.s",
+            Mnemonic::FcvtSD => "fcvt.s.d",
+            Mnemonic::FcvtSL => "fcvt.s.l",
+            Mnemonic::FcvtSLu => "fcvt.s.lu",
+            Mnemonic::FcvtSW => "fcvt.s.w",
+            Mnemonic::FcvtSWu => "fcvt.s.wu",
+            Mnemonic::FcvtWD => "fcvt.w.d",
+            Mnemonic::FcvtWS => "fcvt.w.s",
+            Mnemonic::FcvtWuD => "fcvt.wu.d",
+            Mnemonic::FcvtWuS => "fcvt.wu.s",
+            Mnemonic::FdivD => "fdiv.d",
+            Mnemonic::FdivS => "fdiv.s",
+            Mnemonic::Fence => "fence",
+            Mnemonic::FeqD => "feq.d",
+            Mnemonic::FeqS => "feq.s",
+            Mnemonic::Fld => "fld",
+            Mnemonic::FleD => "fle.d",
+            Mnemonic::FleS => "fle.s",
+            Mnemonic::FltD => "flt.d",
+            Mnemonic::FltS => "flt.s",
+            Mnemonic::Flw => "flw",
+            Mnemonic::FmaddD => "fmadd.d",
+            Mnemonic::FmaddS => "fmadd.s",
+            Mnemonic::FmaxD => "fmax.d",
+            Mnemonic::FmaxS => "fmax.s",
+            Mnemonic::FminD => "fmin.d",
+            Mnemonic::FminS => "fmin.s",
+            Mnemonic::FmsubD => "fmsub.d",
+            Mnemonic::FmsubS => "fmsub.s",
+            Mnemonic::FmulD => "fmul.d",
+            Mnemonic::FmulS => "fmul.s",
+            Mnemonic::FmvDX => "fmv.d.x",
+            Mnemonic::FmvWX => "fmv.w.x",
+            Mnemonic::FmvXD => "fmv.x.d",
+            Mnemonic::FmvXW => "fmv.x.w",
+            Mnemonic::FnmaddD => "fnmadd.d",
+            Mnemonic::FnmaddS => "fnmadd.s",
+            Mnemonic::FnmsubD => "fnmsub.d",
+            Mnemonic::FnmsubS => "fnmsub.s",
+            Mnemonic::Fsd => "fsd",
+            Mnemonic::FsgnjD => "fsgnj.d",
+            Mnemonic::FsgnjS => "fsgnj.s",
+            Mnemonic::FsgnjnD => "fsgnjn.d",
+            Mnemonic::FsgnjnS => "fsgnjn.s",
+            Mnemonic::FsgnjxD => "fsgnjx.d",
+            Mnemonic::FsgnjxS => "fsgnjx.s",
+            Mnemonic::FsqrtD => "fsqrt.d",
+            Mnemonic::FsqrtS => "fsqrt.s",
+            Mnemonic::FsubD => "fsub.d",
+            Mnemonic::FsubS => "fsub.s",
+            Mnemonic::Fsw => "fsw",
+            Mnemonic::Jal => "jal",
+            Mnemonic::Jalr => "jalr",
+            Mnemonic::Lb => "lb",
+            Mnemonic::Lbu => "lbu",
+            Mnemonic::Ld => "ld",
+            Mnemonic::Lh => "lh",
+            Mnemonic::Lhu => "lhu",
+            Mnemonic::LrD => "lr.d",
+            Mnemonic::LrW => "lr.w",
+            Mnemonic::Lui => "lui",
+            Mnemonic::Lw => "lw",
+            Mnemonic::Lwu => "lwu",
+            Mnemonic::Mret => "mret",
+            Mnemonic::Mul => "mul",
+            Mnemonic::Mulh => "mulh",
+            Mnemonic::Mulhsu => "mulhsu",
+            Mnemonic::Mulhu => "mulhu",
+            Mnemonic::Mulw => "mulw",
+            Mnemonic::Or => "or",
+            Mnemonic::Ori => "ori",
+            Mnemonic::Rem => "rem",
+            Mnemonic::Remu => "remu",
+            Mnemonic::Remuw => "remuw",
+            Mnemonic::Remw => "remw",
+            Mnemonic::Sb => "sb",
+            Mnemonic::ScD => "sc.d",
+            Mnemonic::ScW => "sc.w",
+            Mnemonic::Sd => "sd",
+            Mnemonic::SfenceVma => "sfence.vma",
+            Mnemonic::Sh => "sh",
+            Mnemonic::Sll => "sll",
+            Mnemonic::Slli => "slli",
+            Mnemonic::Slliw => "slliw",
+            Mnemonic::Sllw => "sllw",
+            Mnemonic::Slt => "slt",
+            Mnemonic::Slti => "slti",
+            Mnemonic::Sltiu => "sltiu",
+            Mnemonic::Sltu => "sltu",
+            Mnemonic::Sra => "sra",
+            Mnemonic::Srai => "srai",
+            Mnemonic::Sraiw => "sraiw",
+            Mnemonic::Sraw => "sraw",
+            Mnemonic::Sret => "sret",
+            Mnemonic::Srl => "srl",
+            Mnemonic::Srli => "srli",
+            Mnemonic::Srliw => "srliw",
+            Mnemonic::Srlw => "srlw",
+            Mnemonic::Sub => "sub",
+            Mnemonic::Subw => "subw",
+            Mnemonic::Sw => "sw",
+            Mnemonic::VaaddVv => "vaadd.vv",
+            Mnemonic::VaaddVx => "vaadd.vx",
+            Mnemonic::VaadduVv => "vaaddu.vv",
+            Mnemonic::VaadduVx => "vaaddu.vx",
+            Mnemonic::VadcVim => "vadc.vim",
+            Mnemonic::VadcVvm => "vadc.vvm",
+            Mnemonic::VadcVxm => "vadc.vxm",
+            Mnemonic::VaddVi => "vadd.vi",
+            Mnemonic::VaddVv => "vadd.vv",
+            Mnemonic::VaddVx => "vadd.vx",
+            Mnemonic::VandVi => "vand.vi",
+            Mnemonic::VandVv => "vand.vv",
+            Mnemonic::VandVx => "vand.vx",
+            Mnemonic::VasubVv => "vasub.vv",
+            Mnemonic::VasubVx => "vasub.vx",
+            Mnemonic::VasubuVv => "vasubu.vv",
+            Mnemonic::VasubuVx => "vasubu.vx",
+            Mnemonic::VcompressVm => "vcompress.vm",
+            Mnemonic::VcpopM => "vcpop.m",
+            Mnemonic::VdivVv => "vdiv.vv",
+            Mnemonic::VdivVx => "vdiv.vx",
+            Mnemonic::VdivuVv => "vdivu.vv",
+            Mnemonic::VdivuVx => "vdivu.vx",
+            Mnemonic::VfaddVf => "vfadd.vf",
+            Mnemonic::VfaddVv => "vfadd.vv",
+            Mnemonic::VfclassV => "vfclass.v",
+            Mnemonic::VfcvtFXV => "vfcvt.f.x.v",
+            Mnemonic::VfcvtFXuV => "vfcvt.f.xu.v",
+            Mnemonic::VfcvtRtzXFV => "vfcvt.rtz.x.f.v",
+            Mnemonic::VfcvtRtzXuFV => "vfcvt.rtz.xu.f.v",
+            Mnemonic::VfcvtXFV => "vfcvt.x.f.v",
+            Mnemonic::VfcvtXuFV => "vfcvt.xu.f.v",
+            Mnemonic::VfdivVf => "vfdiv.vf",
+            Mnemonic::VfdivVv => "vfdiv.vv",
+            Mnemonic::VfirstM => "vfirst.m",
+            Mnemonic::VfmaccVf => "vfmacc.vf",
+            Mnemonic::VfmaccVv => "vfmacc.vv",
+            Mnemonic::VfmaddVf => "vfmadd.vf",
+            Mnemonic::VfmaddVv => "vfmadd.vv",
+            Mnemonic::VfmaxVf => "vfmax.vf",
+            Mnemonic::VfmaxVv => "vfmax.vv",
+            Mnemonic::VfmergeVfm => "vfmerge.vfm",
+            Mnemonic::VfminVf => "vfmin.vf",
+            Mnemonic::VfminVv => "vfmin.vv",
+            Mnemonic::VfmsacVf => "vfmsac.vf",
+            Mnemonic::VfmsacVv => "vfmsac.vv",
+            Mnemonic::VfmsubVf => "vfmsub.vf",
+            Mnemonic::VfmsubVv => "vfmsub.vv",
+            Mnemonic::VfmulVf => "vfmul.vf",
+            Mnemonic::VfmulVv => "vfmul.vv",
+            Mnemonic::VfmvFS => "vfmv.f.s",
+            Mnemonic::VfmvSF => "vfmv.s.f",
+            Mnemonic::VfmvVF => "vfmv.v.f",
+            Mnemonic::VfncvtFFW => "vfncvt.f.f.w",
+            Mnemonic::VfncvtFXW => "vfncvt.f.x.w",
+            Mnemonic::VfncvtFXuW => "vfncvt.f.xu.w",
+            Mnemonic::VfncvtRodFFW => "vfncvt.rod.f.f.w",
+            Mnemonic::VfncvtRtzXFW => "vfncvt.rtz.x.f.w",
+            Mnemonic::VfncvtRtzXuFW => "vfncvt.rtz.xu.f.w",
+            Mnemonic::VfncvtXFW => "vfncvt.x.f.w",
+            Mnemonic::VfncvtXuFW => "vfncvt.xu.f.w",
+            Mnemonic::VfnmaccVf => "vfnmacc.vf",
+            Mnemonic::VfnmaccVv => "vfnmacc.vv",
+            Mnemonic::VfnmaddVf => "vfnmadd.vf",
+            Mnemonic::VfnmaddVv => "vfnmadd.vv",
+            Mnemonic::VfnmsacVf => "vfnmsac.vf",
+            Mnemonic::VfnmsacVv => "vfnmsac.vv",
+            Mnemonic::VfnmsubVf => "vfnmsub.vf",
+            Mnemonic::VfnmsubVv => "vfnmsub.vv",
+            Mnemonic::VfrdivVf => "vfrdiv.vf",
+            Mnemonic::Vfrec7V => "vfrec7.v",
+            Mnemonic::VfredmaxVs => "vfredmax.vs",
+            Mnemonic::VfredminVs => "vfredmin.vs",
+            Mnemonic::VfredosumVs => "vfredosum.vs",
+            Mnemonic::VfredusumVs => "vfredusum.vs",
+            Mnemonic::Vfrsqrt7V => "vfrsqrt7.v",
+            Mnemonic::VfrsubVf => "vfrsub.vf",
+            Mnemonic::VfsgnjVf => "vfsgnj.vf",
+            Mnemonic::VfsgnjVv => "vfsgnj.vv",
+            Mnemonic::VfsgnjnVf => "vfsgnjn.vf",
+            Mnemonic::VfsgnjnVv => "vfsgnjn.vv",
+            Mnemonic::VfsgnjxVf => "vfsgnjx.vf",
+            Mnemonic::VfsgnjxVv => "vfsgnjx.vv",
+            Mnemonic::Vfslide1downVf => "vfslide1down.vf",
+            Mnemonic::Vfslide1upVf => "vfslide1up.vf",
+            Mnemonic::VfsqrtV => "vfsqrt.v",
+            Mnemonic::VfsubVf => "vfsub.vf",
+            Mnemonic::VfsubVv => "vfsub.vv",
+            Mnemonic::VfwaddVf => "vfwadd.vf",
+            Mnemonic::VfwaddVv => "vfwadd.vv",
+            Mnemonic::VfwaddWf => "vfwadd.wf",
+            Mnemonic::VfwaddWv => "vfwadd.wv",
+            Mnemonic::VfwcvtFFV => "vfwcvt.f.f.v",
+            Mnemonic::VfwcvtFXV => "vfwcvt.f.x.v",
+            Mnemonic::VfwcvtFXuV => "vfwcvt.f.xu.v",
+            Mnemonic::VfwcvtRtzXFV => "vfwcvt.rtz.x.f.v",
+            Mnemonic::VfwcvtRtzXuFV => "vfwcvt.rtz.xu.f.v",
+            Mnemonic::VfwcvtXFV => "vfwcvt.x.f.v",
+            Mnemonic::VfwcvtXuFV => "vfwcvt.xu.f.v",
+            Mnemonic::VfwmaccVf => "vfwmacc.vf",
+            Mnemonic::VfwmaccVv => "vfwmacc.vv",
+            Mnemonic::VfwmsacVf => "vfwmsac.vf",
+            Mnemonic::VfwmsacVv => "vfwmsac.vv",
+            Mnemonic::VfwmulVf => "vfwmul.vf",
+            Mnemonic::VfwmulVv => "vfwmul.vv",
+            Mnemonic::VfwnmaccVf => "vfwnmacc.vf",
+            Mnemonic::VfwnmaccVv => "vfwnmacc.vv",
+            Mnemonic::VfwnmsacVf => "vfwnmsac.vf",
+            Mnemonic::VfwnmsacVv => "vfwnmsac.vv",
+            Mnemonic::VfwredosumVs => "vfwredosum.vs",
+            Mnemonic::VfwredusumVs => "vfwredusum.vs",
+            Mnemonic::VfwsubVf => "vfwsub.vf",
+            Mnemonic::VfwsubVv => "vfwsub.vv",
+            Mnemonic::VfwsubWf => "vfwsub.wf",
+            Mnemonic::VfwsubWv => "vfwsub.wv",
+            Mnemonic::VidV => "vid.v",
+            Mnemonic::ViotaM => "viota.m",
+            Mnemonic::Vl1re16V => "vl1re16.v",
+            Mnemonic::Vl1re32V => "vl1re32.v",
+            Mnemonic::Vl1re64V => "vl1re64.v",
+            Mnemonic::Vl1re8V => "vl1re8.v",
+            Mnemonic::Vl2re16V => "vl2re16.v",
+            Mnemonic::Vl2re32V => "vl2re32.v",
+            Mnemonic::Vl2re64V => "vl2re64.v",
+            Mnemonic::Vl2re8V => "vl2re8.v",
+            Mnemonic::Vl4re16V => "vl4re16.v",
+            Mnemonic::Vl4re32V => "vl4re32.v",
+            Mnemonic::Vl4re64V => "vl4re64.v",
+            Mnemonic::Vl4re8V => "vl4re8.v",
+            Mnemonic::Vl8re16V => "vl8re16.v",
+            Mnemonic::Vl8re32V => "vl8re32.v",
+            Mnemonic::Vl8re64V => "vl8re64.v",
+            Mnemonic::Vl8re8V => "vl8re8.v",
+            Mnemonic::Vle16V => "vle16.v",
+            Mnemonic::Vle16ffV => "vle16ff.v",
+            Mnemonic::Vle32V => "vle32.v",
+            Mnemonic::Vle32ffV => "vle32ff.v",
+            Mnemonic::Vle64V => "vle64.v",
+            Mnemonic::Vle64ffV => "vle64ff.v",
+            Mnemonic::Vle8V => "vle8.v",
+            Mnemonic::Vle8ffV => "vle8ff.v",
+            Mnemonic::VlmV => "vlm.v",
+            Mnemonic::Vloxei16V => "vloxei16.v",
+            Mnemonic::Vloxei32V => "vloxei32.v",
+            Mnemonic::Vloxei64V => "vloxei64.v",
+            Mnemonic::Vloxei8V => "vloxei8.v",
+            Mnemonic::Vlse16V => "vlse16.v",
+            Mnemonic::Vlse32V => "vlse32.v",
+            Mnemonic::Vlse64V => "vlse64.v",
+            Mnemonic::Vlse8V => "vlse8.v",
+            Mnemonic::Vluxei16V => "vluxei16.v",
+            Mnemonic::Vluxei32V => "vluxei32.v",
+            Mnemonic::Vluxei64V => "vluxei64.v",
+            Mnemonic::Vluxei8V => "vluxei8.v",
+            Mnemonic::VmaccVv => "vmacc.vv",
+            Mnemonic::VmaccVx => "vmacc.vx",
+            Mnemonic::VmadcVi => "vmadc.vi",
+            Mnemonic::VmadcVim => "vmadc.vim",
+            Mnemonic::VmadcVv => "vmadc.vv",
+            Mnemonic::VmadcVvm => "vmadc.vvm",
+            Mnemonic::VmadcVx => "vmadc.vx",
+            Mnemonic::VmadcVxm => "vmadc.vxm",
+            Mnemonic::VmaddVv => "vmadd.vv",
+            Mnemonic::VmaddVx => "vmadd.vx",
+            Mnemonic::VmandMm => "vmand.mm",
+            Mnemonic::VmandnMm => "vmandn.mm",
+            Mnemonic::VmaxVv => "vmax.vv",
+            Mnemonic::VmaxVx => "vmax.vx",
+            Mnemonic::VmaxuVv => "vmaxu.vv",
+            Mnemonic::VmaxuVx => "vmaxu.vx",
+            Mnemonic::VmergeVim => "vmerge.vim",
+            Mnemonic::VmergeVvm => "vmerge.vvm",
+            Mnemonic::VmergeVxm => "vmerge.vxm",
+            Mnemonic::VmfeqVf => "vmfeq.vf",
+            Mnemonic::VmfeqVv => "vmfeq.vv",
+            Mnemonic::VmfgeVf => "vmfge.vf",
+            Mnemonic::VmfgtVf => "vmfgt.vf",
+            Mnemonic::VmfleVf => "vmfle.vf",
+            Mnemonic::VmfleVv => "vmfle.vv",
+            Mnemonic::VmfltVf => "vmflt.vf",
+            Mnemonic::VmfltVv => "vmflt.vv",
+            Mnemonic::VmfneVf => "vmfne.vf",
+            Mnemonic::VmfneVv => "vmfne.vv",
+            Mnemonic::VminVv => "vmin.vv",
+            Mnemonic::VminVx => "vmin.vx",
+            Mnemonic::VminuVv => "vminu.vv",
+            Mnemonic::VminuVx => "vminu.vx",
+            Mnemonic::VmnandMm => "vmnand.mm",
+            Mnemonic::VmnorMm => "vmnor.mm",
+            Mnemonic::VmorMm => "vmor.mm",
+            Mnemonic::VmornMm => "vmorn.mm",
+            Mnemonic::VmsbcVv => "vmsbc.vv",
+            Mnemonic::VmsbcVvm => "vmsbc.vvm",
+            Mnemonic::VmsbcVx => "vmsbc.vx",
+            Mnemonic::VmsbcVxm => "vmsbc.vxm",
+            Mnemonic::VmsbfM => "vmsbf.m",
+            Mnemonic::VmseqVi => "vmseq.vi",
+            Mnemonic::VmseqVv => "vmseq.vv",
+            Mnemonic::VmseqVx => "vmseq.vx",
+            Mnemonic::VmsgtVi => "vmsgt.vi",
+            Mnemonic::VmsgtVx => "vmsgt.vx",
+            Mnemonic::VmsgtuVi => "vmsgtu.vi",
+            Mnemonic::VmsgtuVx => "vmsgtu.vx",
+            Mnemonic::VmsifM => "vmsif.m",
+            Mnemonic::VmsleVi => "vmsle.vi",
+            Mnemonic::VmsleVv => "vmsle.vv",
+            Mnemonic::VmsleVx => "vmsle.vx",
+            Mnemonic::VmsleuVi => "vmsleu.vi",
+            Mnemonic::VmsleuVv => "vmsleu.vv",
+            Mnemonic::VmsleuVx => "vmsleu.vx",
+            Mnemonic::VmsltVv => "vmslt.vv",
+            Mnemonic::VmsltVx => "vmslt.vx",
+            Mnemonic::VmsltuVv => "vmsltu.vv",
+            Mnemonic::VmsltuVx => "vmsltu.vx",
+            Mnemonic::VmsneVi => "vmsne.vi",
+            Mnemonic::VmsneVv => "vmsne.vv",
+            Mnemonic::VmsneVx => "vmsne.vx",
+            Mnemonic::VmsofM => "vmsof.m",
+            Mnemonic::VmulVv => "vmul.vv",
+            Mnemonic::VmulVx => "vmul.vx",
+            Mnemonic::VmulhVv => "vmulh.vv",
+            Mnemonic::VmulhVx => "vmulh.vx",
+            Mnemonic::VmulhsuVv => "vmulhsu.vv",
+            Mnemonic::VmulhsuVx => "vmulhsu.vx",
+            Mnemonic::VmulhuVv => "vmulhu.vv",
+            Mnemonic::VmulhuVx => "vmulhu.vx",
+            Mnemonic::VmvSX => "vmv.s.x",
+            Mnemonic::VmvVI => "vmv.v.i",
+            Mnemonic::VmvVV => "vmv.v.v",
+            Mnemonic::VmvVX => "vmv.v.x",
+            Mnemonic::VmvXS => "vmv.x.s",
+            Mnemonic::Vmv1rV => "vmv1r.v",
+            Mnemonic::Vmv2rV => "vmv2r.v",
+            Mnemonic::Vmv4rV => "vmv4r.v",
+            Mnemonic::Vmv8rV => "vmv8r.v",
+            Mnemonic::VmxnorMm => "vmxnor.mm",
+            Mnemonic::VmxorMm => "vmxor.mm",
+            Mnemonic::VnclipWi => "vnclip.wi",
+            Mnemonic::VnclipWv => "vnclip.wv",
+            Mnemonic::VnclipWx => "vnclip.wx",
+            Mnemonic::VnclipuWi => "vnclipu.wi",
+            Mnemonic::VnclipuWv => "vnclipu.wv",
+            Mnemonic::VnclipuWx => "vnclipu.wx",
+            Mnemonic::VnmsacVv => "vnmsac.vv",
+            Mnemonic::VnmsacVx => "vnmsac.vx",
+            Mnemonic::VnmsubVv => "vnmsub.vv",
+            Mnemonic::VnmsubVx => "vnmsub.vx",
+            Mnemonic::VnsraWi => "vnsra.wi",
+            Mnemonic::VnsraWv => "vnsra.wv",
+            Mnemonic::VnsraWx => "vnsra.wx",
+            Mnemonic::VnsrlWi => "vnsrl.wi",
+            Mnemonic::VnsrlWv => "vnsrl.wv",
+            Mnemonic::VnsrlWx => "vnsrl.wx",
+            Mnemonic::VorVi => "vor.vi",
+            Mnemonic::VorVv => "vor.vv",
+            Mnemonic::VorVx => "vor.vx",
+            Mnemonic::VredandVs => "vredand.vs",
+            Mnemonic::VredmaxVs => "vredmax.vs",
+            Mnemonic::VredmaxuVs => "vredmaxu.vs",
+            Mnemonic::VredminVs => "vredmin.vs",
+            Mnemonic::VredminuVs => "vredminu.vs",
+            Mnemonic::VredorVs => "vredor.vs",
+            Mnemonic::VredsumVs => "vredsum.vs",
+            Mnemonic::VredxorVs => "vredxor.vs",
+            Mnemonic::VremVv => "vrem.vv",
+            Mnemonic::VremVx => "vrem.vx",
+            Mnemonic::VremuVv => "vremu.vv",
+            Mnemonic::VremuVx => "vremu.vx",
+            Mnemonic::VrgatherVi => "vrgather.vi",
+            Mnemonic::VrgatherVv => "vrgather.vv",
+            Mnemonic::VrgatherVx => "vrgather.vx",
+            Mnemonic::Vrgatherei16Vv => "vrgatherei16.vv",
+            Mnemonic::VrsubVi => "vrsub.vi",
+            Mnemonic::VrsubVx => "vrsub.vx",
+            Mnemonic::Vs1rV => "vs1r.v",
+            Mnemonic::Vs2rV => "vs2r.v",
+            Mnemonic::Vs4rV => "vs4r.v",
+            Mnemonic::Vs8rV => "vs8r.v",
+            Mnemonic::VsaddVi => "vsadd.vi",
+            Mnemonic::VsaddVv => "vsadd.vv",
+            Mnemonic::VsaddVx => "vsadd.vx",
+            Mnemonic::VsadduVi => "vsaddu.vi",
+            Mnemonic::VsadduVv => "vsaddu.vv",
+            Mnemonic::VsadduVx => "vsaddu.vx",
+            Mnemonic::VsbcVvm => "vsbc.vvm",
+            Mnemonic::VsbcVxm => "vsbc.vxm",
+            Mnemonic::Vse16V => "vse16.v",
+            Mnemonic::Vse32V => "vse32.v",
+            Mnemonic::Vse64V => "vse64.v",
+            Mnemonic::Vse8V => "vse8.v",
+            Mnemonic::Vsetivli => "vsetivli",
+            Mnemonic::Vsetvl => "vsetvl",
+            Mnemonic::Vsetvli => "vsetvli",
+            Mnemonic::VsextVf2 => "vsext.vf2",
+            Mnemonic::VsextVf4 => "vsext.vf4",
+            Mnemonic::VsextVf8 => "vsext.vf8",
+            Mnemonic::Vslide1downVx => "vslide1down.vx",
+            Mnemonic::Vslide1upVx => "vslide1up.vx",
+            Mnemonic::VslidedownVi => "vslidedown.vi",
+            Mnemonic::VslidedownVx => "vslidedown.vx",
+            Mnemonic::VslideupVi => "vslideup.vi",
+            Mnemonic::VslideupVx => "vslideup.vx",
+            Mnemonic::VsllVi => "vsll.vi",
+            Mnemonic::VsllVv => "vsll.vv",
+            Mnemonic::VsllVx => "vsll.vx",
+            Mnemonic::VsmV => "vsm.v",
+            Mnemonic::VsmulVv => "vsmul.vv",
+            Mnemonic::VsmulVx => "vsmul.vx",
+            Mnemonic::Vsoxei16V => "vsoxei16.v",
+            Mnemonic::Vsoxei32V => "vsoxei32.v",
+            Mnemonic::Vsoxei64V => "vsoxei64.v",
+            Mnemonic::Vsoxei8V => "vsoxei8.v",
+            Mnemonic::VsraVi => "vsra.vi",
+            Mnemonic::VsraVv => "vsra.vv",
+            Mnemonic::VsraVx => "vsra.vx",
+            Mnemonic::VsrlVi => "vsrl.vi",
+            Mnemonic::VsrlVv => "vsrl.vv",
+            Mnemonic::VsrlVx => "vsrl.vx",
+            Mnemonic::Vsse16V => "vsse16.v",
+            Mnemonic::Vsse32V => "vsse32.v",
+            Mnemonic::Vsse64V => "vsse64.v",
+            Mnemonic::Vsse8V => "vsse8.v",
+            Mnemonic::VssraVi => "vssra.vi",
+            Mnemonic::VssraVv => "vssra.vv",
+            Mnemonic::VssraVx => "vssra.vx",
+            Mnemonic::VssrlVi => "vssrl.vi",
+            Mnemonic::VssrlVv => "vssrl.vv",
+            Mnemonic::VssrlVx => "vssrl.vx",
+            Mnemonic::VssubVv => "vssub.vv",
+            Mnemonic::VssubVx => "vssub.vx",
+            Mnemonic::VssubuVv => "vssubu.vv",
+            Mnemonic::VssubuVx => "vssubu.vx",
+            Mnemonic::VsubVv => "vsub.vv",
+            Mnemonic::VsubVx => "vsub.vx",
+            Mnemonic::Vsuxei16V => "vsuxei16.v",
+            Mnemonic::Vsuxei32V => "vsuxei32.v",
+            Mnemonic::Vsuxei64V => "vsuxei64.v",
+            Mnemonic::Vsuxei8V => "vsuxei8.v",
+            Mnemonic::VwaddVv => "vwadd.vv",
+            Mnemonic::VwaddVx => "vwadd.vx",
+            Mnemonic::VwaddWv => "vwadd.wv",
+            Mnemonic::VwaddWx => "vwadd.wx",
+            Mnemonic::VwadduVv => "vwaddu.vv",
+            Mnemonic::VwadduVx => "vwaddu.vx",
+            Mnemonic::VwadduWv => "vwaddu.wv",
+            Mnemonic::VwadduWx => "vwaddu.wx",
+            Mnemonic::VwmaccVv => "vwmacc.vv",
+            Mnemonic::VwmaccVx => "vwmacc.vx",
+            Mnemonic::VwmaccsuVv => "vwmaccsu.vv",
+            Mnemonic::VwmaccsuVx => "vwmaccsu.vx",
+            Mnemonic::VwmaccuVv => "vwmaccu.vv",
+            Mnemonic::VwmaccuVx => "vwmaccu.vx",
+            Mnemonic::VwmaccusVx => "vwmaccus.vx",
+            Mnemonic::VwmulVv => "vwmul.vv",
+            Mnemonic::VwmulVx => "vwmul.vx",
+            Mnemonic::VwmulsuVv => "vwmulsu.vv",
+            Mnemonic::VwmulsuVx => "vwmulsu.vx",
+            Mnemonic::VwmuluVv => "vwmulu.vv",
+            Mnemonic::VwmuluVx => "vwmulu.vx",
+            Mnemonic::VwredsumVs => "vwredsum.vs",
+            Mnemonic::VwredsumuVs => "vwredsumu.vs",
+            Mnemonic::VwsubVv => "vwsub.vv",
+            Mnemonic::VwsubVx => "vwsub.vx",
+            Mnemonic::VwsubWv => "vwsub.wv",
+            Mnemonic::VwsubWx => "vwsub.wx",
+            Mnemonic::VwsubuVv => "vwsubu.vv",
+            Mnemonic::VwsubuVx => "vwsubu.vx",
+            Mnemonic::VwsubuWv => "vwsubu.wv",
+            Mnemonic::VwsubuWx => "vwsubu.wx",
+            Mnemonic::VxorVi => "vxor.vi",
+            Mnemonic::VxorVv => "vxor.vv",
+            Mnemonic::VxorVx => "vxor.vx",
+            Mnemonic::VzextVf2 => "vzext.vf2",
+            Mnemonic::VzextVf4 => "vzext.vf4",
+            Mnemonic::VzextVf8 => "vzext.vf8",
+            Mnemonic::Wfi => "wfi",
+            Mnemonic::Xor => "xor",
+            Mnemonic::Xori => "xori",
+            Mnemonic::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMnemonicError(pub String);
+
+impl fmt::Display for ParseMnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown mnemonic: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseMnemonicError {}
+
+impl FromStr for Mnemonic {
+    type Err = ParseMnemonicError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "add" => Ok(Mnemonic::Add),
+            "addi" => Ok(Mnemonic::Addi),
+            "addiw" => Ok(Mnemonic::Addiw),
+            "addw" => Ok(Mnemonic::Addw),
+            "amoadd.d" => Ok(Mnemonic::AmoaddD),
+            "amoadd.w" => Ok(Mnemonic::AmoaddW),
+            "amoand.d" => Ok(Mnemonic::AmoandD),
+            "amoand.w" => Ok(Mnemonic::AmoandW),
+            "amomax.d" => Ok(Mnemonic::AmomaxD),
+            "amomax.w" => Ok(Mnemonic::AmomaxW),
+            "amomaxu.d" => Ok(Mnemonic::AmomaxuD),
+            "amomaxu.w" => Ok(Mnemonic::AmomaxuW),
+            "amomin.d" => Ok(Mnemonic::AmominD),
+            "amomin.w" => Ok(Mnemonic::AmominW),
+            "amominu.d" => Ok(Mnemonic::AmominuD),
+            "amominu.w" => Ok(Mnemonic::AmominuW),
+            "amoor.d" => Ok(Mnemonic::AmoorD),
+            "amoor.w" => Ok(Mnemonic::AmoorW),
+            "amoswap.d" => Ok(Mnemonic::AmoswapD),
+            "amoswap.w" => Ok(Mnemonic::AmoswapW),
+            "amoxor.d" => Ok(Mnemonic::AmoxorD),
+            "amoxor.w" => Ok(Mnemonic::AmoxorW),
+            "and" => Ok(Mnemonic::And),
+            "andi" => Ok(Mnemonic::Andi),
+            "auipc" => Ok(Mnemonic::Auipc),
+            "beq" => Ok(Mnemonic::Beq),
+            "bge" => Ok(Mnemonic::Bge),
+            "bgeu" => Ok(Mnemonic::Bgeu),
+            "blt" => Ok(Mnemonic::Blt),
+            "bltu" => Ok(Mnemonic::Bltu),
+            "bne" => Ok(Mnemonic::Bne),
+            "c.add" => Ok(Mnemonic::CAdd),
+            "c.addi" => Ok(Mnemonic::CAddi),
+            "c.addi16sp" => Ok(Mnemonic::CAddi16sp),
+            "c.addi4spn" => Ok(Mnemonic::CAddi4spn),
+            "c.addiw" => Ok(Mnemonic::CAddiw),
+            "c.addw" => Ok(Mnemonic::CAddw),
+            "c.and" => Ok(Mnemonic::CAnd),
+            "c.andi" => Ok(Mnemonic::CAndi),
+            "c.beqz" => Ok(Mnemonic::CBeqz),
+            "c.bnez" => Ok(Mnemonic::CBnez),
+            "c.ebreak" => Ok(Mnemonic::CEbreak),
+            "c.fld" => Ok(Mnemonic::CFld),
+            "c.fldsp" => Ok(Mnemonic::CFldsp),
+            "c.flw" => Ok(Mnemonic::CFlw),
+            "c.flwsp" => Ok(Mnemonic::CFlwsp),
+            "c.fsd" => Ok(Mnemonic::CFsd),
+            "c.fsdsp" => Ok(Mnemonic::CFsdsp),
+            "c.fsw" => Ok(Mnemonic::CFsw),
+            "c.fswsp" => Ok(Mnemonic::CFswsp),
+            "c.j" => Ok(Mnemonic::CJ),
+            "c.jal" => Ok(Mnemonic::CJal),
+            "c.jalr" => Ok(Mnemonic::CJalr),
+            "c.jr" => Ok(Mnemonic::CJr),
+            "c.lbu" => Ok(Mnemonic::CLbu),
+            "c.ld" => Ok(Mnemonic::CLd),
+            "c.ldsp" => Ok(Mnemonic::CLdsp),
+            "c.lh" => Ok(Mnemonic::CLh),
+            "c.lhu" => Ok(Mnemonic::CLhu),
+            "c.li" => Ok(Mnemonic::CLi),
+            "c.lui" => Ok(Mnemonic::CLui),
+            "c.lw" => Ok(Mnemonic::CLw),
+            "c.lwsp" => Ok(Mnemonic::CLwsp),
+            "c.mul" => Ok(Mnemonic::CMul),
+            "c.mv" => Ok(Mnemonic::CMv),
+            "c.nop" => Ok(Mnemonic::CNop),
+            "c.not" => Ok(Mnemonic::CNot),
+            "c.or" => Ok(Mnemonic::COr),
+            "c.sb" => Ok(Mnemonic::CSb),
+            "c.sd" => Ok(Mnemonic::CSd),
+            "c.sdsp" => Ok(Mnemonic::CSdsp),
+            "c.sext.b" => Ok(Mnemonic::CSextB),
+            "c.sext.h" => Ok(Mnemonic::CSextH),
+            "c.sh" => Ok(Mnemonic::CSh),
+            "c.slli" => Ok(Mnemonic::CSlli),
+            "c.srai" => Ok(Mnemonic::CSrai),
+            "c.srli" => Ok(Mnemonic::CSrli),
+            "c.sub" => Ok(Mnemonic::CSub),
+            "c.subw" => Ok(Mnemonic::CSubw),
+            "c.sw" => Ok(Mnemonic::CSw),
+            "c.swsp" => Ok(Mnemonic::CSwsp),
+            "c.xor" => Ok(Mnemonic::CXor),
+            "c.zext.b" => Ok(Mnemonic::CZextB),
+            "c.zext.h" => Ok(Mnemonic::CZextH),
+            "c.zext.w" => Ok(Mnemonic::CZextW),
+            "csrrc" => Ok(Mnemonic::Csrrc),
+            "csrrci" => Ok(Mnemonic::Csrrci),
+            "csrrs" => Ok(Mnemonic::Csrrs),
+            "csrrsi" => Ok(Mnemonic::Csrrsi),
+            "csrrw" => Ok(Mnemonic::Csrrw),
+            "csrrwi" => Ok(Mnemonic::Csrrwi),
+            "div" => Ok(Mnemonic::Div),
+            "divu" => Ok(Mnemonic::Divu),
+            "divuw" => Ok(Mnemonic::Divuw),
+            "divw" => Ok(Mnemonic::Divw),
+            "ebreak" => Ok(Mnemonic::Ebreak),
+            "ecall" => Ok(Mnemonic::Ecall),
+            "fadd.d" => Ok(Mnemonic::FaddD),
+            "fadd.s" => Ok(Mnemonic::FaddS),
+            "fclass.d" => Ok(Mnemonic::FclassD),
+            "fclass.s" => Ok(Mnemonic::FclassS),
+            "fcvt.d.l" => Ok(Mnemonic::FcvtDL),
+            "fcvt.d.lu" => Ok(Mnemonic::FcvtDLu),
+            "fcvt.d.s" => Ok(Mnemonic::FcvtDS),
+            "fcvt.d.w" => Ok(Mnemonic::FcvtDW),
+            "fcvt.d.wu" => Ok(Mnemonic::FcvtDWu),
+            "fcvt.l.d" => Ok(Mnemonic::FcvtLD),
+            "fcvt.l.s" => Ok(Mnemonic::FcvtLS),
+            "fcvt.lu.d" => Ok(Mnemonic::FcvtLuD),
+            "fcvt.lu.s" => Ok(Mnemonic::FcvtLuS),
+            "fcvt.s.d" => Ok(Mnemonic::FcvtSD),
+            "fcvt.s.l" => Ok(Mnemonic::FcvtSL),
+            "fcvt.s.lu" => Ok(Mnemonic::FcvtSLu),
+            "fcvt.s.w" => Ok(Mnemonic::FcvtSW),
+            "fcvt.s.wu" => Ok(Mnemonic::FcvtSWu),
+            "fcvt.w.d" => Ok(Mnemonic::FcvtWD),
+            "fcvt.w.s" => Ok(Mnemonic::FcvtWS),
+            "fcvt.wu.d" => Ok(Mnemonic::FcvtWuD),
+            "fcvt.wu.s" => Ok(Mnemonic::FcvtWuS),
+            "fdiv.d" => Ok(Mnemonic::FdivD),
+            "fdiv.s" => Ok(Mnemonic::FdivS),
+            "fence" => Ok(Mnemonic::Fence),
+            "feq.d" => Ok(Mnemonic::FeqD),
+            "feq.s" => Ok(Mnemonic::FeqS),
+            "fld" => Ok(Mnemonic::Fld),
+            "fle.d" => Ok(Mnemonic::FleD),
+            "fle.s" => Ok(Mnemonic::FleS),
+            "flt.d" => Ok(Mnemonic::FltD),
+            "flt.s" => Ok(Mnemonic::FltS),
+            "flw" => Ok(Mnemonic::Flw),
+            "fmadd.d" => Ok(Mnemonic::FmaddD),
+            "fmadd.s" => Ok(Mnemonic::FmaddS),
+            "fmax.d" => Ok(Mnemonic::FmaxD),
+            "fmax.s" => Ok(Mnemonic::FmaxS),
+            "fmin.d" => Ok(Mnemonic::FminD),
+            "fmin.s" => Ok(Mnemonic::FminS),
+            "fmsub.d" => Ok(Mnemonic::FmsubD),
+            "fmsub.s" => Ok(Mnemonic::FmsubS),
+            "fmul.d" => Ok(Mnemonic::FmulD),
+            "fmul.s" => Ok(Mnemonic::FmulS),
+            "fmv.d.x" => Ok(Mnemonic::FmvDX),
+            "fmv.w.x" => Ok(Mnemonic::FmvWX),
+            "fmv.x.d" => Ok(Mnemonic::FmvXD),
+            "fmv.x.w" => Ok(Mnemonic::FmvXW),
+            "fnmadd.d" => Ok(Mnemonic::FnmaddD),
+            "fnmadd.s" => Ok(Mnemonic::FnmaddS),
+            "fnmsub.d" => Ok(Mnemonic::FnmsubD),
+            "fnmsub.s" => Ok(Mnemonic::FnmsubS),
+            "fsd" => Ok(Mnemonic::Fsd),
+            "fsgnj.d" => Ok(Mnemonic::FsgnjD),
+            "fsgnj.s" => Ok(Mnemonic::FsgnjS),
+            "fsgnjn.d" => Ok(Mnemonic::FsgnjnD),
+            "fsgnjn.s" => Ok(Mnemonic::FsgnjnS),
+            "fsgnjx.d" => Ok(Mnemonic::FsgnjxD),
+            "fsgnjx.s" => Ok(Mnemonic::FsgnjxS),
+            "fsqrt.d" => Ok(Mnemonic::FsqrtD),
+            "fsqrt.s" => Ok(Mnemonic::FsqrtS),
+            "fsub.d" => Ok(Mnemonic::FsubD),
+            "fsub.s" => Ok(Mnemonic::FsubS),
+            "fsw" => Ok(Mnemonic::Fsw),
+            "jal" => Ok(Mnemonic::Jal),
+            "jalr" => Ok(Mnemonic::Jalr),
+            "lb" => Ok(Mnemonic::Lb),
+            "lbu" => Ok(Mnemonic::Lbu),
+            "ld" => Ok(Mnemonic::Ld),
+            "lh" => Ok(Mnemonic::Lh),
+            "lhu" => Ok(Mnemonic::Lhu),
+            "lr.d" => Ok(Mnemonic::LrD),
+            "lr.w" => Ok(Mnemonic::LrW),
+            "lui" => Ok(Mnemonic::Lui),
+            "lw" => Ok(Mnemonic::Lw),
+            "lwu" => Ok(Mnemonic::Lwu),
+            "mret" => Ok(Mnemonic::Mret),
+            "mul" => Ok(Mnemonic::Mul),
+            "mulh" => Ok(Mnemonic::Mulh),
+            "mulhsu" => Ok(Mnemonic::Mulhsu),
+            "mulhu" => Ok(Mnemonic::Mulhu),
+            "mulw" => Ok(Mnemonic::Mulw),
+            "or" => Ok(Mnemonic::Or),
+            "ori" => Ok(Mnemonic::Ori),
+            "rem" => Ok(Mnemonic::Rem),
+            "remu" => Ok(Mnemonic::Remu),
+            "remuw" => Ok(Mnemonic::Remuw),
+            "remw" => Ok(Mnemonic::Remw),
+            "sb" => Ok(Mnemonic::Sb),
+            "sc.d" => Ok(Mnemonic::ScD),
+            "sc.w" => Ok(Mnemonic::ScW),
+            "sd" => Ok(Mnemonic::Sd),
+            "sfence.vma" => Ok(Mnemonic::SfenceVma),
+            "sh" => Ok(Mnemonic::Sh),
+            "sll" => Ok(Mnemonic::Sll),
+            "slli" => Ok(Mnemonic::Slli),
+            "slliw" => Ok(Mnemonic::Slliw),
+            "sllw" => Ok(Mnemonic::Sllw),
+            "slt" => Ok(Mnemonic::Slt),
+            "slti" => Ok(Mnemonic::Slti),
+            "sltiu" => Ok(Mnemonic::Sltiu),
+            "sltu" => Ok(Mnemonic::Sltu),
+            "sra" => Ok(Mnemonic::Sra),
+            "srai" => Ok(Mnemonic::Srai),
+            "sraiw" => Ok(Mnemonic::Sraiw),
+            "sraw" => Ok(Mnemonic::Sraw),
+            "sret" => Ok(Mnemonic::Sret),
+            "srl" => Ok(Mnemonic::Srl),
+            "srli" => Ok(Mnemonic::Srli),
+            "srliw" => Ok(Mnemonic::Srliw),
+            "srlw" => Ok(Mnemonic::Srlw),
+            "sub" => Ok(Mnemonic::Sub),
+            "subw" => Ok(Mnemonic::Subw),
+            "sw" => Ok(Mnemonic::Sw),
+            "vaadd.vv" => Ok(Mnemonic::VaaddVv),
+            "vaadd.vx" => Ok(Mnemonic::VaaddVx),
+            "vaaddu.vv" => Ok(Mnemonic::VaadduVv),
+            "vaaddu.vx" => Ok(Mnemonic::VaadduVx),
+            "vadc.vim" => Ok(Mnemonic::VadcVim),
+            "vadc.vvm" => Ok(Mnemonic::VadcVvm),
+            "vadc.vxm" => Ok(Mnemonic::VadcVxm),
+            "vadd.vi" => Ok(Mnemonic::VaddVi),
+            "vadd.vv" => Ok(Mnemonic::VaddVv),
+            "vadd.vx" => Ok(Mnemonic::VaddVx),
+            "vand.vi" => Ok(Mnemonic::VandVi),
+            "vand.vv" => Ok(Mnemonic::VandVv),
+            "vand.vx" => Ok(Mnemonic::VandVx),
+            "vasub.vv" => Ok(Mnemonic::VasubVv),
+            "vasub.vx" => Ok(Mnemonic::VasubVx),
+            "vasubu.vv" => Ok(Mnemonic::VasubuVv),
+            "vasubu.vx" => Ok(Mnemonic::VasubuVx),
+            "vcompress.vm" => Ok(Mnemonic::VcompressVm),
+            "vcpop.m" => Ok(Mnemonic::VcpopM),
+            "vdiv.vv" => Ok(Mnemonic::VdivVv),
+            "vdiv.vx" => Ok(Mnemonic::VdivVx),
+            "vdivu.vv" => Ok(Mnemonic::VdivuVv),
+            "vdivu.vx" => Ok(Mnemonic::VdivuVx),
+            "vfadd.vf" => Ok(Mnemonic::VfaddVf),
+            "vfadd.vv" => Ok(Mnemonic::VfaddVv),
+            "vfclass.v" => Ok(Mnemonic::VfclassV),
+            "vfcvt.f.x.v" => Ok(Mnemonic::VfcvtFXV),
+            "vfcvt.f.xu.v" => Ok(Mnemonic::VfcvtFXuV),
+            "vfcvt.rtz.x.f.v" => Ok(Mnemonic::VfcvtRtzXFV),
+            "vfcvt.rtz.xu.f.v" => Ok(Mnemonic::VfcvtRtzXuFV),
+            "vfcvt.x.f.v" => Ok(Mnemonic::VfcvtXFV),
+            "vfcvt.xu.f.v" => Ok(Mnemonic::VfcvtXuFV),
+            "vfdiv.vf" => Ok(Mnemonic::VfdivVf),
+            "vfdiv.vv" => Ok(Mnemonic::VfdivVv),
+            "vfirst.m" => Ok(Mnemonic::VfirstM),
+            "vfmacc.vf" => Ok(Mnemonic::VfmaccVf),
+            "vfmacc.vv" => Ok(Mnemonic::VfmaccVv),
+            "vfmadd.vf" => Ok(Mnemonic::VfmaddVf),
+            "vfmadd.vv" => Ok(Mnemonic::VfmaddVv),
+            "vfmax.vf" => Ok(Mnemonic::VfmaxVf),
+            "vfmax.vv" => Ok(Mnemonic::VfmaxVv),
+            "vfmerge.vfm" => Ok(Mnemonic::VfmergeVfm),
+            "vfmin.vf" => Ok(Mnemonic::VfminVf),
+            "vfmin.vv" => Ok(Mnemonic::VfminVv),
+            "vfmsac.vf" => Ok(Mnemonic::VfmsacVf),
+            "vfmsac.vv" => Ok(Mnemonic::VfmsacVv),
+            "vfmsub.vf" => Ok(Mnemonic::VfmsubVf),
+            "vfmsub.vv" => Ok(Mnemonic::VfmsubVv),
+            "vfmul.vf" => Ok(Mnemonic::VfmulVf),
+            "vfmul.vv" => Ok(Mnemonic::VfmulVv),
+            "vfmv.f.s" => Ok(Mnemonic::VfmvFS),
+            "vfmv.s.f" => Ok(Mnemonic::VfmvSF),
+            "vfmv.v.f" => Ok(Mnemonic::VfmvVF),
+            "vfncvt.f.f.w" => Ok(Mnemonic::VfncvtFFW),
+            "vfncvt.f.x.w" => Ok(Mnemonic::VfncvtFXW),
+            "vfncvt.f.xu.w" => Ok(Mnemonic::VfncvtFXuW),
+            "vfncvt.rod.f.f.w" => Ok(Mnemonic::VfncvtRodFFW),
+            "vfncvt.rtz.x.f.w" => Ok(Mnemonic::VfncvtRtzXFW),
+            "vfncvt.rtz.xu.f.w" => Ok(Mnemonic::VfncvtRtzXuFW),
+            "vfncvt.x.f.w" => Ok(Mnemonic::VfncvtXFW),
+            "vfncvt.xu.f.w" => Ok(Mnemonic::VfncvtXuFW),
+            "vfnmacc.vf" => Ok(Mnemonic::VfnmaccVf),
+            "vfnmacc.vv" => Ok(Mnemonic::VfnmaccVv),
+            "vfnmadd.vf" => Ok(Mnemonic::VfnmaddVf),
+            "vfnmadd.vv" => Ok(Mnemonic::VfnmaddVv),
+            "vfnmsac.vf" => Ok(Mnemonic::VfnmsacVf),
+            "vfnmsac.vv" => Ok(Mnemonic::VfnmsacVv),
+            "vfnmsub.vf" => Ok(Mnemonic::VfnmsubVf),
+            "vfnmsub.vv" => Ok(Mnemonic::VfnmsubVv),
+            "vfrdiv.vf" => Ok(Mnemonic::VfrdivVf),
+            "vfrec7.v" => Ok(Mnemonic::Vfrec7V),
+            "vfredmax.vs" => Ok(Mnemonic::VfredmaxVs),
+            "vfredmin.vs" => Ok(Mnemonic::VfredminVs),
+            "vfredosum.vs" => Ok(Mnemonic::VfredosumVs),
+            "vfredusum.vs" => Ok(Mnemonic::VfredusumVs),
+            "vfrsqrt7.v" => Ok(Mnemonic::Vfrsqrt7V),
+            "vfrsub.vf" => Ok(Mnemonic::VfrsubVf),
+            "vfsgnj.vf" => Ok(Mnemonic::VfsgnjVf),
+            "vfsgnj.vv" => Ok(Mnemonic::VfsgnjVv),
+            "vfsgnjn.vf" => Ok(Mnemonic::VfsgnjnVf),
+            "vfsgnjn.vv" => Ok(Mnemonic::VfsgnjnVv),
+            "vfsgnjx.vf" => Ok(Mnemonic::VfsgnjxVf),
+            "vfsgnjx.vv" => Ok(Mnemonic::VfsgnjxVv),
+            "vfslide1down.vf" => Ok(Mnemonic::Vfslide1downVf),
+            "vfslide1up.vf" => Ok(Mnemonic::Vfslide1upVf),
+            "vfsqrt.v" => Ok(Mnemonic::VfsqrtV),
+            "vfsub.vf" => Ok(Mnemonic::VfsubVf),
+            "vfsub.vv" => Ok(Mnemonic::VfsubVv),
+            "vfwadd.vf" => Ok(Mnemonic::VfwaddVf),
+            "vfwadd.vv" => Ok(Mnemonic::VfwaddVv),
+            "vfwadd.wf" => Ok(Mnemonic::VfwaddWf),
+            "vfwadd.wv" => Ok(Mnemonic::VfwaddWv),
+            "vfwcvt.f.f.v" => Ok(Mnemonic::VfwcvtFFV),
+            "vfwcvt.f.x.v" => Ok(Mnemonic::VfwcvtFXV),
+            "vfwcvt.f.xu.v" => Ok(Mnemonic::VfwcvtFXuV),
+            "vfwcvt.rtz.x.f.v" => Ok(Mnemonic::VfwcvtRtzXFV),
+            "vfwcvt.rtz.xu.f.v" => Ok(Mnemonic::VfwcvtRtzXuFV),
+            "vfwcvt.x.f.v" => Ok(Mnemonic::VfwcvtXFV),
+            "vfwcvt.xu.f.v" => Ok(Mnemonic::VfwcvtXuFV),
+            "vfwmacc.vf" => Ok(Mnemonic::VfwmaccVf),
+            "vfwmacc.vv" => Ok(Mnemonic::VfwmaccVv),
+            "vfwmsac.vf" => Ok(Mnemonic::VfwmsacVf),
+            "vfwmsac.vv" => Ok(Mnemonic::VfwmsacVv),
+            "vfwmul.vf" => Ok(Mnemonic::VfwmulVf),
+            "vfwmul.vv" => Ok(Mnemonic::VfwmulVv),
+            "vfwnmacc.vf" => Ok(Mnemonic::VfwnmaccVf),
+            "vfwnmacc.vv" => Ok(Mnemonic::VfwnmaccVv),
+            "vfwnmsac.vf" => Ok(Mnemonic::VfwnmsacVf),
+            "vfwnmsac.vv" => Ok(Mnemonic::VfwnmsacVv),
+            "vfwredosum.vs" => Ok(Mnemonic::VfwredosumVs),
+            "vfwredusum.vs" => Ok(Mnemonic::VfwredusumVs),
+            "vfwsub.vf" => Ok(Mnemonic::VfwsubVf),
+            "vfwsub.vv" => Ok(Mnemonic::VfwsubVv),
+            "vfwsub.wf" => Ok(Mnemonic::VfwsubWf),
+            "vfwsub.wv" => Ok(Mnemonic::VfwsubWv),
+            "vid.v" => Ok(Mnemonic::VidV),
+            "viota.m" => Ok(Mnemonic::ViotaM),
+            "vl1re16.v" => Ok(Mnemonic::Vl1re16V),
+            "vl1re32.v" => Ok(Mnemonic::Vl1re32V),
+            "vl1re64.v" => Ok(Mnemonic::Vl1re64V),
+            "vl1re8.v" => Ok(Mnemonic::Vl1re8V),
+            "vl2re16.v" => Ok(Mnemonic::Vl2re16V),
+            "vl2re32.v" => Ok(Mnemonic::Vl2re32V),
+            "vl2re64.v" => Ok(Mnemonic::Vl2re64V),
+            "vl2re8.v" => Ok(Mnemonic::Vl2re8V),
+            "vl4re16.v" => Ok(Mnemonic::Vl4re16V),
+            "vl4re32.v" => Ok(Mnemonic::Vl4re32V),
+            "vl4re64.v" => Ok(Mnemonic::Vl4re64V),
+            "vl4re8.v" => Ok(Mnemonic::Vl4re8V),
+            "vl8re16.v" => Ok(Mnemonic::Vl8re16V),
+            "vl8re32.v" => Ok(Mnemonic::Vl8re32V),
+            "vl8re64.v" => Ok(Mnemonic::Vl8re64V),
+            "vl8re8.v" => Ok(Mnemonic::Vl8re8V),
+            "vle16.v" => Ok(Mnemonic::Vle16V),
+            "vle16ff.v" => Ok(Mnemonic::Vle16ffV),
+            "vle32.v" => Ok(Mnemonic::Vle32V),
+            "vle32ff.v" => Ok(Mnemonic::Vle32ffV),
+            "vle64.v" => Ok(Mnemonic::Vle64V),
+            "vle64ff.v" => Ok(Mnemonic::Vle64ffV),
+            "vle8.v" => Ok(Mnemonic::Vle8V),
+            "vle8ff.v" => Ok(Mnemonic::Vle8ffV),
+            "vlm.v" => Ok(Mnemonic::VlmV),
+            "vloxei16.v" => Ok(Mnemonic::Vloxei16V),
+            "vloxei32.v" => Ok(Mnemonic::Vloxei32V),
+            "vloxei64.v" => Ok(Mnemonic::Vloxei64V),
+            "vloxei8.v" => Ok(Mnemonic::Vloxei8V),
+            "vlse16.v" => Ok(Mnemonic::Vlse16V),
+            "vlse32.v" => Ok(Mnemonic::Vlse32V),
+            "vlse64.v" => Ok(Mnemonic::Vlse64V),
+            "vlse8.v" => Ok(Mnemonic::Vlse8V),
+            "vluxei16.v" => Ok(Mnemonic::Vluxei16V),
+            "vluxei32.v" => Ok(Mnemonic::Vluxei32V),
+            "vluxei64.v" => Ok(Mnemonic::Vluxei64V),
+            "vluxei8.v" => Ok(Mnemonic::Vluxei8V),
+            "vmacc.vv" => Ok(Mnemonic::VmaccVv),
+            "vmacc.vx" => Ok(Mnemonic::VmaccVx),
+            "vmadc.vi" => Ok(Mnemonic::VmadcVi),
+            "vmadc.vim" => Ok(Mnemonic::VmadcVim),
+            "vmadc.vv" => Ok(Mnemonic::VmadcVv),
+            "vmadc.vvm" => Ok(Mnemonic::VmadcVvm),
+            "vmadc.vx" => Ok(Mnemonic::VmadcVx),
+            "vmadc.vxm" => Ok(Mnemonic::VmadcVxm),
+            "vmadd.vv" => Ok(Mnemonic::VmaddVv),
+            "vmadd.vx" => Ok(Mnemonic::VmaddVx),
+            "vmand.mm" => Ok(Mnemonic::VmandMm),
+            "vmandn.mm" => Ok(Mnemonic::VmandnMm),
+            "vmax.vv" => Ok(Mnemonic::VmaxVv),
+            "vmax.vx" => Ok(Mnemonic::VmaxVx),
+            "vmaxu.vv" => Ok(Mnemonic::VmaxuVv),
+            "vmaxu.vx" => Ok(Mnemonic::VmaxuVx),
+            "vmerge.vim" => Ok(Mnemonic::VmergeVim),
+            "vmerge.vvm" => Ok(Mnemonic::VmergeVvm),
+            "vmerge.vxm" => Ok(Mnemonic::VmergeVxm),
+            "vmfeq.vf" => Ok(Mnemonic::VmfeqVf),
+            "vmfeq.vv" => Ok(Mnemonic::VmfeqVv),
+            "vmfge.vf" => Ok(Mnemonic::VmfgeVf),
+            "vmfgt.vf" => Ok(Mnemonic::VmfgtVf),
+            "vmfle.vf" => Ok(Mnemonic::VmfleVf),
+            "vmfle.vv" => Ok(Mnemonic::VmfleVv),
+            "vmflt.vf" => Ok(Mnemonic::VmfltVf),
+            "vmflt.vv" => Ok(Mnemonic::VmfltVv),
+            "vmfne.vf" => Ok(Mnemonic::VmfneVf),
+            "vmfne.vv" => Ok(Mnemonic::VmfneVv),
+            "vmin.vv" => Ok(Mnemonic::VminVv),
+            "vmin.vx" => Ok(Mnemonic::VminVx),
+            "vminu.vv" => Ok(Mnemonic::VminuVv),
+            "vminu.vx" => Ok(Mnemonic::VminuVx),
+            "vmnand.mm" => Ok(Mnemonic::VmnandMm),
+            "vmnor.mm" => Ok(Mnemonic::VmnorMm),
+            "vmor.mm" => Ok(Mnemonic::VmorMm),
+            "vmorn.mm" => Ok(Mnemonic::VmornMm),
+            "vmsbc.vv" => Ok(Mnemonic::VmsbcVv),
+            "vmsbc.vvm" => Ok(Mnemonic::VmsbcVvm),
+            "vmsbc.vx" => Ok(Mnemonic::VmsbcVx),
+            "vmsbc.vxm" => Ok(Mnemonic::VmsbcVxm),
+            "vmsbf.m" => Ok(Mnemonic::VmsbfM),
+            "vmseq.vi" => Ok(Mnemonic::VmseqVi),
+            "vmseq.vv" => Ok(Mnemonic::VmseqVv),
+            "vmseq.vx" => Ok(Mnemonic::VmseqVx),
+            "vmsgt.vi" => Ok(Mnemonic::VmsgtVi),
+            "vmsgt.vx" => Ok(Mnemonic::VmsgtVx),
+            "vmsgtu.vi" => Ok(Mnemonic::VmsgtuVi),
+            "vmsgtu.vx" => Ok(Mnemonic::VmsgtuVx),
+            "vmsif.m" => Ok(Mnemonic::VmsifM),
+            "vmsle.vi" => Ok(Mnemonic::VmsleVi),
+            "vmsle.vv" => Ok(Mnemonic::VmsleVv),
+            "vmsle.vx" => Ok(Mnemonic::VmsleVx),
+            "vmsleu.vi" => Ok(Mnemonic::VmsleuVi),
+            "vmsleu.vv" => Ok(Mnemonic::VmsleuVv),
+            "vmsleu.vx" => Ok(Mnemonic::VmsleuVx),
+            "vmslt.vv" => Ok(Mnemonic::VmsltVv),
+            "vmslt.vx" => Ok(Mnemonic::VmsltVx),
+            "vmsltu.vv" => Ok(Mnemonic::VmsltuVv),
+            "vmsltu.vx" => Ok(Mnemonic::VmsltuVx),
+            "vmsne.vi" => Ok(Mnemonic::VmsneVi),
+            "vmsne.vv" => Ok(Mnemonic::VmsneVv),
+            "vmsne.vx" => Ok(Mnemonic::VmsneVx),
+            "vmsof.m" => Ok(Mnemonic::VmsofM),
+            "vmul.vv" => Ok(Mnemonic::VmulVv),
+            "vmul.vx" => Ok(Mnemonic::VmulVx),
+            "vmulh.vv" => Ok(Mnemonic::VmulhVv),
+            "vmulh.vx" => Ok(Mnemonic::VmulhVx),
+            "vmulhsu.vv" => Ok(Mnemonic::VmulhsuVv),
+            "vmulhsu.vx" => Ok(Mnemonic::VmulhsuVx),
+            "vmulhu.vv" => Ok(Mnemonic::VmulhuVv),
+            "vmulhu.vx" => Ok(Mnemonic::VmulhuVx),
+            "vmv.s.x" => Ok(Mnemonic::VmvSX),
+            "vmv.v.i" => Ok(Mnemonic::VmvVI),
+            "vmv.v.v" => Ok(Mnemonic::VmvVV),
+            "vmv.v.x" => Ok(Mnemonic::VmvVX),
+            "vmv.x.s" => Ok(Mnemonic::VmvXS),
+            "vmv1r.v" => Ok(Mnemonic::Vmv1rV),
+            "vmv2r.v" => Ok(Mnemonic::Vmv2rV),
+            "vmv4r.v" => Ok(Mnemonic::Vmv4rV),
+            "vmv8r.v" => Ok(Mnemonic::Vmv8rV),
+            "vmxnor.mm" => Ok(Mnemonic::VmxnorMm),
+            "vmxor.mm" => Ok(Mnemonic::VmxorMm),
+            "vnclip.wi" => Ok(Mnemonic::VnclipWi),
+            "vnclip.wv" => Ok(Mnemonic::VnclipWv),
+            "vnclip.wx" => Ok(Mnemonic::VnclipWx),
+            "vnclipu.wi" => Ok(Mnemonic::VnclipuWi),
+            "vnclipu.wv" => Ok(Mnemonic::VnclipuWv),
+            "vnclipu.wx" => Ok(Mnemonic::VnclipuWx),
+            "vnmsac.vv" => Ok(Mnemonic::VnmsacVv),
+            "vnmsac.vx" => Ok(Mnemonic::VnmsacVx),
+            "vnmsub.vv" => Ok(Mnemonic::VnmsubVv),
+            "vnmsub.vx" => Ok(Mnemonic::VnmsubVx),
+            "vnsra.wi" => Ok(Mnemonic::VnsraWi),
+            "vnsra.wv" => Ok(Mnemonic::VnsraWv),
+            "vnsra.wx" => Ok(Mnemonic::VnsraWx),
+            "vnsrl.wi" => Ok(Mnemonic::VnsrlWi),
+            "vnsrl.wv" => Ok(Mnemonic::VnsrlWv),
+            "vnsrl.wx" => Ok(Mnemonic::VnsrlWx),
+            "vor.vi" => Ok(Mnemonic::VorVi),
+            "vor.vv" => Ok(Mnemonic::VorVv),
+            "vor.vx" => Ok(Mnemonic::VorVx),
+            "vredand.vs" => Ok(Mnemonic::VredandVs),
+            "vredmax.vs" => Ok(Mnemonic::VredmaxVs),
+            "vredmaxu.vs" => Ok(Mnemonic::VredmaxuVs),
+            "vredmin.vs" => Ok(Mnemonic::VredminVs),
+            "vredminu.vs" => Ok(Mnemonic::VredminuVs),
+            "vredor.vs" => Ok(Mnemonic::VredorVs),
+            "vredsum.vs" => Ok(Mnemonic::VredsumVs),
+            "vredxor.vs" => Ok(Mnemonic::VredxorVs),
+            "vrem.vv" => Ok(Mnemonic::VremVv),
+            "vrem.vx" => Ok(Mnemonic::VremVx),
+            "vremu.vv" => Ok(Mnemonic::VremuVv),
+            "vremu.vx" => Ok(Mnemonic::VremuVx),
+            "vrgather.vi" => Ok(Mnemonic::VrgatherVi),
+            "vrgather.vv" => Ok(Mnemonic::VrgatherVv),
+            "vrgather.vx" => Ok(Mnemonic::VrgatherVx),
+            "vrgatherei16.vv" => Ok(Mnemonic::Vrgatherei16Vv),
+            "vrsub.vi" => Ok(Mnemonic::VrsubVi),
+            "vrsub.vx" => Ok(Mnemonic::VrsubVx),
+            "vs1r.v" => Ok(Mnemonic::Vs1rV),
+            "vs2r.v" => Ok(Mnemonic::Vs2rV),
+            "vs4r.v" => Ok(Mnemonic::Vs4rV),
+            "vs8r.v" => Ok(Mnemonic::Vs8rV),
+            "vsadd.vi" => Ok(Mnemonic::VsaddVi),
+            "vsadd.vv" => Ok(Mnemonic::VsaddVv),
+            "vsadd.vx" => Ok(Mnemonic::VsaddVx),
+            "vsaddu.vi" => Ok(Mnemonic::VsadduVi),
+            "vsaddu.vv" => Ok(Mnemonic::VsadduVv),
+            "vsaddu.vx" => Ok(Mnemonic::VsadduVx),
+            "vsbc.vvm" => Ok(Mnemonic::VsbcVvm),
+            "vsbc.vxm" => Ok(Mnemonic::VsbcVxm),
+            "vse16.v" => Ok(Mnemonic::Vse16V),
+            "vse32.v" => Ok(Mnemonic::Vse32V),
+            "vse64.v" => Ok(Mnemonic::Vse64V),
+            "vse8.v" => Ok(Mnemonic::Vse8V),
+            "vsetivli" => Ok(Mnemonic::Vsetivli),
+            "vsetvl" => Ok(Mnemonic::Vsetvl),
+            "vsetvli" => Ok(Mnemonic::Vsetvli),
+            "vsext.vf2" => Ok(Mnemonic::VsextVf2),
+            "vsext.vf4" => Ok(Mnemonic::VsextVf4),
+            "vsext.vf8" => Ok(Mnemonic::VsextVf8),
+            "vslide1down.vx" => Ok(Mnemonic::Vslide1downVx),
+            "vslide1up.vx" => Ok(Mnemonic::Vslide1upVx),
+            "vslidedown.vi" => Ok(Mnemonic::VslidedownVi),
+            "vslidedown.vx" => Ok(Mnemonic::VslidedownVx),
+            "vslideup.vi" => Ok(Mnemonic::VslideupVi),
+            "vslideup.vx" => Ok(Mnemonic::VslideupVx),
+            "vsll.vi" => Ok(Mnemonic::VsllVi),
+            "vsll.vv" => Ok(Mnemonic::VsllVv),
+            "vsll.vx" => Ok(Mnemonic::VsllVx),
+            "vsm.v" => Ok(Mnemonic::VsmV),
+            "vsmul.vv" => Ok(Mnemonic::VsmulVv),
+            "vsmul.vx" => Ok(Mnemonic::VsmulVx),
+            "vsoxei16.v" => Ok(Mnemonic::Vsoxei16V),
+            "vsoxei32.v" => Ok(Mnemonic::Vsoxei32V),
+            "vsoxei64.v" => Ok(Mnemonic::Vsoxei64V),
+            "vsoxei8.v" => Ok(Mnemonic::Vsoxei8V),
+            "vsra.vi" => Ok(Mnemonic::VsraVi),
+            "vsra.vv" => Ok(Mnemonic::VsraVv),
+            "vsra.vx" => Ok(Mnemonic::VsraVx),
+            "vsrl.vi" => Ok(Mnemonic::VsrlVi),
+            "vsrl.vv" => Ok(Mnemonic::VsrlVv),
+            "vsrl.vx" => Ok(Mnemonic::VsrlVx),
+            "vsse16.v" => Ok(Mnemonic::Vsse16V),
+            "vsse32.v" => Ok(Mnemonic::Vsse32V),
+            "vsse64.v" => Ok(Mnemonic::Vsse64V),
+            "vsse8.v" => Ok(Mnemonic::Vsse8V),
+            "vssra.vi" => Ok(Mnemonic::VssraVi),
+            "vssra.vv" => Ok(Mnemonic::VssraVv),
+            "vssra.vx" => Ok(Mnemonic::VssraVx),
+            "vssrl.vi" => Ok(Mnemonic::VssrlVi),
+            "vssrl.vv" => Ok(Mnemonic::VssrlVv),
+            "vssrl.vx" => Ok(Mnemonic::VssrlVx),
+            "vssub.vv" => Ok(Mnemonic::VssubVv),
+            "vssub.vx" => Ok(Mnemonic::VssubVx),
+            "vssubu.vv" => Ok(Mnemonic::VssubuVv),
+            "vssubu.vx" => Ok(Mnemonic::VssubuVx),
+            "vsub.vv" => Ok(Mnemonic::VsubVv),
+            "vsub.vx" => Ok(Mnemonic::VsubVx),
+            "vsuxei16.v" => Ok(Mnemonic::Vsuxei16V),
+            "vsuxei32.v" => Ok(Mnemonic::Vsuxei32V),
+            "vsuxei64.v" => Ok(Mnemonic::Vsuxei64V),
+            "vsuxei8.v" => Ok(Mnemonic::Vsuxei8V),
+            "vwadd.vv" => Ok(Mnemonic::VwaddVv),
+            "vwadd.vx" => Ok(Mnemonic::VwaddVx),
+            "vwadd.wv" => Ok(Mnemonic::VwaddWv),
+            "vwadd.wx" => Ok(Mnemonic::VwaddWx),
+            "vwaddu.vv" => Ok(Mnemonic::VwadduVv),
+            "vwaddu.vx" => Ok(Mnemonic::VwadduVx),
+            "vwaddu.wv" => Ok(Mnemonic::VwadduWv),
+            "vwaddu.wx" => Ok(Mnemonic::VwadduWx),
+            "vwmacc.vv" => Ok(Mnemonic::VwmaccVv),
+            "vwmacc.vx" => Ok(Mnemonic::VwmaccVx),
+            "vwmaccsu.vv" => Ok(Mnemonic::VwmaccsuVv),
+            "vwmaccsu.vx" => Ok(Mnemonic::VwmaccsuVx),
+            "vwmaccu.vv" => Ok(Mnemonic::VwmaccuVv),
+            "vwmaccu.vx" => Ok(Mnemonic::VwmaccuVx),
+            "vwmaccus.vx" => Ok(Mnemonic::VwmaccusVx),
+            "vwmul.vv" => Ok(Mnemonic::VwmulVv),
+            "vwmul.vx" => Ok(Mnemonic::VwmulVx),
+            "vwmulsu.vv" => Ok(Mnemonic::VwmulsuVv),
+            "vwmulsu.vx" => Ok(Mnemonic::VwmulsuVx),
+            "vwmulu.vv" => Ok(Mnemonic::VwmuluVv),
+            "vwmulu.vx" => Ok(Mnemonic::VwmuluVx),
+            "vwredsum.vs" => Ok(Mnemonic::VwredsumVs),
+            "vwredsumu.vs" => Ok(Mnemonic::VwredsumuVs),
+            "vwsub.vv" => Ok(Mnemonic::VwsubVv),
+            "vwsub.vx" => Ok(Mnemonic::VwsubVx),
+            "vwsub.wv" => Ok(Mnemonic::VwsubWv),
+            "vwsub.wx" => Ok(Mnemonic::VwsubWx),
+            "vwsubu.vv" => Ok(Mnemonic::VwsubuVv),
+            "vwsubu.vx" => Ok(Mnemonic::VwsubuVx),
+            "vwsubu.wv" => Ok(Mnemonic::VwsubuWv),
+            "vwsubu.wx" => Ok(Mnemonic::VwsubuWx),
+            "vxor.vi" => Ok(Mnemonic::VxorVi),
+            "vxor.vv" => Ok(Mnemonic::VxorVv),
+            "vxor.vx" => Ok(Mnemonic::VxorVx),
+            "vzext.vf2" => Ok(Mnemonic::VzextVf2),
+            "vzext.vf4" => Ok(Mnemonic::VzextVf4),
+            "vzext.vf8" => Ok(Mnemonic::VzextVf8),
+            "wfi" => Ok(Mnemonic::Wfi),
+            "xor" => Ok(Mnemonic::Xor),
+            "xori" => Ok(Mnemonic::Xori),
+            "unknown" => Ok(Mnemonic::Unknown),
+            _ => Err(ParseMnemonicError(s.to_string())),
+        }
+    }
+}
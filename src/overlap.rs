@@ -0,0 +1,324 @@
+use crate::disassembler::{is_compressed, Xlen};
+use crate::isa::*;
+use crate::isa_meta::Extension;
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// The spec tables, in the order `disassmeble_one` searches them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Table {
+    GenericCompressed,
+    XlenCompressed,
+    GenericFull,
+    XlenFull,
+}
+
+impl Table {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Table::GenericCompressed => "generic compressed",
+            Table::XlenCompressed => "xlen compressed",
+            Table::GenericFull => "generic",
+            Table::XlenFull => "xlen",
+        }
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How much of the encoding space one spec claims, counted in 16-bit words
+/// for compressed specs and 32-bit words otherwise
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpecCoverage {
+    pub name: String,
+    pub mnemonic: Mnemonic,
+    pub table: Table,
+    pub mask_bits: u32,
+    pub match_bits: u32,
+    /// Encodings agreeing with the mask and match bits
+    pub matched: u64,
+    /// Encodings that decode as this spec
+    pub decoded: u64,
+}
+
+impl SpecCoverage {
+    pub fn is_reachable(&self) -> bool {
+        self.decoded > 0
+    }
+}
+
+/// Encodings that two specs both accept, which decode as the earlier one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conflict {
+    pub winner: Mnemonic,
+    pub winner_table: Table,
+    pub hidden: Mnemonic,
+    pub hidden_table: Table,
+    pub count: u64,
+    /// The lowest of the encodings
+    pub example: u32,
+}
+
+/// Which encodings every spec decodes, and where the first-match order
+/// hides one spec behind another
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OverlapReport {
+    /// Compressed specs in search order, then 32-bit specs by opcode
+    pub specs: Vec<SpecCoverage>,
+    pub conflicts: Vec<Conflict>,
+}
+
+/// A set of encodings, those with the bits under `mask` equal to `bits`
+#[derive(Debug, Clone, Copy)]
+struct Cube {
+    mask: u32,
+    bits: u32,
+}
+
+impl Cube {
+    fn of(spec: &Spec) -> Self {
+        Self {
+            mask: spec.mask_bits,
+            bits: spec.match_bits,
+        }
+    }
+
+    /// Helper: Count the 32-bit encodings in the cube
+    fn size(&self) -> u64 {
+        1 << (32 - self.mask.count_ones())
+    }
+
+    fn intersect(&self, other: &Cube) -> Option<Cube> {
+        if (self.bits ^ other.bits) & self.mask & other.mask != 0 {
+            return None;
+        }
+        Some(Cube {
+            mask: self.mask | other.mask,
+            bits: self.bits | other.bits,
+        })
+    }
+
+    /// Helper: Split the part of the cube outside `other` into disjoint cubes
+    fn subtract(&self, other: &Cube, out: &mut Vec<Cube>) {
+        if self.intersect(other).is_none() {
+            out.push(*self);
+            return;
+        }
+        // peel off one bit at a time where `other` is fixed and we are not
+        let mut rest = *self;
+        let mut free = other.mask & !self.mask;
+        while free != 0 {
+            let bit = free & free.wrapping_neg();
+            free &= free - 1;
+            out.push(Cube {
+                mask: rest.mask | bit,
+                bits: rest.bits | (!other.bits & bit),
+            });
+            rest = Cube {
+                mask: rest.mask | bit,
+                bits: rest.bits | (other.bits & bit),
+            };
+        }
+    }
+}
+
+impl OverlapReport {
+    /// Check the specs of `xlen`, limited to `extensions` as in
+    /// `Disassembler::with_extensions`. The 16-bit space is decoded
+    /// exhaustively, so operand constraints like `rd_n0` count; 32-bit specs
+    /// have none and are compared symbolically per opcode
+    pub fn build(xlen: Xlen, extensions: Option<&BTreeSet<Extension>>) -> Self {
+        let xlen_compressed = match xlen {
+            Xlen::XLEN32 => &*RV_ISA_SPECS_32_COMPRESSED,
+            Xlen::XLEN64 => &*RV_ISA_SPECS_64_COMPRESSED,
+        };
+        let tables = [
+            (&*RV_ISA_SPECS_GENERIC_COMPRESSED, Table::GenericCompressed),
+            (xlen_compressed, Table::XlenCompressed),
+        ];
+        let mut specs: Vec<(&Spec, Table)> = tables
+            .into_iter()
+            .flat_map(|(specs, table)| specs.iter().map(move |spec| (spec, table)))
+            .collect();
+        for opcode in 0..0x80u8 {
+            let xlen_full = match xlen {
+                Xlen::XLEN32 => get_32_full_specs_by_opcode(opcode),
+                Xlen::XLEN64 => get_64_full_specs_by_opcode(opcode),
+            };
+            let tables = [
+                (get_generic_full_specs_by_opcode(opcode), Table::GenericFull),
+                (xlen_full, Table::XlenFull),
+            ];
+            for (table_specs, table) in tables {
+                specs.extend(
+                    table_specs
+                        .into_iter()
+                        .flat_map(|table_specs| table_specs.iter())
+                        .map(|spec| (spec, table)),
+                );
+            }
+        }
+        specs.retain(|(spec, _)| extensions.is_none_or(|set| set.contains(&spec.extension)));
+        Self::check(&specs)
+    }
+
+    /// Check arbitrary specs, given in the order a decoder tries them
+    pub fn check(specs: &[(&Spec, Table)]) -> Self {
+        let mut report = Self::default();
+        let compressed: Vec<(&Spec, Table)> = specs
+            .iter()
+            .filter(|(spec, _)| is_compressed(spec.match_bits))
+            .copied()
+            .collect();
+        report.check_compressed(&compressed);
+        for opcode in 0..0x80u32 {
+            let full: Vec<(&Spec, Table)> = specs
+                .iter()
+                .filter(|(spec, _)| !is_compressed(spec.match_bits))
+                .filter(|(spec, _)| spec.match_bits & 0x7f == opcode)
+                .copied()
+                .collect();
+            report.check_full(&full);
+        }
+        report
+    }
+
+    /// Specs that no encoding decodes as
+    pub fn unreachable(&self) -> impl Iterator<Item = &SpecCoverage> {
+        self.specs.iter().filter(|spec| !spec.is_reachable())
+    }
+
+    /// Helper: Decode every 16-bit word against the compressed specs
+    fn check_compressed(&mut self, specs: &[(&Spec, Table)]) {
+        let mut matched = vec![0; specs.len()];
+        let mut decoded = vec![0; specs.len()];
+        // (winner, hidden) -> (count, example)
+        let mut conflicts: BTreeMap<(usize, usize), (u64, u32)> = BTreeMap::new();
+        for word in (0..0x10000u32).filter(|word| word & 0b11 != 0b11) {
+            let mut winner = None;
+            for (index, (spec, _)) in specs.iter().enumerate() {
+                if !spec.compare(word) {
+                    continue;
+                }
+                matched[index] += 1;
+                // the decoder moves on past specs whose operands are invalid
+                if spec.args.iter().any(|arg| arg(word).0.is_error()) {
+                    continue;
+                }
+                match winner {
+                    None => {
+                        winner = Some(index);
+                        decoded[index] += 1;
+                    }
+                    Some(winner) => {
+                        conflicts.entry((winner, index)).or_insert((0, word)).0 += 1;
+                    }
+                }
+            }
+        }
+        self.add(specs, &matched, &decoded, conflicts);
+    }
+
+    /// Helper: Intersect the mask and match bits of 32-bit specs sharing an
+    /// opcode, subtracting what earlier specs already claim
+    fn check_full(&mut self, specs: &[(&Spec, Table)]) {
+        let cubes: Vec<Cube> = specs.iter().map(|(spec, _)| Cube::of(spec)).collect();
+        let matched: Vec<u64> = cubes.iter().map(Cube::size).collect();
+        let mut decoded = vec![0; specs.len()];
+        let mut conflicts = BTreeMap::new();
+        for (index, cube) in cubes.iter().enumerate() {
+            let mut claimed = vec![*cube];
+            for earlier in &cubes[..index] {
+                let mut rest = Vec::new();
+                for part in &claimed {
+                    part.subtract(earlier, &mut rest);
+                }
+                claimed = rest;
+            }
+            decoded[index] = claimed.iter().map(Cube::size).sum();
+            for (later, other) in cubes.iter().enumerate().skip(index + 1) {
+                let shared: Vec<Cube> = claimed
+                    .iter()
+                    .filter_map(|part| part.intersect(other))
+                    .collect();
+                if let Some(example) = shared.iter().map(|part| part.bits).min() {
+                    let count = shared.iter().map(Cube::size).sum();
+                    conflicts.insert((index, later), (count, example));
+                }
+            }
+        }
+        self.add(specs, &matched, &decoded, conflicts);
+    }
+
+    /// Helper: Record the coverage and conflicts of one group of specs
+    fn add(
+        &mut self,
+        specs: &[(&Spec, Table)],
+        matched: &[u64],
+        decoded: &[u64],
+        conflicts: BTreeMap<(usize, usize), (u64, u32)>,
+    ) {
+        for (index, (spec, table)) in specs.iter().enumerate() {
+            self.specs.push(SpecCoverage {
+                name: spec.name.clone(),
+                mnemonic: spec.mnemonic,
+                table: *table,
+                mask_bits: spec.mask_bits,
+                match_bits: spec.match_bits,
+                matched: matched[index],
+                decoded: decoded[index],
+            });
+        }
+        for ((winner, hidden), (count, example)) in conflicts {
+            self.conflicts.push(Conflict {
+                winner: specs[winner].0.mnemonic,
+                winner_table: specs[winner].1,
+                hidden: specs[hidden].0.mnemonic,
+                hidden_table: specs[hidden].1,
+                count,
+                example,
+            });
+        }
+    }
+}
+
+impl fmt::Display for OverlapReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unreachable: Vec<_> = self.unreachable().collect();
+        writeln!(
+            f,
+            "{} specs, {} conflicts, {} unreachable",
+            self.specs.len(),
+            self.conflicts.len(),
+            unreachable.len()
+        )?;
+        writeln!(f, "conflicts:")?;
+        for conflict in &self.conflicts {
+            writeln!(
+                f,
+                "  {} ({}) hides {} ({}): {} encodings, e.g. {:08x}",
+                conflict.winner,
+                conflict.winner_table,
+                conflict.hidden,
+                conflict.hidden_table,
+                conflict.count,
+                conflict.example
+            )?;
+        }
+        writeln!(f, "unreachable:")?;
+        for spec in unreachable {
+            writeln!(
+                f,
+                "  {} ({}): mask {:08x} match {:08x}",
+                spec.mnemonic, spec.table, spec.mask_bits, spec.match_bits
+            )?;
+        }
+        Ok(())
+    }
+}
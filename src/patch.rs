@@ -0,0 +1,176 @@
+use crate::control_flow::Target;
+use crate::disassembler::{is_compressed, Disassembler, Xlen};
+use crate::encoder::{arg_value, field_layout, place, specs, EncodeError};
+use crate::mnemonic::Mnemonic;
+use std::fmt;
+
+/// Decoder tags of FP register fields, by the integer name they patch as
+const FP_ALIASES: &[(&str, &str)] = &[("rd", "fd"), ("rs1", "fs1"), ("rs2", "fs2"), ("rs3", "fs3")];
+
+/// Helper: Get the byte length of the instruction starting with `word`
+fn insn_len(word: u32) -> usize {
+    if is_compressed(word) {
+        2
+    } else {
+        4
+    }
+}
+
+/// Why an instruction could not be patched
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PatchError {
+    /// No whole instruction lies at the address
+    OutOfBounds { addr: u64 },
+    /// The word does not decode as an instruction
+    Undecodable { raw: u32 },
+    /// The instruction has no PC-relative target to move
+    NoTarget { raw: u32 },
+    /// The value does not fit the field
+    Encode(EncodeError),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PatchError::OutOfBounds { addr } => write!(f, "no instruction at 0x{:x}", addr),
+            PatchError::Undecodable { raw } => write!(f, "{:08x} is not an instruction", raw),
+            PatchError::NoTarget { raw } => write!(f, "{:08x} has no PC-relative target", raw),
+            PatchError::Encode(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for PatchError {}
+
+impl From<EncodeError> for PatchError {
+    fn from(err: EncodeError) -> Self {
+        PatchError::Encode(err)
+    }
+}
+
+/// Rewrites single operands of encoded instructions in place
+#[derive(Debug, Clone)]
+pub struct Patcher {
+    disassembler: Disassembler,
+}
+
+impl Patcher {
+    pub fn new(xlen: Xlen) -> Self {
+        Self {
+            disassembler: Disassembler::new(xlen),
+        }
+    }
+
+    pub fn xlen(&self) -> Xlen {
+        self.disassembler.xlen()
+    }
+
+    /// Set one operand of `raw`, keeping every other bit. `field` is a
+    /// decoder tag such as `rd`, `rs1`, `imm` or `csr`, where `rd` and
+    /// `rs1`-`rs3` also name FP registers. `value` is in the units the
+    /// decoder reports, e.g. the byte offset of a branch. Operands sharing
+    /// the field's bits, like `rs1` of `c.add`, change with it
+    pub fn patch_word(&self, raw: u32, field: &str, value: i64) -> Result<u32, PatchError> {
+        let insn = self
+            .disassembler
+            .disassmeble_one(raw)
+            .filter(|insn| insn.mnemonic != Mnemonic::Unknown)
+            .ok_or(PatchError::Undecodable { raw })?;
+        let spec = specs(self.xlen())
+            .find(|spec| spec.mnemonic == insn.mnemonic && spec.compare(insn.raw))
+            .ok_or(EncodeError::UnknownMnemonic(insn.mnemonic))?;
+        let alias = FP_ALIASES
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, alias)| *alias);
+        let layout = spec
+            .args
+            .iter()
+            .filter_map(|arg| field_layout(spec, *arg))
+            .find(|layout| layout.tag == field || Some(layout.tag.as_str()) == alias)
+            .ok_or_else(|| EncodeError::UnexpectedOperand(field.to_string()))?;
+
+        let out_of_range = || EncodeError::OutOfRange {
+            operand: layout.tag.clone(),
+            value,
+        };
+        let bits = layout.bits.iter().fold(0, |bits, (bit, _)| bits | 1 << bit);
+        let word = (insn.raw & !bits) | place(&layout, value).ok_or_else(out_of_range)?;
+        if arg_value((layout.decode)(word).0) != Some(value) {
+            return Err(EncodeError::Invalid {
+                operand: layout.tag.clone(),
+                value,
+            }
+            .into());
+        }
+        match self.disassembler.disassmeble_one(word) {
+            Some(patched) if patched.mnemonic == spec.mnemonic => Ok(word),
+            Some(patched) => Err(EncodeError::Shadowed {
+                by: patched.mnemonic,
+            }
+            .into()),
+            None => Err(EncodeError::UnknownMnemonic(spec.mnemonic).into()),
+        }
+    }
+
+    /// Patch the instruction at `addr` of `code`, which is loaded at `base`,
+    /// and return the new word. `code` is left untouched on error
+    pub fn patch(
+        &self,
+        code: &mut [u8],
+        base: u64,
+        addr: u64,
+        field: &str,
+        value: i64,
+    ) -> Result<u32, PatchError> {
+        let (offset, raw) = self.read(code, base, addr)?;
+        let word = self.patch_word(raw, field, value)?;
+        let len = insn_len(word);
+        code[offset..offset + len].copy_from_slice(&word.to_le_bytes()[..len]);
+        Ok(word)
+    }
+
+    /// Point the branch or jump at `addr` to `target` by rewriting its offset
+    pub fn retarget(
+        &self,
+        code: &mut [u8],
+        base: u64,
+        addr: u64,
+        target: u64,
+    ) -> Result<u32, PatchError> {
+        let (_, raw) = self.read(code, base, addr)?;
+        let insn = self
+            .disassembler
+            .disassmeble_one(raw)
+            .ok_or(PatchError::Undecodable { raw })?;
+        // `jalr` targets depend on a register even when resolved
+        let is_pcrel = !matches!(
+            insn.mnemonic,
+            Mnemonic::Jalr | Mnemonic::CJr | Mnemonic::CJalr
+        );
+        match insn.control_flow(addr, self.xlen()).target {
+            Some(Target::Direct(_)) if is_pcrel => {}
+            _ => return Err(PatchError::NoTarget { raw: insn.raw }),
+        }
+        self.patch(code, base, addr, "imm", target.wrapping_sub(addr) as i64)
+    }
+
+    /// Helper: Read the instruction word at `addr`, with its offset into `code`
+    fn read(&self, code: &[u8], base: u64, addr: u64) -> Result<(usize, u32), PatchError> {
+        let out_of_bounds = PatchError::OutOfBounds { addr };
+        let offset = addr
+            .checked_sub(base)
+            .and_then(|offset| usize::try_from(offset).ok())
+            .ok_or(out_of_bounds.clone())?;
+        let half = |at: usize| {
+            code.get(at..at.checked_add(2)?)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as u32)
+        };
+        let low = half(offset).ok_or(out_of_bounds.clone())?;
+        if is_compressed(low) {
+            return Ok((offset, low));
+        }
+        let high = offset.checked_add(2).and_then(half).ok_or(out_of_bounds)?;
+        Ok((offset, low | high << 16))
+    }
+}
@@ -0,0 +1,379 @@
+use crate::args::Arg;
+use crate::insn::Insn;
+use crate::isa_meta::{Category, Extension};
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+pub const CSR_FFLAGS: u32 = 0x1;
+pub const CSR_FRM: u32 = 0x2;
+pub const CSR_VXSAT: u32 = 0x9;
+pub const CSR_VXRM: u32 = 0xa;
+pub const CSR_VL: u32 = 0xc20;
+pub const CSR_VTYPE: u32 = 0xc21;
+
+/// Dynamic rounding mode encoding of the `rm` field
+const RM_DYN: u32 = 0x7;
+
+/// An architectural register
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Reg {
+    Gpr(u32),
+    Fpr(u32),
+    Vr(u32),
+    Csr(u32),
+}
+
+impl fmt::Display for Reg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reg::Gpr(val) => write!(f, "x{}", val),
+            Reg::Fpr(val) => write!(f, "f{}", val),
+            Reg::Vr(val) => write!(f, "v{}", val),
+            Reg::Csr(val) => write!(f, "CSR#{}", val),
+        }
+    }
+}
+
+/// Helper: Check if a vector instruction consumes v0 as a carry or merge mask
+pub(crate) fn uses_v0_implicitly(name: &str) -> bool {
+    [".vvm", ".vxm", ".vim", ".vfm"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+}
+
+/// Helper: Check if a vector instruction ignores `vl` and `vtype`
+fn is_whole_register_vector(name: &str) -> bool {
+    (name.starts_with("vl") && name.contains("re") && !name.starts_with("vle"))
+        || (name.starts_with("vs") && name.ends_with("r.v"))
+        || (name.starts_with("vmv") && name.ends_with("r.v"))
+}
+
+/// Helper: Check if an FP instruction can raise floating-point exceptions
+fn sets_fflags(mnemonic: Mnemonic) -> bool {
+    let name = mnemonic.as_str();
+    mnemonic.category() == Category::Fp
+        && !name.starts_with("fmv.")
+        && !name.starts_with("fsgnj")
+        && !name.starts_with("fclass.")
+}
+
+/// Helper: Check if a vector instruction does floating-point arithmetic,
+/// returning whether it rounds by `frm`
+fn vector_fp(name: &str) -> Option<bool> {
+    let op = name.split('.').next().unwrap_or("");
+    if !(op.starts_with("vf") || op.starts_with("vmf")) || op == "vfirst" {
+        return None;
+    }
+    if matches!(op, "vfmv" | "vfmerge" | "vfslide1up" | "vfslide1down") || op.starts_with("vfsgnj")
+    {
+        return None;
+    }
+    let exact = op.starts_with("vmf")
+        || matches!(
+            op,
+            "vfmin" | "vfmax" | "vfredmin" | "vfredmax" | "vfclass" | "vfrsqrt7"
+        )
+        || name.contains(".rtz.")
+        || name.contains(".rod.")
+        || name.starts_with("vfwcvt.f.");
+    Some(!exact)
+}
+
+/// Helper: Check if a vector instruction does fixed-point arithmetic,
+/// returning whether it rounds by `vxrm` and whether it saturates
+fn vector_fixed_point(name: &str) -> Option<(bool, bool)> {
+    match name.split('.').next().unwrap_or("") {
+        "vsadd" | "vsaddu" | "vssub" | "vssubu" => Some((false, true)),
+        "vaadd" | "vaaddu" | "vasub" | "vasubu" | "vssrl" | "vssra" => Some((true, false)),
+        "vsmul" | "vnclip" | "vnclipu" => Some((true, true)),
+        _ => None,
+    }
+}
+
+/// Collect the registers an instruction reads and writes without encoding them
+pub fn implicit_operands(mnemonic: Mnemonic, flags: &HashMap<String, Arg>) -> (Vec<Reg>, Vec<Reg>) {
+    let mut src = Vec::new();
+    let mut dst = Vec::new();
+    let name = mnemonic.as_str();
+    match mnemonic {
+        Mnemonic::CLwsp
+        | Mnemonic::CLdsp
+        | Mnemonic::CFlwsp
+        | Mnemonic::CFldsp
+        | Mnemonic::CSwsp
+        | Mnemonic::CSdsp
+        | Mnemonic::CFswsp
+        | Mnemonic::CFsdsp
+        | Mnemonic::CAddi4spn => src.push(Reg::Gpr(2)),
+        Mnemonic::CAddi16sp => {
+            src.push(Reg::Gpr(2));
+            dst.push(Reg::Gpr(2));
+        }
+        Mnemonic::CJal | Mnemonic::CJalr => dst.push(Reg::Gpr(1)),
+        Mnemonic::Vsetvl | Mnemonic::Vsetvli | Mnemonic::Vsetivli => {
+            dst.push(Reg::Csr(CSR_VL));
+            dst.push(Reg::Csr(CSR_VTYPE));
+        }
+        _ => {}
+    }
+    if mnemonic.extension() == Extension::RvV
+        && !name.starts_with("vset")
+        && !is_whole_register_vector(name)
+    {
+        src.push(Reg::Csr(CSR_VL));
+        src.push(Reg::Csr(CSR_VTYPE));
+        let masked = matches!(flags.get("vm"), Some(Arg::Flag(0)));
+        if masked || uses_v0_implicitly(name) {
+            src.push(Reg::Vr(0));
+        }
+        if let Some(rounds) = vector_fp(name) {
+            if rounds {
+                src.push(Reg::Csr(CSR_FRM));
+            }
+            dst.push(Reg::Csr(CSR_FFLAGS));
+        }
+        if let Some((rounds, saturates)) = vector_fixed_point(name) {
+            if rounds {
+                src.push(Reg::Csr(CSR_VXRM));
+            }
+            if saturates {
+                dst.push(Reg::Csr(CSR_VXSAT));
+            }
+        }
+    }
+    if matches!(flags.get("rm"), Some(Arg::Flag(RM_DYN))) {
+        src.push(Reg::Csr(CSR_FRM));
+    }
+    if sets_fflags(mnemonic) {
+        dst.push(Reg::Csr(CSR_FFLAGS));
+    }
+    (src, dst)
+}
+
+/// The full sets of architectural registers an instruction reads and writes
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegSets {
+    pub read: BTreeSet<Reg>,
+    pub written: BTreeSet<Reg>,
+}
+
+/// Helper: Check if a conversion operand names an integer type
+fn is_int_type(ty: &str) -> bool {
+    matches!(ty, "w" | "wu" | "l" | "lu")
+}
+
+/// Helper: Check if a scalar FP instruction writes an integer register
+fn fp_writes_gpr(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('.').collect();
+    match parts[0] {
+        "fclass" | "feq" | "flt" | "fle" => true,
+        "fmv" => parts.get(1) == Some(&"x"),
+        "fcvt" => parts.get(1).is_some_and(|ty| is_int_type(ty)),
+        _ => false,
+    }
+}
+
+/// Helper: Check if a scalar FP instruction reads an integer register as rs1
+fn fp_reads_gpr(name: &str) -> bool {
+    let parts: Vec<&str> = name.split('.').collect();
+    match parts[0] {
+        "flw" | "fld" | "fsw" | "fsd" => true,
+        "fmv" => parts.get(2) == Some(&"x"),
+        "fcvt" => parts.get(2).is_some_and(|ty| is_int_type(ty)),
+        _ => false,
+    }
+}
+
+/// Helper: Check if a vector instruction takes its scalar operand from an FPR
+fn vector_scalar_is_fpr(name: &str) -> bool {
+    name.ends_with(".vf")
+        || name.ends_with(".wf")
+        || name.ends_with(".vfm")
+        || name.ends_with(".s.f")
+        || name.ends_with(".v.f")
+}
+
+/// Resolve an operand tag to the register it names, regardless of how
+/// the generated tables spelled the tag (`rd` vs `fd`, `rs1` vs `fs1`)
+pub fn operand_reg(mnemonic: Mnemonic, tag: &str, val: u32) -> Reg {
+    let name = mnemonic.as_str();
+    if tag.starts_with('v') {
+        return Reg::Vr(val);
+    }
+    let is_dst = tag == "rd" || tag == "fd";
+    let is_fpr = match mnemonic.extension() {
+        Extension::RvF | Extension::Rv64F | Extension::RvD | Extension::Rv64D => {
+            if is_dst {
+                !fp_writes_gpr(name)
+            } else if tag == "rs1" || tag == "fs1" {
+                !fp_reads_gpr(name)
+            } else {
+                true
+            }
+        }
+        Extension::RvCD | Extension::Rv32CF => matches!(tag, "rd" | "rs2"),
+        Extension::RvV => {
+            (is_dst && name == "vfmv.f.s") || (tag == "rs1" && vector_scalar_is_fpr(name))
+        }
+        _ => tag.starts_with('f'),
+    };
+    if is_fpr {
+        Reg::Fpr(val)
+    } else {
+        Reg::Gpr(val)
+    }
+}
+
+/// Helper: Check if a vector instruction accumulates into its destination
+fn reads_vd(name: &str) -> bool {
+    let op = name.split('.').next().unwrap_or("");
+    op.ends_with("macc")
+        || op.ends_with("msac")
+        || op.ends_with("madd")
+        || op.ends_with("msub")
+        || op.starts_with("vwmacc")
+        || op == "vslideup"
+}
+
+/// Helper: Get the number of registers each vector operand spans
+fn vector_group(name: &str, tag: &str, lmul: u32) -> u32 {
+    let op = name.split('.').next().unwrap_or("");
+    let is_reduction = op.starts_with("vred")
+        || op.starts_with("vfred")
+        || op.starts_with("vwred")
+        || op.starts_with("vfwred");
+    let is_mask_result = op.starts_with("vms")
+        || op.starts_with("vmf")
+        || op.starts_with("vmadc")
+        || op.starts_with("vmsbc")
+        || name.ends_with(".mm");
+    let is_widening = op.starts_with("vw") || op.starts_with("vfw");
+    let is_narrowing = matches!(op, "vnsrl" | "vnsra" | "vnclip" | "vnclipu") || op == "vfncvt";
+    let double = (lmul * 2).min(8);
+    // whole-register moves, loads and stores encode their own group size
+    if let Some(n) = name
+        .strip_prefix("vmv")
+        .or_else(|| name.strip_prefix("vl"))
+        .or_else(|| name.strip_prefix("vs"))
+        .and_then(|rest| rest.chars().next())
+        .and_then(|c| c.to_digit(10))
+    {
+        return n;
+    }
+    match tag {
+        "vd" => {
+            if is_reduction
+                || is_mask_result
+                || name.ends_with(".s.x")
+                || name.ends_with(".s.f")
+                || name == "vlm.v"
+            {
+                1
+            } else if is_widening {
+                double
+            } else {
+                lmul
+            }
+        }
+        "vs1" => {
+            if is_reduction || name.ends_with(".mm") || op == "vcompress" {
+                1
+            } else {
+                lmul
+            }
+        }
+        "vs2" => {
+            if name.ends_with(".m")
+                || name.ends_with(".mm")
+                || name.ends_with(".x.s")
+                || name.ends_with(".f.s")
+            {
+                1
+            } else if is_narrowing
+                || (is_widening
+                    && (name.ends_with(".wv") || name.ends_with(".wx") || name.ends_with(".wf")))
+            {
+                double
+            } else if let Some(factor) = name
+                .strip_prefix("vzext.vf")
+                .or_else(|| name.strip_prefix("vsext.vf"))
+            {
+                (lmul / factor.parse::<u32>().unwrap_or(1)).max(1)
+            } else {
+                lmul
+            }
+        }
+        "vs3" => {
+            if name == "vsm.v" {
+                1
+            } else {
+                lmul
+            }
+        }
+        _ => 1,
+    }
+}
+
+/// Helper: Add a register to a set, expanding vector register groups
+fn insert_reg(set: &mut BTreeSet<Reg>, reg: Reg, group: u32) {
+    match reg {
+        Reg::Vr(base) => {
+            for i in 0..group {
+                if base + i < 32 {
+                    set.insert(Reg::Vr(base + i));
+                }
+            }
+        }
+        _ => {
+            set.insert(reg);
+        }
+    }
+}
+
+/// Collect the registers an instruction reads and writes.
+/// `lmul` is the vector register group size in effect, if known
+pub fn reg_sets(insn: &Insn, lmul: Option<u32>) -> RegSets {
+    let lmul = lmul.unwrap_or(1).clamp(1, 8);
+    let name = insn.name.as_str();
+    let mut sets = RegSets::default();
+    for (tag, arg) in &insn.args.src {
+        let reg = operand_reg(insn.mnemonic, tag, arg.get_val());
+        insert_reg(&mut sets.read, reg, vector_group(name, tag, lmul));
+    }
+    for (tag, arg) in &insn.args.dst {
+        let reg = operand_reg(insn.mnemonic, tag, arg.get_val());
+        let group = vector_group(name, tag, lmul);
+        insert_reg(&mut sets.written, reg, group);
+        if tag == "vd" && reads_vd(name) {
+            insert_reg(&mut sets.read, reg, group);
+        }
+    }
+    sets.read.extend(insn.args.implicit_src.iter().copied());
+    sets.written.extend(insn.args.implicit_dst.iter().copied());
+    if let Some(csr) = insn.args.csr {
+        let csr = Reg::Csr(csr.get_val());
+        let rd = insn.args.dst.get("rd").map(|rd| rd.get_val());
+        // csrrw/csrrwi with rd=x0 do not read the CSR
+        let reads = !matches!(insn.mnemonic, Mnemonic::Csrrw | Mnemonic::Csrrwi) || rd != Some(0);
+        // csrrs/csrrc with rs1=x0 (or a zero immediate) do not write it
+        let writes = match insn.mnemonic {
+            Mnemonic::Csrrs | Mnemonic::Csrrc => {
+                insn.args.src.get("rs1").map(|rs1| rs1.get_val()) != Some(0)
+            }
+            Mnemonic::Csrrsi | Mnemonic::Csrrci => {
+                insn.args.imm.map(|imm| imm.get_val_signed_imm()) != Some(0)
+            }
+            _ => true,
+        };
+        if reads {
+            sets.read.insert(csr);
+        }
+        if writes {
+            sets.written.insert(csr);
+        }
+    }
+    sets.written.remove(&Reg::Gpr(0));
+    sets
+}
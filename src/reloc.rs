@@ -0,0 +1,328 @@
+use crate::disassembler::Xlen;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A RISC-V psABI relocation type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum RelocKind {
+    None,
+    Abs32,
+    Abs64,
+    Relative,
+    Copy,
+    JumpSlot,
+    TlsDtpmod32,
+    TlsDtpmod64,
+    TlsDtprel32,
+    TlsDtprel64,
+    TlsTprel32,
+    TlsTprel64,
+    Tlsdesc,
+    Branch,
+    Jal,
+    Call,
+    CallPlt,
+    GotHi20,
+    TlsGotHi20,
+    TlsGdHi20,
+    PcrelHi20,
+    PcrelLo12I,
+    PcrelLo12S,
+    Hi20,
+    Lo12I,
+    Lo12S,
+    TprelHi20,
+    TprelLo12I,
+    TprelLo12S,
+    TprelAdd,
+    Add8,
+    Add16,
+    Add32,
+    Add64,
+    Sub8,
+    Sub16,
+    Sub32,
+    Sub64,
+    Got32Pcrel,
+    Align,
+    RvcBranch,
+    RvcJump,
+    Relax,
+    Sub6,
+    Set6,
+    Set8,
+    Set16,
+    Set32,
+    Pcrel32,
+    Irelative,
+    Plt32,
+    SetUleb128,
+    SubUleb128,
+    TlsdescHi20,
+    TlsdescLoadLo12,
+    TlsdescAddLo12,
+    TlsdescCall,
+    /// A type this crate does not know by name
+    Other(u32),
+}
+
+/// `r_type` and name of every known relocation type
+const RELOC_TYPES: &[(u32, RelocKind, &str)] = &[
+    (0, RelocKind::None, "R_RISCV_NONE"),
+    (1, RelocKind::Abs32, "R_RISCV_32"),
+    (2, RelocKind::Abs64, "R_RISCV_64"),
+    (3, RelocKind::Relative, "R_RISCV_RELATIVE"),
+    (4, RelocKind::Copy, "R_RISCV_COPY"),
+    (5, RelocKind::JumpSlot, "R_RISCV_JUMP_SLOT"),
+    (6, RelocKind::TlsDtpmod32, "R_RISCV_TLS_DTPMOD32"),
+    (7, RelocKind::TlsDtpmod64, "R_RISCV_TLS_DTPMOD64"),
+    (8, RelocKind::TlsDtprel32, "R_RISCV_TLS_DTPREL32"),
+    (9, RelocKind::TlsDtprel64, "R_RISCV_TLS_DTPREL64"),
+    (10, RelocKind::TlsTprel32, "R_RISCV_TLS_TPREL32"),
+    (11, RelocKind::TlsTprel64, "R_RISCV_TLS_TPREL64"),
+    (12, RelocKind::Tlsdesc, "R_RISCV_TLSDESC"),
+    (16, RelocKind::Branch, "R_RISCV_BRANCH"),
+    (17, RelocKind::Jal, "R_RISCV_JAL"),
+    (18, RelocKind::Call, "R_RISCV_CALL"),
+    (19, RelocKind::CallPlt, "R_RISCV_CALL_PLT"),
+    (20, RelocKind::GotHi20, "R_RISCV_GOT_HI20"),
+    (21, RelocKind::TlsGotHi20, "R_RISCV_TLS_GOT_HI20"),
+    (22, RelocKind::TlsGdHi20, "R_RISCV_TLS_GD_HI20"),
+    (23, RelocKind::PcrelHi20, "R_RISCV_PCREL_HI20"),
+    (24, RelocKind::PcrelLo12I, "R_RISCV_PCREL_LO12_I"),
+    (25, RelocKind::PcrelLo12S, "R_RISCV_PCREL_LO12_S"),
+    (26, RelocKind::Hi20, "R_RISCV_HI20"),
+    (27, RelocKind::Lo12I, "R_RISCV_LO12_I"),
+    (28, RelocKind::Lo12S, "R_RISCV_LO12_S"),
+    (29, RelocKind::TprelHi20, "R_RISCV_TPREL_HI20"),
+    (30, RelocKind::TprelLo12I, "R_RISCV_TPREL_LO12_I"),
+    (31, RelocKind::TprelLo12S, "R_RISCV_TPREL_LO12_S"),
+    (32, RelocKind::TprelAdd, "R_RISCV_TPREL_ADD"),
+    (33, RelocKind::Add8, "R_RISCV_ADD8"),
+    (34, RelocKind::Add16, "R_RISCV_ADD16"),
+    (35, RelocKind::Add32, "R_RISCV_ADD32"),
+    (36, RelocKind::Add64, "R_RISCV_ADD64"),
+    (37, RelocKind::Sub8, "R_RISCV_SUB8"),
+    (38, RelocKind::Sub16, "R_RISCV_SUB16"),
+    (39, RelocKind::Sub32, "R_RISCV_SUB32"),
+    (40, RelocKind::Sub64, "R_RISCV_SUB64"),
+    (41, RelocKind::Got32Pcrel, "R_RISCV_GOT32_PCREL"),
+    (43, RelocKind::Align, "R_RISCV_ALIGN"),
+    (44, RelocKind::RvcBranch, "R_RISCV_RVC_BRANCH"),
+    (45, RelocKind::RvcJump, "R_RISCV_RVC_JUMP"),
+    (51, RelocKind::Relax, "R_RISCV_RELAX"),
+    (52, RelocKind::Sub6, "R_RISCV_SUB6"),
+    (53, RelocKind::Set6, "R_RISCV_SET6"),
+    (54, RelocKind::Set8, "R_RISCV_SET8"),
+    (55, RelocKind::Set16, "R_RISCV_SET16"),
+    (56, RelocKind::Set32, "R_RISCV_SET32"),
+    (57, RelocKind::Pcrel32, "R_RISCV_32_PCREL"),
+    (58, RelocKind::Irelative, "R_RISCV_IRELATIVE"),
+    (59, RelocKind::Plt32, "R_RISCV_PLT32"),
+    (60, RelocKind::SetUleb128, "R_RISCV_SET_ULEB128"),
+    (61, RelocKind::SubUleb128, "R_RISCV_SUB_ULEB128"),
+    (62, RelocKind::TlsdescHi20, "R_RISCV_TLSDESC_HI20"),
+    (63, RelocKind::TlsdescLoadLo12, "R_RISCV_TLSDESC_LOAD_LO12"),
+    (64, RelocKind::TlsdescAddLo12, "R_RISCV_TLSDESC_ADD_LO12"),
+    (65, RelocKind::TlsdescCall, "R_RISCV_TLSDESC_CALL"),
+];
+
+impl RelocKind {
+    /// Decode the type field of `r_info`
+    pub fn from_r_type(r_type: u32) -> Self {
+        RELOC_TYPES
+            .iter()
+            .find(|(val, _, _)| *val == r_type)
+            .map_or(RelocKind::Other(r_type), |(_, kind, _)| *kind)
+    }
+
+    pub fn r_type(&self) -> u32 {
+        match self {
+            RelocKind::Other(r_type) => *r_type,
+            kind => RELOC_TYPES.iter().find(|(_, k, _)| k == kind).unwrap().0,
+        }
+    }
+
+    /// Helper: Check if the relocation's symbol labels the instruction
+    /// holding the high part rather than the final target
+    pub fn is_pcrel_lo(&self) -> bool {
+        matches!(
+            self,
+            RelocKind::PcrelLo12I
+                | RelocKind::PcrelLo12S
+                | RelocKind::TlsdescLoadLo12
+                | RelocKind::TlsdescAddLo12
+                | RelocKind::TlsdescCall
+        )
+    }
+
+    /// Helper: Check if the relocation fills in the high part of a PC-relative pair
+    pub fn is_pcrel_hi(&self) -> bool {
+        matches!(
+            self,
+            RelocKind::PcrelHi20
+                | RelocKind::GotHi20
+                | RelocKind::TlsGotHi20
+                | RelocKind::TlsGdHi20
+                | RelocKind::TlsdescHi20
+        )
+    }
+}
+
+impl fmt::Display for RelocKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match RELOC_TYPES.iter().find(|(_, kind, _)| kind == self) {
+            Some((_, _, name)) => write!(f, "{}", name),
+            None => write!(f, "R_RISCV_{}", self.r_type()),
+        }
+    }
+}
+
+/// A symbol as a relocation refers to it
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RelocSymbol {
+    /// Empty for absolute relocations such as `R_RISCV_RELAX`
+    pub name: String,
+    pub value: u64,
+}
+
+/// One entry of a `.rela` section
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Relocation {
+    /// Address of the patched instruction or data
+    pub offset: u64,
+    pub kind: RelocKind,
+    pub symbol: RelocSymbol,
+    pub addend: i64,
+}
+
+impl Relocation {
+    /// Render the referenced symbol as objdump does, e.g. `sym+0x4` or `*ABS*`
+    pub fn target(&self) -> String {
+        let name = match self.symbol.name.as_str() {
+            "" => "*ABS*",
+            name => name,
+        };
+        match self.addend {
+            0 => name.to_string(),
+            addend if addend < 0 => format!("{}-0x{:x}", name, addend.unsigned_abs()),
+            addend => format!("{}+0x{:x}", name, addend),
+        }
+    }
+}
+
+impl fmt::Display for Relocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}\t{}", self.kind, self.target())
+    }
+}
+
+/// Parse the entries of a `.rela` section. `symbol` resolves an index into
+/// the linked symbol table; unresolved indices become absolute symbols
+pub fn parse_rela(
+    bytes: &[u8],
+    xlen: Xlen,
+    symbol: impl Fn(u32) -> Option<RelocSymbol>,
+) -> Vec<Relocation> {
+    let entry_size = match xlen {
+        Xlen::XLEN32 => 12,
+        Xlen::XLEN64 => 24,
+    };
+    bytes
+        .chunks_exact(entry_size)
+        .map(|entry| {
+            let (offset, sym, r_type, addend) = match xlen {
+                Xlen::XLEN32 => {
+                    let word = |i: usize| u32::from_le_bytes(entry[i..i + 4].try_into().unwrap());
+                    let info = word(4);
+                    (
+                        word(0) as u64,
+                        info >> 8,
+                        info & 0xff,
+                        word(8) as i32 as i64,
+                    )
+                }
+                Xlen::XLEN64 => {
+                    let dword = |i: usize| u64::from_le_bytes(entry[i..i + 8].try_into().unwrap());
+                    let info = dword(8);
+                    (dword(0), (info >> 32) as u32, info as u32, dword(16) as i64)
+                }
+            };
+            let symbol = match sym {
+                0 => RelocSymbol::default(),
+                sym => symbol(sym).unwrap_or_default(),
+            };
+            Relocation {
+                offset,
+                kind: RelocKind::from_r_type(r_type),
+                symbol,
+                addend,
+            }
+        })
+        .collect()
+}
+
+/// Relocations keyed by the address they patch
+#[derive(Debug, Clone, Default)]
+pub struct RelocationTable {
+    relocs: BTreeMap<u64, Vec<Relocation>>,
+}
+
+impl RelocationTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_relocations(relocs: Vec<Relocation>) -> Self {
+        let mut table = Self::new();
+        for reloc in relocs {
+            table.insert(reloc);
+        }
+        table
+    }
+
+    /// Add a relocation after the ones already at its offset
+    pub fn insert(&mut self, reloc: Relocation) {
+        self.relocs.entry(reloc.offset).or_default().push(reloc);
+    }
+
+    /// Get the relocations patching `addr`, in section order
+    pub fn at(&self, addr: u64) -> &[Relocation] {
+        self.relocs
+            .get(&addr)
+            .map_or(&[], |relocs| relocs.as_slice())
+    }
+
+    /// Find the `HI20` relocation a `PCREL_LO12` one refers to through the
+    /// label on its `auipc`
+    pub fn pcrel_hi(&self, lo: &Relocation) -> Option<&Relocation> {
+        if !lo.kind.is_pcrel_lo() {
+            return None;
+        }
+        let auipc = lo.symbol.value.wrapping_add(lo.addend as u64);
+        self.at(auipc).iter().find(|reloc| reloc.kind.is_pcrel_hi())
+    }
+
+    /// Render a relocation for a listing, following a `PCREL_LO12` to the
+    /// target of its partner, e.g. `R_RISCV_PCREL_LO12_I\t.L0 (sym+0x4)`
+    pub fn describe(&self, reloc: &Relocation) -> String {
+        match self.pcrel_hi(reloc) {
+            Some(hi) => format!("{} ({})", reloc, hi.target()),
+            None => reloc.to_string(),
+        }
+    }
+
+    /// Iterate over every relocation in address order
+    pub fn iter(&self) -> impl Iterator<Item = &Relocation> {
+        self.relocs.values().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.relocs.values().map(|relocs| relocs.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relocs.is_empty()
+    }
+}
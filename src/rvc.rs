@@ -0,0 +1,284 @@
+use crate::args::{Arg, Legality};
+use crate::disassembler::{is_compressed, Disassembler, Xlen};
+use crate::encoder::{spec_operands, specs, EncodeError, Encoder};
+use crate::insn::Insn;
+use crate::isa::Spec;
+use crate::isa_meta::Extension;
+use crate::mnemonic::Mnemonic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Where a register of the base instruction comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Rd,
+    Rs1,
+    Rs2,
+    Fixed(u32),
+}
+
+const RD: Option<Source> = Some(Source::Rd);
+const RS1: Option<Source> = Some(Source::Rs1);
+const RS2: Option<Source> = Some(Source::Rs2);
+const ZERO: Option<Source> = Some(Source::Fixed(0));
+const RA: Option<Source> = Some(Source::Fixed(1));
+const SP: Option<Source> = Some(Source::Fixed(2));
+
+/// Helper: Get the base instruction a compressed one stands for, with where
+/// its `rd`, `rs1` and `rs2` come from
+fn expansion(mnemonic: Mnemonic) -> Option<(Mnemonic, [Option<Source>; 3])> {
+    Some(match mnemonic {
+        Mnemonic::CAddi4spn => (Mnemonic::Addi, [RD, SP, None]),
+        Mnemonic::CNop => (Mnemonic::Addi, [ZERO, ZERO, None]),
+        Mnemonic::CAddi => (Mnemonic::Addi, [RD, RD, None]),
+        Mnemonic::CAddiw => (Mnemonic::Addiw, [RD, RD, None]),
+        Mnemonic::CLi => (Mnemonic::Addi, [RD, ZERO, None]),
+        Mnemonic::CAddi16sp => (Mnemonic::Addi, [SP, SP, None]),
+        Mnemonic::CLui => (Mnemonic::Lui, [RD, None, None]),
+        Mnemonic::CSlli => (Mnemonic::Slli, [RD, RD, None]),
+        Mnemonic::CSrli => (Mnemonic::Srli, [RD, RD, None]),
+        Mnemonic::CSrai => (Mnemonic::Srai, [RD, RD, None]),
+        Mnemonic::CAndi => (Mnemonic::Andi, [RD, RD, None]),
+        Mnemonic::CMv => (Mnemonic::Add, [RD, ZERO, RS2]),
+        Mnemonic::CAdd => (Mnemonic::Add, [RD, RD, RS2]),
+        Mnemonic::CSub => (Mnemonic::Sub, [RD, RD, RS2]),
+        Mnemonic::CXor => (Mnemonic::Xor, [RD, RD, RS2]),
+        Mnemonic::COr => (Mnemonic::Or, [RD, RD, RS2]),
+        Mnemonic::CAnd => (Mnemonic::And, [RD, RD, RS2]),
+        Mnemonic::CSubw => (Mnemonic::Subw, [RD, RD, RS2]),
+        Mnemonic::CAddw => (Mnemonic::Addw, [RD, RD, RS2]),
+        Mnemonic::CLw => (Mnemonic::Lw, [RD, RS1, None]),
+        Mnemonic::CLd => (Mnemonic::Ld, [RD, RS1, None]),
+        Mnemonic::CFlw => (Mnemonic::Flw, [RD, RS1, None]),
+        Mnemonic::CFld => (Mnemonic::Fld, [RD, RS1, None]),
+        Mnemonic::CSw => (Mnemonic::Sw, [None, RS1, RS2]),
+        Mnemonic::CSd => (Mnemonic::Sd, [None, RS1, RS2]),
+        Mnemonic::CFsw => (Mnemonic::Fsw, [None, RS1, RS2]),
+        Mnemonic::CFsd => (Mnemonic::Fsd, [None, RS1, RS2]),
+        Mnemonic::CLwsp => (Mnemonic::Lw, [RD, SP, None]),
+        Mnemonic::CLdsp => (Mnemonic::Ld, [RD, SP, None]),
+        Mnemonic::CFlwsp => (Mnemonic::Flw, [RD, SP, None]),
+        Mnemonic::CFldsp => (Mnemonic::Fld, [RD, SP, None]),
+        Mnemonic::CSwsp => (Mnemonic::Sw, [None, SP, RS2]),
+        Mnemonic::CSdsp => (Mnemonic::Sd, [None, SP, RS2]),
+        Mnemonic::CFswsp => (Mnemonic::Fsw, [None, SP, RS2]),
+        Mnemonic::CFsdsp => (Mnemonic::Fsd, [None, SP, RS2]),
+        Mnemonic::CJ => (Mnemonic::Jal, [ZERO, None, None]),
+        Mnemonic::CJal => (Mnemonic::Jal, [RA, None, None]),
+        Mnemonic::CJr => (Mnemonic::Jalr, [ZERO, RS1, None]),
+        Mnemonic::CJalr => (Mnemonic::Jalr, [RA, RS1, None]),
+        Mnemonic::CBeqz => (Mnemonic::Beq, [None, RS1, ZERO]),
+        Mnemonic::CBnez => (Mnemonic::Bne, [None, RS1, ZERO]),
+        Mnemonic::CEbreak => (Mnemonic::Ebreak, [None, None, None]),
+        Mnemonic::CLbu => (Mnemonic::Lbu, [RD, RS1, None]),
+        Mnemonic::CLhu => (Mnemonic::Lhu, [RD, RS1, None]),
+        Mnemonic::CLh => (Mnemonic::Lh, [RD, RS1, None]),
+        Mnemonic::CSb => (Mnemonic::Sb, [None, RS1, RS2]),
+        Mnemonic::CSh => (Mnemonic::Sh, [None, RS1, RS2]),
+        Mnemonic::CZextB => (Mnemonic::Andi, [RD, RD, None]),
+        Mnemonic::CNot => (Mnemonic::Xori, [RD, RD, None]),
+        Mnemonic::CMul => (Mnemonic::Mul, [RD, RD, RS2]),
+        _ => return None,
+    })
+}
+
+/// Helper: Get the immediate a compressed instruction implies without
+/// encoding it
+fn implied_imm(mnemonic: Mnemonic) -> Option<i32> {
+    match mnemonic {
+        Mnemonic::CZextB => Some(0xff),
+        Mnemonic::CNot => Some(-1),
+        _ => None,
+    }
+}
+
+/// Expand a compressed instruction into the 32-bit instruction it stands
+/// for, e.g. `c.lwsp` into `lw rd, imm(sp)`. The result keeps the original
+/// `len` of 2 so addresses still advance by the compressed size, while `raw`
+/// holds the 32-bit encoding. Returns `None` for non-compressed instructions,
+/// for reserved encodings without a 32-bit form, like RV32 `c.slli` by 32,
+/// and for `c.sext.*`/`c.zext.[hw]`, whose Zbb and Zba forms are not decoded
+pub fn expand(insn: &Insn, xlen: Xlen) -> Option<Insn> {
+    let (base, sources) = expansion(insn.mnemonic)?;
+    let spec = specs(xlen).find(|spec| spec.mnemonic == base)?;
+    let reg = |source: Option<Source>| match source? {
+        Source::Rd => insn.operand("rd"),
+        Source::Rs1 => insn.operand("rs1"),
+        Source::Rs2 => insn.operand("rs2"),
+        Source::Fixed(reg) => Some(reg),
+    };
+    let imm = implied_imm(insn.mnemonic)
+        .or(insn.args.imm.map(|imm| imm.get_val_signed_imm()))
+        .unwrap_or(0);
+    let mut operands = Vec::new();
+    for (tag, arg) in spec_operands(spec) {
+        let arg = match arg {
+            Arg::DstReg(_) => Arg::DstReg(reg(sources[0])?),
+            Arg::SrcReg(_) if tag == "rs1" => Arg::SrcReg(reg(sources[1])?),
+            Arg::SrcReg(_) => Arg::SrcReg(reg(sources[2])?),
+            _ => Arg::Imm(imm),
+        };
+        operands.push((tag, arg));
+    }
+    let operands: Vec<(&str, Arg)> = operands
+        .iter()
+        .map(|(tag, arg)| (tag.as_str(), *arg))
+        .collect();
+    let raw = Encoder::new(xlen).encode(base, &operands).ok()?;
+    let mut expanded = Disassembler::new(xlen).disassmeble_one(raw)?;
+    expanded.len = insn.len;
+    Some(expanded)
+}
+
+/// Why a 32-bit instruction has no compressed encoding, from the least to
+/// the most nearly compressible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Incompressible {
+    /// Already a 16-bit instruction
+    Compressed,
+    /// No compressed instruction stands for this one, e.g. `mulh`
+    NoEquivalent,
+    /// Only compressed instructions of disabled extensions stand for it
+    ExtensionDisabled,
+    /// The operands don't have the shape of a compressed form, e.g. `rd`
+    /// differs from `rs1` or the base register is not `sp`
+    Operands,
+    /// A register outside `x8`-`x15`, or one the compressed form reserves
+    Register,
+    /// The immediate is out of range, misaligned or a reserved zero
+    Immediate,
+}
+
+impl fmt::Display for Incompressible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Incompressible::Compressed => "already compressed",
+            Incompressible::NoEquivalent => "no compressed form",
+            Incompressible::ExtensionDisabled => "compressed form not enabled",
+            Incompressible::Operands => "operands don't fit a compressed form",
+            Incompressible::Register => "register outside x8-x15",
+            Incompressible::Immediate => "immediate out of range",
+        };
+        write!(f, "{}", reason)
+    }
+}
+
+/// Compress a 32-bit instruction into the 16-bit instruction that expands
+/// back to exactly the same word, e.g. `addi a0, a0, 1` into `c.addi a0, 1`.
+/// Only compressed instructions of the enabled `extensions` (Zca, Zcf, Zcd
+/// and Zcb, with `None` enabling all of them) are candidates. Returns `None`
+/// when no compressed form fits, e.g. a register outside `x8`-`x15` or an
+/// immediate out of range
+pub fn compress(insn: &Insn, xlen: Xlen, extensions: Option<&BTreeSet<Extension>>) -> Option<u16> {
+    try_compress(insn, xlen, extensions).ok()
+}
+
+/// Like `compress`, but tells why no compressed form fits. With several
+/// candidate forms, the reason of the one that came closest is kept
+pub fn try_compress(
+    insn: &Insn,
+    xlen: Xlen,
+    extensions: Option<&BTreeSet<Extension>>,
+) -> Result<u16, Incompressible> {
+    if is_compressed(insn.raw) {
+        return Err(Incompressible::Compressed);
+    }
+    let mut disassembler = Disassembler::new(xlen);
+    disassembler.set_extensions(extensions.cloned());
+    let encoder = Encoder::new(xlen);
+    // FP loads and stores tag their FPR operands `fd` and `fs2`
+    let regs = [
+        insn.operand("rd").or(insn.operand("fd")),
+        insn.operand("rs1"),
+        insn.operand("rs2").or(insn.operand("fs2")),
+    ];
+    let imm = insn.args.imm.map(|imm| imm.get_val_signed_imm());
+
+    let compress_as = |spec: &Spec, sources: [Option<Source>; 3]| {
+        if extensions.is_some_and(|enabled| !enabled.contains(&spec.extension)) {
+            return Err(Incompressible::ExtensionDisabled);
+        }
+        // registers the compressed form fixes or shares between operands
+        let fits = sources.iter().zip(regs).all(|(source, reg)| match source {
+            Some(Source::Fixed(fixed)) => reg == Some(*fixed),
+            Some(source) => sources
+                .iter()
+                .zip(regs)
+                .all(|(other, other_reg)| other != &Some(*source) || other_reg == reg),
+            None => true,
+        });
+        let implied = implied_imm(spec.mnemonic);
+        if !fits || implied.is_some_and(|implied| imm != Some(implied)) {
+            return Err(Incompressible::Operands);
+        }
+
+        let mut operands = Vec::new();
+        for (tag, arg) in spec_operands(spec) {
+            let value = match arg {
+                Arg::DstReg(_) | Arg::SrcReg(_) => {
+                    let source = match tag.as_str() {
+                        "rd" => Source::Rd,
+                        "rs1" => Source::Rs1,
+                        _ => Source::Rs2,
+                    };
+                    let slot = sources.iter().position(|s| *s == Some(source));
+                    slot.and_then(|slot| regs[slot]).map(|reg| match arg {
+                        Arg::DstReg(_) => Arg::DstReg(reg),
+                        _ => Arg::SrcReg(reg),
+                    })
+                }
+                _ => imm.map(Arg::Imm),
+            };
+            if let Some(value) = value {
+                operands.push((tag, value));
+            }
+        }
+        let operands: Vec<(&str, Arg)> = operands
+            .iter()
+            .map(|(tag, arg)| (tag.as_str(), *arg))
+            .collect();
+        let raw = encoder
+            .encode(spec.mnemonic, &operands)
+            .map_err(|err| match err {
+                EncodeError::OutOfRange { operand, .. } | EncodeError::Invalid { operand, .. }
+                    if operand == "imm" =>
+                {
+                    Incompressible::Immediate
+                }
+                EncodeError::OutOfRange { .. }
+                | EncodeError::Invalid { .. }
+                // e.g. `c.lui sp` is `c.addi16sp`
+                | EncodeError::Shadowed { .. } => Incompressible::Register,
+                _ => Incompressible::Operands,
+            })?;
+        let compressed = disassembler
+            .disassmeble_one(raw)
+            .ok_or(Incompressible::ExtensionDisabled)?;
+        // e.g. `c.addi a0, 0` or `c.addi4spn a0, sp, 0`, which assemblers never emit
+        if compressed.legality != Legality::Legal {
+            return Err(match imm {
+                Some(0) => Incompressible::Immediate,
+                _ => Incompressible::Register,
+            });
+        }
+        match expand(&compressed, xlen) {
+            Some(expanded) if compressed.mnemonic == spec.mnemonic && expanded.raw == insn.raw => {
+                Ok(raw as u16)
+            }
+            _ => Err(Incompressible::Operands),
+        }
+    };
+
+    let mut reason = Incompressible::NoEquivalent;
+    for spec in specs(xlen).filter(|spec| is_compressed(spec.match_bits)) {
+        match expansion(spec.mnemonic) {
+            Some((base, sources)) if base == insn.mnemonic => match compress_as(spec, sources) {
+                Ok(raw) => return Ok(raw),
+                Err(err) => reason = reason.max(err),
+            },
+            _ => {}
+        }
+    }
+    Err(reason)
+}
@@ -0,0 +1,174 @@
+use crate::cfg::Cfg;
+use crate::const_prop::ConstProp;
+use crate::control_flow::{FlowKind, Target};
+use crate::jump_table::JumpTable;
+use crate::mem_access::MemAccessKind;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::ops::RangeBounds;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum XrefKind {
+    /// Conditional branch target
+    Branch,
+    /// Unconditional jump or tail call target, including jump table entries
+    Jump,
+    Call,
+    /// Loaded from
+    Read,
+    /// Stored to
+    Write,
+    /// Atomic read-modify-write
+    ReadWrite,
+    /// Address built in a register from a `lui` or `auipc` without being
+    /// accessed, e.g. `la`
+    Address,
+}
+
+impl XrefKind {
+    /// Helper: Check if the reference transfers control
+    pub fn is_code(&self) -> bool {
+        matches!(self, XrefKind::Branch | XrefKind::Jump | XrefKind::Call)
+    }
+}
+
+/// A reference from the instruction at `from` to the address `to`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Xref {
+    pub from: u64,
+    pub to: u64,
+    pub kind: XrefKind,
+}
+
+/// Cross references indexed both by target and by referring instruction
+#[derive(Debug, Clone, Default)]
+pub struct XrefDb {
+    to: BTreeMap<u64, Vec<Xref>>,
+    from: BTreeMap<u64, Vec<Xref>>,
+}
+
+impl XrefDb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Index the control transfers of `cfg` and the addresses `consts`
+    /// resolved for its instructions
+    pub fn build(cfg: &Cfg, consts: &ConstProp) -> Self {
+        let mut db = Self::new();
+        for block in cfg.blocks() {
+            for (addr, insn) in &block.insns {
+                let flow = insn.control_flow(*addr, cfg.xlen);
+                let resolved = consts.get(*addr);
+                let target = match flow.target {
+                    Some(Target::Direct(target)) => Some(target),
+                    // e.g. auipc + jalr
+                    Some(Target::Indirect) => resolved.and_then(|consts| consts.address),
+                    None => None,
+                };
+                let code_kind = match flow.kind {
+                    FlowKind::Branch => Some(XrefKind::Branch),
+                    FlowKind::Jump | FlowKind::TailCall => Some(XrefKind::Jump),
+                    FlowKind::Call | FlowKind::CoroutineSwap => Some(XrefKind::Call),
+                    _ => None,
+                };
+                if let (Some(kind), Some(to)) = (code_kind, target) {
+                    db.insert(Xref {
+                        from: *addr,
+                        to,
+                        kind,
+                    });
+                    continue;
+                }
+                let Some(resolved) = resolved else {
+                    continue;
+                };
+                // an address built without being accessed only counts when
+                // rooted in a lui/auipc, which the annotation guarantees
+                let (data_kind, to) = match insn.mem_access().map(|access| access.kind) {
+                    Some(MemAccessKind::Load) => (XrefKind::Read, resolved.address),
+                    Some(MemAccessKind::Store) => (XrefKind::Write, resolved.address),
+                    Some(MemAccessKind::ReadModifyWrite) => (XrefKind::ReadWrite, resolved.address),
+                    None => (XrefKind::Address, resolved.annotation),
+                };
+                if let Some(to) = to {
+                    db.insert(Xref {
+                        from: *addr,
+                        to,
+                        kind: data_kind,
+                    });
+                }
+            }
+        }
+        db
+    }
+
+    /// Add the entries of recovered jump tables as jumps from their jump
+    pub fn add_jump_tables(&mut self, tables: &[JumpTable]) {
+        for table in tables {
+            for target in &table.targets {
+                self.insert(Xref {
+                    from: table.jump,
+                    to: *target,
+                    kind: XrefKind::Jump,
+                });
+            }
+        }
+    }
+
+    /// Add a reference, ignoring duplicates
+    pub fn insert(&mut self, xref: Xref) {
+        let to = self.to.entry(xref.to).or_default();
+        if to.contains(&xref) {
+            return;
+        }
+        to.push(xref);
+        self.from.entry(xref.from).or_default().push(xref);
+    }
+
+    /// Get the references to `addr`
+    pub fn refs_to(&self, addr: u64) -> &[Xref] {
+        self.to.get(&addr).map_or(&[], |refs| refs.as_slice())
+    }
+
+    /// Get the references made by the instruction at `addr`
+    pub fn refs_from(&self, addr: u64) -> &[Xref] {
+        self.from.get(&addr).map_or(&[], |refs| refs.as_slice())
+    }
+
+    /// Get the references to any address in `range`, e.g. a block of MMIO registers
+    pub fn refs_to_range(&self, range: impl RangeBounds<u64>) -> impl Iterator<Item = &Xref> {
+        self.to.range(range).flat_map(|(_, refs)| refs.iter())
+    }
+
+    /// Get the addresses of the instructions that call `addr`
+    pub fn callers(&self, addr: u64) -> Vec<u64> {
+        self.refs_to(addr)
+            .iter()
+            .filter(|xref| xref.kind == XrefKind::Call)
+            .map(|xref| xref.from)
+            .collect()
+    }
+
+    /// Get the references to `addr` that read, write or take its address
+    pub fn data_refs_to(&self, addr: u64) -> Vec<Xref> {
+        self.refs_to(addr)
+            .iter()
+            .filter(|xref| !xref.kind.is_code())
+            .copied()
+            .collect()
+    }
+
+    /// Iterate over every reference, ordered by target
+    pub fn iter(&self) -> impl Iterator<Item = &Xref> {
+        self.to.values().flatten()
+    }
+
+    pub fn len(&self) -> usize {
+        self.to.values().map(|refs| refs.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.to.is_empty()
+    }
+}
@@ -0,0 +1,185 @@
+// tests for the text assembler
+use rvdasm::assembler::*;
+use rvdasm::disassembler::*;
+use rvdasm::mnemonic::Mnemonic;
+use std::fs::File;
+use std::io::Read;
+
+#[test]
+fn test_gnu_syntax() {
+    // encodings from llvm-mc
+    let cases = [
+        ("addi a0, a1, -4", 0xffc58513),
+        ("lw a0, 8(sp)", 0x00812503),
+        ("sw a1, -12(s0)", 0xfeb42a23),
+        ("fsd fs1, 0(sp)", 0x00913027),
+        ("lui a0, 0x12345", 0x12345537),
+        ("jalr ra, 4(t1)", 0x004300e7),
+        ("jal 16", 0x010000ef),
+        ("beq a0, a1, -16", 0xfeb508e3),
+        ("c.addi16sp sp, -64", 0x7139),
+        ("c.lwsp a0, 12(sp)", 0x4532),
+        ("c.sd s0, 8(a1)", 0xe580),
+        ("c.add a0, a1", 0x952e),
+        ("c.lui a0, 0xfffff", 0x757d),
+        ("lr.d.aq a0, (a1)", 0x1405b52f),
+        ("amoadd.w.aqrl a0, a1, (a2)", 0x06b6252f),
+        ("csrrw a0, mstatus, a1", 0x30059573),
+        ("fence iorw, o", 0x0f40000f),
+        ("fence.tso", 0x8330000f),
+        ("fadd.d fa0, fa1, fa2, rtz", 0x02c59553),
+        ("fcvt.d.w fs1, t1", 0xd20304d3),
+        ("vsetvli t0, a0, e32, m1, ta, ma", 0x0d0572d7),
+        ("vsetivli a0, 8, e64, mf2, tu, mu", 0xc1f47557),
+        ("vluxei32.v v2, (a0), v4, v0.t", 0x04456107),
+        ("vadd.vx v1, v2, a0, v0.t", 0x002540d7),
+        ("vmacc.vx v1, a0, v3", 0xb63560d7),
+        ("vmerge.vim v1, v2, 5, v0", 0x5c22b0d7),
+        ("vsll.vi v22, v27, 25", 0x97bcbb57),
+    ];
+    let assembler = Assembler::new(Xlen::XLEN64);
+    for (text, raw) in cases {
+        assert_eq!(assembler.assemble_one(text), Ok(raw), "{}", text);
+    }
+}
+
+#[test]
+fn test_display_syntax_round_trip() {
+    let mut bin = Vec::new();
+    File::open("tests/data/test.bin")
+        .unwrap()
+        .read_to_end(&mut bin)
+        .unwrap();
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let assembler = Assembler::with_syntax(Xlen::XLEN64, Syntax::Display);
+    let insns = disassembler.disassemble_all(&bin, 0x80000000);
+    let words = insns
+        .values()
+        .map(|insn| insn.raw)
+        .chain((0..=0xffffu32).filter(|raw| is_compressed(*raw)));
+    for raw in words {
+        let Some(insn) = disassembler.disassmeble_one(raw) else {
+            continue;
+        };
+        if insn.mnemonic == Mnemonic::Unknown {
+            continue;
+        }
+        // flags are not printed, so compare the text rather than the word
+        let text = insn.to_string();
+        let word = assembler.assemble_one(&text).unwrap();
+        let back = disassembler.disassmeble_one(word).unwrap();
+        assert_eq!(back.to_string(), text);
+    }
+}
+
+#[test]
+fn test_pseudo_instructions() {
+    let assembler = Assembler::new(Xlen::XLEN64);
+    let words = |text: &str| -> Vec<u32> {
+        let program = assembler.assemble(text, 0).unwrap();
+        program.insns.iter().map(|(_, raw)| *raw).collect()
+    };
+    assert_eq!(words("nop"), [0x00000013]);
+    assert_eq!(words("li a0, 5"), [0x00500513]);
+    assert_eq!(words("li a0, 0x12345678"), [0x12345537, 0x6785051b]);
+    assert_eq!(words("li a0, 0xffffffff"), [0xfff00513, 0x02055513]);
+    assert_eq!(
+        words("li a0, 0x123456789"),
+        [0x00092537, 0xa2b5051b, 0x00d51513, 0x78950513]
+    );
+    assert_eq!(words("mv a0, a1"), [0x00058513]);
+    assert_eq!(words("ble a0, a1, -8"), [0xfea5dce3]);
+    assert_eq!(words("ret"), [0x00008067]);
+    assert_eq!(words("csrr a0, mstatus"), [0x30002573]);
+    assert_eq!(words("rdtime a1"), [0xc01025f3]);
+    assert_eq!(words("fneg.s fa0, fa1"), [0x20b59553]);
+    // RV32 builds constants with `addi`
+    let rv32 = Assembler::new(Xlen::XLEN32).assemble("li a0, 0xfffff800", 0);
+    assert_eq!(rv32.unwrap().insns, [(0, 0x80000513)]);
+}
+
+#[test]
+fn test_labels() {
+    let text = "
+        .globl _start
+        .equ COUNT, 10
+    _start:
+        li t0, COUNT        # loop count
+        la a0, table
+    1:  addi t0, t0, -1
+        bnez t0, 1b
+        beqz a0, 1f
+        call helper
+    1:  auipc a1, %pcrel_hi(table)
+        ld a2, %pcrel_lo(1b)(a1)
+        lui a3, %hi(table); addi a3, a3, %lo(table)
+        c.addi a0, 1
+        .p2align 3
+        j _start
+    helper:
+        ret
+    table:
+        .dword helper
+    ";
+    let program = Assembler::new(Xlen::XLEN64).assemble(text, 0x1000).unwrap();
+    assert_eq!(program.labels["_start"], 0x1000);
+    assert_eq!(program.labels["helper"], 0x103c);
+    assert_eq!(program.labels["COUNT"], 10);
+    let expected = [
+        (0x1000, 0x00a00293),
+        (0x1004, 0x00000517),
+        (0x1008, 0x03c50513),
+        (0x100c, 0xfff28293),
+        (0x1010, 0xfe029ee3),
+        (0x1014, 0x00050663),
+        (0x1018, 0x00000097),
+        (0x101c, 0x024080e7),
+        (0x1020, 0x00000597),
+        (0x1024, 0x0205b603),
+        (0x1028, 0x000016b7),
+        (0x102c, 0x04068693),
+        (0x1030, 0x0505),
+        // alignment padding
+        (0x1032, 0x0001),
+        (0x1034, 0x00000013),
+        (0x1038, 0xfc9ff06f),
+        (0x103c, 0x00008067),
+    ];
+    assert_eq!(program.insns, expected);
+    assert_eq!(program.bytes.len(), 0x48);
+    assert_eq!(program.bytes[0x40..], 0x103cu64.to_le_bytes());
+}
+
+#[test]
+fn test_errors() {
+    let mut assembler = Assembler::new(Xlen::XLEN64);
+    let message = |text: &str| assembler.assemble(text, 0).unwrap_err().to_string();
+    assert_eq!(message("nop\nfoo a0"), "line 2: unknown instruction `foo`");
+    assert_eq!(message("j missing"), "line 1: undefined symbol `missing`");
+    assert_eq!(message("x:\nx:"), "line 2: `x` is defined twice");
+    assert_eq!(
+        message("add a0, fa0, a1"),
+        "line 1: add: f10 is not a valid rs1"
+    );
+    assert_eq!(
+        message("addi a0, a0, 4096"),
+        "line 1: addi: imm = 4096 is out of range"
+    );
+    assert!(message("c.lw a0, 4(sp)").contains("c.lw"));
+    assert!(assembler.assemble_one("li a0, 0x12345678").is_err());
+    // out-of-range values are errors, not overflows
+    assert_eq!(
+        message("la a0, 9223372036854775807"),
+        "line 1: `9223372036854775807` is out of range of auipc"
+    );
+    assert_eq!(
+        message("lui a0, %hi(0x7fffffffffffffff)"),
+        "line 1: 9223372036854775807 does not fit in 32 bits"
+    );
+    assert!(message("addi a0, a0, %lo(0x100000000)").contains("32 bits"));
+
+    assembler.define_symbol("printf", 0x2000);
+    let program = assembler.assemble("call printf", 0x1000).unwrap();
+    assert_eq!(program.insns, [(0x1000, 0x00001097), (0x1004, 0x000080e7)]);
+    assert!(program.labels.is_empty());
+}
@@ -0,0 +1,106 @@
+// tests for reading the canonical form back
+use rvdasm::canonical::*;
+use rvdasm::disassembler::*;
+use rvdasm::encoder::EncodeError;
+use rvdasm::mnemonic::Mnemonic;
+use std::fs::File;
+use std::io::Read;
+
+#[test]
+fn test_canonical_round_trip() {
+    let mut bin = Vec::new();
+    File::open("tests/data/test.bin")
+        .unwrap()
+        .read_to_end(&mut bin)
+        .unwrap();
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        let disassembler = Disassembler::new(xlen);
+        let parser = CanonicalParser::new(xlen);
+        let insns = disassembler.disassemble_all(&bin, 0x80000000);
+        let words = insns
+            .values()
+            .map(|insn| insn.raw)
+            .chain((0..=0xffffu32).filter(|raw| is_compressed(*raw)));
+        for raw in words {
+            let Some(insn) = disassembler.disassmeble_one(raw) else {
+                continue;
+            };
+            if insn.mnemonic == Mnemonic::Unknown {
+                continue;
+            }
+            let parsed = parser.parse(&insn.to_canonical_with_flags()).unwrap();
+            assert_eq!(parsed.raw, raw, "{}", insn.to_canonical_with_flags());
+            assert_eq!(parsed.len, insn.len);
+        }
+    }
+}
+
+#[test]
+fn test_parse_canonical() {
+    let parser = CanonicalParser::new(Xlen::XLEN64);
+    let insn = parser.parse("addi RD x5 RS1 x0 IMM -3").unwrap();
+    assert_eq!(insn.raw, 0xffd00293);
+    assert_eq!(insn.to_canonical(), "addi RD x5 RS1 x0 IMM -3");
+    let csr = parser.parse("csrrs RD x10 RS1 x0 CSR 768").unwrap();
+    assert_eq!(csr.raw, 0x30002573);
+    // flags print after the operands, when asked for
+    let fadd = parser.parse("fadd.s FD f1 FS1 f2 FS2 f3 RM 1").unwrap();
+    assert_eq!(fadd.raw, 0x003110d3);
+    assert_eq!(
+        fadd.to_canonical_with_flags(),
+        "fadd.s FD f1 FS1 f2 FS2 f3 RM 1"
+    );
+    assert_eq!(fadd.to_canonical(), "fadd.s FD f1 FS1 f2 FS2 f3");
+    // traces written without flags get the dynamic rounding mode
+    assert_eq!(
+        parser.parse("fadd.s FD f1 FS1 f2 FS2 f3").unwrap().raw,
+        0x003170d3
+    );
+    let vadd = parser
+        .parse("vadd.vv VD vd1 VS1 vs13 VS2 vs22 VM 1")
+        .unwrap();
+    assert_eq!(vadd.raw, 0x022180d7);
+    let c_addi = parser.parse("c.addi RD x10 RS1 x10 IMM 3").unwrap();
+    assert_eq!((c_addi.raw, c_addi.len), (0x050d, 2));
+}
+
+#[test]
+fn test_parse_trace() {
+    let parser = CanonicalParser::new(Xlen::XLEN64);
+    let trace = "START INST lui RD x10 IMM 4096 TIMESTAMP 0 END\n\n\
+                 START INST jalr RD x0 RS1 x1 IMM 0 TIMESTAMP 17 END\n";
+    let records = parser.parse_trace(trace).unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].insn.raw, 0x00001537);
+    assert_eq!(records[1].insn.raw, 0x00008067);
+    assert_eq!(records[1].timestamp, 17);
+    assert_eq!(
+        records[1].to_string(),
+        "START INST jalr RD x0 RS1 x1 IMM 0 TIMESTAMP 17 END"
+    );
+}
+
+#[test]
+fn test_parse_errors() {
+    let parser = CanonicalParser::new(Xlen::XLEN64);
+    assert_eq!(
+        parser.parse("frob RD x1").unwrap_err(),
+        CanonicalError::UnknownMnemonic("frob".to_string())
+    );
+    assert!(matches!(
+        parser.parse("addi RD x5 RS1"),
+        Err(CanonicalError::Malformed(_))
+    ));
+    assert!(matches!(
+        parser.parse("addi RD f5 RS1 x0 IMM 0"),
+        Err(CanonicalError::Malformed(_))
+    ));
+    assert_eq!(
+        parser.parse("addi RD x5 RS1 x0 IMM 0 CSR 1").unwrap_err(),
+        CanonicalError::Encode(EncodeError::UnexpectedOperand("csr".to_string()))
+    );
+    assert!(matches!(
+        parser.parse_record("START INST addi RD x5 RS1 x0 IMM 0 END"),
+        Err(CanonicalError::Malformed(_))
+    ));
+}
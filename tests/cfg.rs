@@ -0,0 +1,59 @@
+// tests for the control-flow graph
+use rvdasm::cfg::*;
+use rvdasm::disassembler::*;
+
+mod common;
+use common::assemble;
+
+#[test]
+fn test_cfg_loop() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x00000513, // 0x00: li a0, 0
+        0x00a00593, // 0x04: li a1, 10
+        0x00150513, // 0x08: addi a0, a0, 1
+        0xfeb54ee3, // 0x0c: blt a0, a1, -4
+        0x008000ef, // 0x10: jal ra, 8
+        0x00008067, // 0x14: ret
+        0x00150513, // 0x18: addi a0, a0, 1
+        0x00008067, // 0x1c: ret
+    ]);
+    let insns = disassembler.disassemble_all(&code, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let starts: Vec<u64> = cfg.blocks().map(|block| block.start).collect();
+    assert_eq!(starts, vec![0x1000, 0x1008, 0x1010, 0x1014, 0x1018]);
+
+    let successors = cfg.successors(0x1008);
+    assert!(successors.contains(&Edge {
+        from: 0x1008,
+        to: 0x1008,
+        kind: EdgeKind::Taken
+    }));
+    assert!(successors.contains(&Edge {
+        from: 0x1008,
+        to: 0x1010,
+        kind: EdgeKind::FallThrough
+    }));
+    assert!(cfg.successors(0x1010).contains(&Edge {
+        from: 0x1010,
+        to: 0x1018,
+        kind: EdgeKind::Call
+    }));
+    assert!(cfg.successors(0x1018).contains(&Edge {
+        from: 0x1018,
+        to: 0x1014,
+        kind: EdgeKind::Return
+    }));
+    assert_eq!(cfg.block_containing(0x100c).unwrap().start, 0x1008);
+
+    let dominators = cfg.dominators(0x1000);
+    assert_eq!(dominators.idom(0x1008), Some(0x1000));
+    assert_eq!(dominators.idom(0x1014), Some(0x1010));
+    assert!(dominators.dominates(0x1008, 0x1014));
+    assert!(!dominators.dominates(0x1014, 0x1008));
+
+    let loops = cfg.loops(0x1000);
+    assert_eq!(loops.len(), 1);
+    assert_eq!(loops[0].header, 0x1008);
+    assert_eq!(loops[0].latches, vec![0x1008]);
+}
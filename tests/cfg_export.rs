@@ -0,0 +1,46 @@
+// tests for the control-flow graph exporters
+use rvdasm::cfg::*;
+use rvdasm::cfg_export::*;
+use rvdasm::disassembler::*;
+
+mod common;
+use common::assemble;
+
+fn build_cfg() -> Cfg {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x00000513, // li a0, 0
+        0x00150513, // addi a0, a0, 1
+        0xfe051ee3, // bnez a0, -4
+        0x00008067, // ret
+    ]);
+    let insns = disassembler.disassemble_all(&code, 0x100);
+    Cfg::build(&insns, Xlen::XLEN64)
+}
+
+#[test]
+fn test_dot_export() {
+    let cfg = build_cfg();
+    let dot = to_dot(&cfg, 0x100, &ExportOptions::default());
+    assert!(dot.starts_with("digraph \"0x100\" {"));
+    assert!(dot.contains("addi x10, x10, 1"));
+    assert!(dot.contains("b104 -> b104 [color=red, style=bold];"));
+    assert!(dot.contains("b104 -> b10c [color=blue];"));
+    let plain = ExportOptions {
+        color_edges: false,
+        highlight_back_edges: false,
+    };
+    assert!(to_dot(&cfg, 0x100, &plain).contains("b104 -> b104;"));
+}
+
+#[test]
+fn test_json_export() {
+    let cfg = build_cfg();
+    let graph = to_json_graph(&cfg, 0x100);
+    assert_eq!(graph.blocks.len(), 3);
+    let back_edges: Vec<&JsonEdge> = graph.edges.iter().filter(|edge| edge.back_edge).collect();
+    assert_eq!(back_edges.len(), 1);
+    assert_eq!((back_edges[0].from, back_edges[0].to), (0x104, 0x104));
+    let json: serde_json::Value = serde_json::from_str(&to_json(&cfg, 0x100)).unwrap();
+    assert_eq!(json["edges"][0]["kind"], "FallThrough");
+}
@@ -0,0 +1,31 @@
+// helpers shared by the integration tests
+use rvdasm::isa::*;
+
+/// Lay out instruction words as little-endian code bytes
+#[allow(dead_code)]
+pub fn assemble(words: &[u32]) -> Vec<u8> {
+    words.iter().flat_map(|word| word.to_le_bytes()).collect()
+}
+
+/// Every spec table, named after the XLEN it belongs to, if any
+#[allow(dead_code)]
+pub fn spec_tables() -> Vec<(Option<u32>, &'static [Spec])> {
+    let mut tables: Vec<(Option<u32>, &'static [Spec])> = vec![
+        (None, &RV_ISA_SPECS_GENERIC_COMPRESSED),
+        (Some(32), &RV_ISA_SPECS_32_COMPRESSED),
+        (Some(64), &RV_ISA_SPECS_64_COMPRESSED),
+    ];
+    for opcode in 0..0x80u8 {
+        let by_xlen = [
+            (None, get_generic_full_specs_by_opcode(opcode)),
+            (Some(32), get_32_full_specs_by_opcode(opcode)),
+            (Some(64), get_64_full_specs_by_opcode(opcode)),
+        ];
+        for (xlen, specs) in by_xlen {
+            if let Some(specs) = specs {
+                tables.push((xlen, specs));
+            }
+        }
+    }
+    tables
+}
@@ -0,0 +1,100 @@
+// tests for register constant propagation
+use rvdasm::cfg::*;
+use rvdasm::const_prop::*;
+use rvdasm::disassembler::*;
+
+const CODE: &[u8] = &[
+    0x37, 0x15, 0x00, 0x80, // 0x00: lui a0, 0x80001
+    0x13, 0x05, 0x45, 0x23, // 0x04: addi a0, a0, 0x234
+    0x97, 0x15, 0x00, 0x00, // 0x08: auipc a1, 1
+    0x90, 0x69, // 0x0c: c.ld a2, 16(a1)
+    0x23, 0xbc, 0xc5, 0xfe, // 0x0e: sd a2, -8(a1)
+    0xc1, 0x66, // 0x12: c.lui a3, 0x10
+    0x91, 0x06, // 0x14: c.addi a3, 4
+    0x97, 0x00, 0x00, 0x00, // 0x16: auipc ra, 0
+    0xe7, 0x80, 0x00, 0x04, // 0x1a: jalr ra, 64(ra)
+    0x37, 0x07, 0x00, 0x10, // 0x1e: lui a4, 0x10000
+    0x91, 0xc3, // 0x22: c.beqz a5, 0x26
+    0x48, 0xc3, // 0x24: c.sw a0, 4(a4)
+    0x08, 0xc7, // 0x26: c.sw a0, 8(a4)
+];
+
+#[test]
+fn test_address_materialisation() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insns = disassembler.disassemble_all(CODE, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let consts = ConstProp::within_blocks(&cfg);
+    // lui sign-extends on RV64
+    assert_eq!(consts.comment(0x1004).unwrap(), "# 0xffffffff80001234");
+    assert_eq!(consts.get(0x1008).unwrap().result, Some(0x2008));
+    assert_eq!(consts.get(0x100c).unwrap().address, Some(0x2018));
+    assert_eq!(consts.get(0x100e).unwrap().address, Some(0x2000));
+    assert_eq!(consts.get(0x1014).unwrap().annotation, Some(0x10004));
+    assert_eq!(consts.get(0x101a).unwrap().address, Some(0x1056));
+    // li and lui on their own are not annotated
+    assert!(consts.comment(0x1000).is_none());
+    assert!(consts.comment(0x101e).is_none());
+    // the call clobbers a0, but not a4
+    assert_eq!(consts.get(0x1022).unwrap().before.get(&10), None);
+    // within blocks, nothing flows into the block after the branch
+    assert_eq!(consts.get(0x1026).unwrap().address, None);
+}
+
+#[test]
+fn test_address_materialisation_rv32() {
+    let disassembler = Disassembler::new(Xlen::XLEN32);
+    let insns = disassembler.disassemble_all(CODE, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN32);
+    let consts = ConstProp::within_blocks(&cfg);
+    assert_eq!(consts.comment(0x1004).unwrap(), "# 0x80001234");
+}
+
+#[test]
+fn test_within_function() {
+    let disassembler = Disassembler::new(Xlen::XLEN32);
+    let insns = disassembler.disassemble_all(CODE, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN32);
+    let consts = ConstProp::within_function(&cfg, 0x1000);
+    assert_eq!(consts.get(0x1024).unwrap().address, Some(0x10000004));
+    assert_eq!(consts.get(0x1026).unwrap().address, Some(0x10000008));
+    let refs = consts.referenced_addresses();
+    assert!(refs.contains(&(0x1014, 0x10004)));
+    assert!(refs.contains(&(0x1026, 0x10000008)));
+}
+
+#[test]
+fn test_small_constants_not_annotated() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = [
+        0x0d, 0x45, // 0x00: c.li a0, 3
+        0xaa, 0x85, // 0x02: c.mv a1, a0
+        0x13, 0x06, 0x45, 0x00, // 0x04: addi a2, a0, 4
+    ];
+    let insns = disassembler.disassemble_all(&code, 0);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let consts = ConstProp::within_blocks(&cfg);
+    assert_eq!(consts.get(0x2).unwrap().result, Some(3));
+    assert_eq!(consts.get(0x4).unwrap().result, Some(7));
+    assert!(consts.comment(0x2).is_none());
+    assert!(consts.comment(0x4).is_none());
+    assert!(consts.referenced_addresses().is_empty());
+}
+
+#[test]
+fn test_loop_to_entry() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = [
+        0x13, 0x85, 0x07, 0x00, // 0x00: mv a0, a5
+        0x93, 0x07, 0x80, 0x00, // 0x04: li a5, 8
+        0xe3, 0x9c, 0x05, 0xfe, // 0x08: bnez a1, 0x0
+        0x67, 0x80, 0x00, 0x00, // 0x0c: ret
+    ];
+    let insns = disassembler.disassemble_all(&code, 0);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let consts = ConstProp::within_function(&cfg, 0);
+    // the first pass through the entry knows nothing, whatever the loop says
+    assert!(consts.get(0x0).unwrap().before.is_empty());
+    assert_eq!(consts.get(0x4).unwrap().before.get(&10), None);
+    assert_eq!(consts.get(0x8).unwrap().before.get(&15), Some(&8));
+}
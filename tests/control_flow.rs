@@ -0,0 +1,63 @@
+// tests for the control-flow descriptors
+use rvdasm::control_flow::*;
+use rvdasm::disassembler::*;
+
+#[test]
+fn test_direct_targets() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // bne a0, a1, -8
+    let insn = disassembler.disassmeble_one(0xfeb51ce3).unwrap();
+    let flow = insn.control_flow(0x80000010, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Branch);
+    assert_eq!(flow.fall_through, 0x80000014);
+    assert_eq!(flow.target, Some(Target::Direct(0x80000008)));
+    // jal ra, 16
+    let insn = disassembler.disassmeble_one(0x010000ef).unwrap();
+    let flow = insn.control_flow(0x1000, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Call);
+    assert_eq!(flow.target, Some(Target::Direct(0x1010)));
+    // c.j -4 wraps around zero on RV32
+    let insn = disassembler.disassmeble_one(0xbff5).unwrap();
+    let flow = insn.control_flow(0, Xlen::XLEN32);
+    assert_eq!(flow.kind, FlowKind::Jump);
+    assert_eq!(flow.fall_through, 2);
+    assert_eq!(flow.target, Some(Target::Direct(0xffff_fffc)));
+}
+
+#[test]
+fn test_indirect_hints() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // ret (jalr x0, 0(ra))
+    let flow = disassembler
+        .disassmeble_one(0x00008067)
+        .unwrap()
+        .control_flow(0, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Return);
+    assert_eq!(flow.target, Some(Target::Indirect));
+    assert!(!flow.falls_through());
+    // jalr ra, 0(a5)
+    let flow = disassembler
+        .disassmeble_one(0x000780e7)
+        .unwrap()
+        .control_flow(0, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Call);
+    // jr t1
+    let flow = disassembler
+        .disassmeble_one(0x00030067)
+        .unwrap()
+        .control_flow(0, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::TailCall);
+    // c.jr a5
+    let flow = disassembler
+        .disassmeble_one(0x8782)
+        .unwrap()
+        .control_flow(0, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Jump);
+    // c.jalr a5
+    let flow = disassembler
+        .disassmeble_one(0x9782)
+        .unwrap()
+        .control_flow(0, Xlen::XLEN64);
+    assert_eq!(flow.kind, FlowKind::Call);
+    assert_eq!(flow.fall_through, 2);
+}
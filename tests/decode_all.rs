@@ -20,7 +20,7 @@ fn test_decode_all(to_canonical: bool) {
                 "0x{:08x}: {:08x}     {}",
                 key,
                 insns[&key].get_raw(),
-                insns[&key].to_string()
+                insns[&key]
             );
         }
     }
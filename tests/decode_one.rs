@@ -8,3 +8,90 @@ fn test_decode_one() {
     let insn = disassembler.disassmeble_one(code);
     println!("{:?}", insn);
 }
+
+#[test]
+fn test_decode_compressed_regs() {
+    // the 3-bit register fields of the compressed formats name x8-x15
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insn = disassembler.disassmeble_one(0x4dd8).unwrap();
+    assert_eq!(insn.to_string(), "c.lw x14, x11, 28");
+    assert_eq!(insn.operand("rd"), Some(14));
+    assert_eq!(insn.operand("rs1"), Some(11));
+    let insn = disassembler.disassmeble_one(0xc000).unwrap();
+    assert_eq!(insn.to_string(), "c.sw x8, x8, 0");
+    assert_eq!(insn.operand("rs1"), Some(8));
+    assert_eq!(insn.operand("rs2"), Some(8));
+}
+
+#[test]
+fn test_decode_compressed_rd_rs1() {
+    // CA and CB formats share rd' and rs1' in bits 7-9
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insn = disassembler.disassmeble_one(0x8d05).unwrap();
+    assert_eq!(insn.to_string(), "c.sub x10, x10, x9");
+    assert_eq!(insn.operand("rd"), Some(10));
+    assert_eq!(insn.operand("rs2"), Some(9));
+    let insn = disassembler.disassmeble_one(0x899d).unwrap();
+    assert_eq!(insn.to_string(), "c.andi x11, x11, 7");
+    let insn = disassembler.disassmeble_one(0x820d).unwrap();
+    assert_eq!(insn.to_string(), "c.srli x12, x12, 3");
+}
+
+#[test]
+fn test_decode_compressed_imms() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let imm = |raw| {
+        let insn = disassembler.disassmeble_one(raw).unwrap();
+        (
+            insn.to_string(),
+            insn.args.imm.unwrap().get_val_signed_imm(),
+        )
+    };
+    // c.addi and c.addi16sp immediates are signed
+    assert_eq!(imm(0x157d), ("c.addi x10, x10, -1".to_string(), -1));
+    assert_eq!(imm(0x7139), ("c.addi16sp -64".to_string(), -64));
+    assert_eq!(imm(0x617d), ("c.addi16sp 496".to_string(), 496));
+    // compressed shift amounts are 6 bits wide
+    assert_eq!(imm(0x057e), ("c.slli x10, x10, 31".to_string(), 31));
+    assert_eq!(imm(0x8245), ("c.srli x12, x12, 17".to_string(), 17));
+}
+
+#[test]
+fn test_decode_vsetivli() {
+    // zimm5 (the AVL) and zimm10 (vtype) used to share the "imm" tag
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // vsetivli a0, 8, e32, m1, ta, ma
+    let insn = disassembler.disassmeble_one(0xcd047557).unwrap();
+    assert_eq!(insn.to_string(), "vsetivli x10, 8");
+    assert_eq!(insn.args.imm.unwrap().get_val_signed_imm(), 8);
+    assert_eq!(insn.args.flags["vtypei"].get_val(), 0xd0);
+}
+
+#[test]
+fn test_decode_zcb_extends() {
+    let rv32 = Disassembler::new(Xlen::XLEN32);
+    let rv64 = Disassembler::new(Xlen::XLEN64);
+    let name = |disassembler: &Disassembler, raw| {
+        disassembler
+            .disassmeble_one(raw)
+            .map(|insn| insn.to_string())
+    };
+    assert_eq!(name(&rv32, 0x9d65).unwrap(), "c.sext.b x10, x10");
+    assert_eq!(name(&rv32, 0x9d69).unwrap(), "c.zext.h x10, x10");
+    assert_eq!(name(&rv32, 0x9d6d).unwrap(), "c.sext.h x10, x10");
+    // c.zext.w is RV64 only
+    assert_eq!(name(&rv64, 0x9d71).unwrap(), "c.zext.w x10, x10");
+    assert_eq!(name(&rv32, 0x9d71), None);
+}
+
+#[test]
+fn test_decode_rv32_shifts() {
+    let disassembler = Disassembler::new(Xlen::XLEN32);
+    let name = |raw| disassembler.disassmeble_one(raw).unwrap().to_string();
+    assert_eq!(name(0x00351513), "slli x10, x10, 3");
+    assert_eq!(name(0x00355513), "srli x10, x10, 3");
+    assert_eq!(name(0x41f55513), "srai x10, x10, 31");
+    assert_eq!(name(0x050e), "c.slli x10, x10, 3");
+    assert_eq!(name(0x810d), "c.srli x10, x10, 3");
+    assert_eq!(name(0x850d), "c.srai x10, x10, 3");
+}
@@ -0,0 +1,129 @@
+// tests for the code density report
+use rvdasm::assembler::Assembler;
+use rvdasm::density::*;
+use rvdasm::disassembler::*;
+use rvdasm::functions::*;
+use rvdasm::isa_meta::Extension;
+use rvdasm::rvc::Incompressible;
+use std::collections::BTreeSet;
+
+const PROGRAM: &str = "
+main:
+    addi sp, sp, -16
+    sd ra, 8(sp)
+    c.li a0, 1
+    jal ra, helper
+    ld ra, 8(sp)
+    addi sp, sp, 16
+    ret
+helper:
+    lw a0, 12(t0)
+    addi a0, a0, 100
+    mulh a0, a0, a1
+    c.jr ra
+";
+
+fn build(isa: Option<&str>) -> DensityReport {
+    let program = Assembler::new(Xlen::XLEN64)
+        .assemble(PROGRAM, 0x1000)
+        .unwrap();
+    let insns = Disassembler::new(Xlen::XLEN64).disassemble_all(&program.bytes, 0x1000);
+    let mut symbols = SymbolTable::new();
+    for (name, start, end) in [("main", 0x1000, 0x101a), ("helper", 0x101a, 0x1028)] {
+        symbols.insert(Function {
+            name: name.to_string(),
+            start,
+            end,
+            reasons: BTreeSet::new(),
+        });
+    }
+    let extensions = isa.map(|isa| {
+        Disassembler::from_isa_string(isa)
+            .unwrap()
+            .extensions()
+            .unwrap()
+            .clone()
+    });
+    DensityReport::build(&insns, Xlen::XLEN64, &symbols, extensions.as_ref())
+}
+
+#[test]
+fn test_density_counts() {
+    let report = build(None);
+    assert_eq!(report.total.compressed, 2);
+    assert_eq!(report.total.full, 9);
+    assert_eq!(report.total.compressible, 5);
+    assert_eq!(report.total.bytes(), 0x28);
+    assert_eq!(report.total.savings(), 10);
+    assert!((report.total.compressed_ratio() - 2.0 / 11.0).abs() < 1e-9);
+
+    let main = &report.functions[&0x1000];
+    assert_eq!(main.name, "main");
+    assert_eq!(
+        (
+            main.counts.compressed,
+            main.counts.full,
+            main.counts.compressible
+        ),
+        (1, 6, 5)
+    );
+    let helper = &report.functions[&0x101a].counts;
+    assert_eq!(
+        (helper.compressed, helper.full, helper.compressible),
+        (1, 3, 0)
+    );
+
+    assert_eq!(report.extensions[&Extension::RvC].compressed, 2);
+    assert_eq!(report.extensions[&Extension::RvM].full, 1);
+    assert_eq!(report.extensions[&Extension::RvI].full, 6);
+    assert_eq!(report.extensions[&Extension::Rv64I].full, 2);
+}
+
+#[test]
+fn test_density_reasons() {
+    let report = build(None);
+    let compressible: Vec<(u64, u16)> = report
+        .compressible()
+        .map(|candidate| (candidate.addr, candidate.compressed.unwrap()))
+        .collect();
+    // encodings from llvm-mc
+    assert_eq!(
+        compressible,
+        [
+            (0x1000, 0x1141),
+            (0x1004, 0xe406),
+            (0x100e, 0x60a2),
+            (0x1012, 0x0141),
+            (0x1016, 0x8082)
+        ]
+    );
+    let reason = |addr: u64| {
+        report
+            .candidates
+            .iter()
+            .find(|candidate| candidate.addr == addr)
+            .map(|candidate| candidate.compressed.unwrap_err())
+    };
+    // `jal ra` only compresses on RV32
+    assert_eq!(reason(0x100a), Some(Incompressible::Operands));
+    assert_eq!(reason(0x101a), Some(Incompressible::Register));
+    assert_eq!(reason(0x101e), Some(Incompressible::Immediate));
+    // `mulh` has no compressed form, so it is only counted
+    assert_eq!(reason(0x1022), None);
+    assert_eq!(report.reasons[&Incompressible::NoEquivalent], 1);
+
+    let text = report.to_string();
+    assert!(text.contains("0x0000101a helper: 1 x 16-bit, 3 x 32-bit"));
+    assert!(text.contains("register outside x8-x15: 1"));
+}
+
+#[test]
+fn test_density_extensions() {
+    let report = build(Some("rv64gc"));
+    assert_eq!(report.total.compressible, 5);
+    // without C nothing compresses, though the decoded code is unchanged
+    let report = build(Some("rv64g"));
+    assert_eq!(report.total.compressible, 0);
+    assert_eq!(report.total.compressed, 2);
+    assert_eq!(report.reasons[&Incompressible::ExtensionDisabled], 8);
+}
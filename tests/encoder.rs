@@ -0,0 +1,172 @@
+// tests for the encoder
+use rvdasm::args::{Arg, Legality};
+use rvdasm::disassembler::*;
+use rvdasm::encoder::*;
+use rvdasm::isa::*;
+use rvdasm::mnemonic::Mnemonic;
+use std::fs::File;
+use std::io::Read;
+
+/// Helper: Check `encode(decode(raw)) == raw` for every word that decodes
+fn check_round_trip(xlen: Xlen, words: impl Iterator<Item = u32>) {
+    let disassembler = Disassembler::new(xlen);
+    let encoder = Encoder::new(xlen);
+    for raw in words {
+        // reserved bits such as RV32 shamt[5] have no operand to encode
+        let decoded = disassembler.disassmeble_one(raw);
+        if let Some(insn) = decoded.filter(|insn| insn.legality < Legality::Reserved) {
+            assert_eq!(
+                encoder.encode_insn(&insn),
+                Ok(raw),
+                "{:08x} {} at {:?}",
+                raw,
+                insn,
+                xlen
+            );
+        }
+    }
+}
+
+#[test]
+fn test_round_trip_compressed() {
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        check_round_trip(xlen, (0..=0xffffu32).filter(|raw| is_compressed(*raw)));
+    }
+}
+
+#[test]
+fn test_round_trip_full_specs() {
+    // every spec with pseudo-random operand bits
+    let mut seed = 0x1234_5678u32;
+    let mut next = move || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed
+    };
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        let mut words = Vec::new();
+        for opcode in 0..0x80u8 {
+            let xlen_specs = match xlen {
+                Xlen::XLEN32 => get_32_full_specs_by_opcode(opcode),
+                Xlen::XLEN64 => get_64_full_specs_by_opcode(opcode),
+            };
+            let tables = [get_generic_full_specs_by_opcode(opcode), xlen_specs];
+            for spec in tables.into_iter().flatten().flat_map(|specs| specs.iter()) {
+                words.push(spec.match_bits);
+                words.push(spec.match_bits | !spec.mask_bits);
+                for _ in 0..64 {
+                    words.push(spec.match_bits | (next() & !spec.mask_bits));
+                }
+            }
+        }
+        check_round_trip(xlen, words.into_iter());
+    }
+}
+
+#[test]
+fn test_round_trip_binary() {
+    let mut bin = Vec::new();
+    File::open("tests/data/test.bin")
+        .unwrap()
+        .read_to_end(&mut bin)
+        .unwrap();
+    let insns = Disassembler::new(Xlen::XLEN64).disassemble_all(&bin, 0x80000000);
+    let encoder = Encoder::new(Xlen::XLEN64);
+    for insn in insns.values() {
+        if insn.mnemonic != Mnemonic::Unknown {
+            assert_eq!(encoder.encode_insn(insn), Ok(insn.raw), "{}", insn);
+        }
+    }
+}
+
+#[test]
+fn test_encode_operands() {
+    let encoder = Encoder::new(Xlen::XLEN64);
+    let addi = [
+        ("rd", Arg::DstReg(10)),
+        ("rs1", Arg::SrcReg(11)),
+        ("imm", Arg::Imm(-4)),
+    ];
+    assert_eq!(encoder.encode(Mnemonic::Addi, &addi), Ok(0xffc58513));
+    // jal ra, 0x800 scrambles the offset
+    let jal = [("rd", Arg::DstReg(1)), ("imm", Arg::Imm(0x800))];
+    assert_eq!(encoder.encode(Mnemonic::Jal, &jal), Ok(0x001000ef));
+    // c.addi16sp sp, -64
+    let addi16sp = [("imm", Arg::Imm(-64))];
+    assert_eq!(encoder.encode(Mnemonic::CAddi16sp, &addi16sp), Ok(0x7139));
+    // c.add shares rd and rs1, so rs1 may be left out
+    let add = [("rd", Arg::DstReg(10)), ("rs2", Arg::SrcReg(11))];
+    assert_eq!(encoder.encode(Mnemonic::CAdd, &add), Ok(0x952e));
+}
+
+#[test]
+fn test_encode_errors() {
+    let encoder = Encoder::new(Xlen::XLEN64);
+    let addi = |imm| {
+        [
+            ("rd", Arg::DstReg(10)),
+            ("rs1", Arg::SrcReg(11)),
+            ("imm", Arg::Imm(imm)),
+        ]
+    };
+    assert_eq!(
+        encoder.encode(Mnemonic::Addi, &addi(2048)),
+        Err(EncodeError::OutOfRange {
+            operand: "imm".to_string(),
+            value: 2048
+        })
+    );
+    assert!(encoder.encode(Mnemonic::Addi, &addi(-2048)).is_ok());
+    // branch offsets are even
+    let beq = [
+        ("rs1", Arg::SrcReg(1)),
+        ("rs2", Arg::SrcReg(2)),
+        ("imm", Arg::Imm(3)),
+    ];
+    assert!(matches!(
+        encoder.encode(Mnemonic::Beq, &beq),
+        Err(EncodeError::OutOfRange { .. })
+    ));
+    assert_eq!(
+        encoder.encode(Mnemonic::Addi, &addi(0)[..2]),
+        Err(EncodeError::MissingOperand("imm".to_string()))
+    );
+    let extra = [("rd", Arg::DstReg(1)), ("csr", Arg::CSR(0x300))];
+    assert_eq!(
+        encoder.encode(Mnemonic::Lui, &extra),
+        Err(EncodeError::UnexpectedOperand("csr".to_string()))
+    );
+    // c.addi reserves x0, and c.add needs rd == rs1
+    let c_addi = [
+        ("rd", Arg::DstReg(0)),
+        ("rs1", Arg::SrcReg(0)),
+        ("imm", Arg::Imm(1)),
+    ];
+    assert!(matches!(
+        encoder.encode(Mnemonic::CAddi, &c_addi),
+        Err(EncodeError::Invalid { .. })
+    ));
+    let c_add = [
+        ("rd", Arg::DstReg(10)),
+        ("rs1", Arg::SrcReg(11)),
+        ("rs2", Arg::SrcReg(12)),
+    ];
+    assert!(matches!(
+        encoder.encode(Mnemonic::CAdd, &c_add),
+        Err(EncodeError::Invalid { .. })
+    ));
+    // c.ld does not exist on RV32
+    let c_ld = [
+        ("rd", Arg::DstReg(8)),
+        ("rs1", Arg::SrcReg(9)),
+        ("imm", Arg::UImm(8)),
+    ];
+    assert!(Encoder::new(Xlen::XLEN64)
+        .encode(Mnemonic::CLd, &c_ld)
+        .is_ok());
+    assert_eq!(
+        Encoder::new(Xlen::XLEN32).encode(Mnemonic::CLd, &c_ld),
+        Err(EncodeError::UnknownMnemonic(Mnemonic::CLd))
+    );
+}
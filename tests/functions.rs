@@ -0,0 +1,90 @@
+// tests for function boundary detection
+use rvdasm::disassembler::*;
+use rvdasm::functions::*;
+
+mod common;
+use common::assemble;
+
+#[test]
+fn test_discover_functions() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0xff010113, // 0x00: addi sp, sp, -16
+        0x00113423, // 0x04: sd ra, 8(sp)
+        0x018000ef, // 0x08: jal ra, 0x20
+        0x00000097, // 0x0c: auipc ra, 0
+        0x01c080e7, // 0x10: jalr ra, 28(ra)
+        0x00813083, // 0x14: ld ra, 8(sp)
+        0x01010113, // 0x18: addi sp, sp, 16
+        0x00008067, // 0x1c: ret
+        0x00150513, // 0x20: addi a0, a0, 1
+        0x00008067, // 0x24: ret
+        0x00250513, // 0x28: addi a0, a0, 2
+        0x00008067, // 0x2c: ret
+        0xfe010113, // 0x30: addi sp, sp, -32
+        0x00113c23, // 0x34: sd ra, 24(sp)
+        0x01813083, // 0x38: ld ra, 24(sp)
+        0x02010113, // 0x3c: addi sp, sp, 32
+        0x00008067, // 0x40: ret
+    ]);
+    let insns = disassembler.disassemble_all(&code, 0x1000);
+    let table = SymbolTable::discover(&insns, Xlen::XLEN64, &[0x1000]);
+    let bounds: Vec<(u64, u64)> = table.functions().map(|f| (f.start, f.end)).collect();
+    assert_eq!(
+        bounds,
+        vec![
+            (0x1000, 0x1020),
+            (0x1020, 0x1028),
+            (0x1028, 0x1030),
+            (0x1030, 0x1044)
+        ]
+    );
+    let main = table.function(0x1000).unwrap();
+    assert!(main.reasons.contains(&StartReason::Root));
+    assert!(main.reasons.contains(&StartReason::Prologue));
+    assert!(table
+        .function(0x1028)
+        .unwrap()
+        .reasons
+        .contains(&StartReason::CallTarget));
+    let leaf = table.function(0x1030).unwrap();
+    assert!(leaf.reasons.contains(&StartReason::Prologue));
+    assert!(leaf.reasons.contains(&StartReason::AfterReturn));
+    assert_eq!(table.symbolize(0x1024).unwrap(), "sub_1020+0x4");
+    assert!(table.function_containing(0x1044).is_none());
+}
+
+#[test]
+fn test_discover_compressed_prologue() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = [
+        0x41, 0x11, // 0x00: c.addi sp, -16
+        0x06, 0xe4, // 0x02: c.sdsp ra, 8(sp)
+        0xa2, 0x60, // 0x04: c.ldsp ra, 8(sp)
+        0x41, 0x01, // 0x06: c.addi sp, 16
+        0x82, 0x80, // 0x08: c.jr ra
+    ];
+    let insns = disassembler.disassemble_all(&code, 0);
+    let table = SymbolTable::discover(&insns, Xlen::XLEN64, &[]);
+    let function = table.function(0).unwrap();
+    assert_eq!(function.end, 0xa);
+    assert!(function.reasons.contains(&StartReason::Prologue));
+}
+
+#[test]
+fn test_discover_cm_push() {
+    // cm.push overlaps c.fsdsp, which Zcmp code does not enable
+    let disassembler = Disassembler::from_isa_string("rv32imac").unwrap();
+    let code = [
+        0x42, 0xb8, // 0x00: cm.push {ra}, -16
+        0x05, 0x05, // 0x02: c.addi a0, 1
+        0x82, 0x80, // 0x04: c.jr ra
+    ];
+    let insns = disassembler.disassemble_all(&code, 0);
+    let table = SymbolTable::discover(&insns, Xlen::XLEN32, &[]);
+    assert!(table
+        .function(0)
+        .unwrap()
+        .reasons
+        .contains(&StartReason::Push));
+}
@@ -0,0 +1,115 @@
+// tests for the random instruction generator
+use rvdasm::disassembler::*;
+use rvdasm::generator::*;
+use rvdasm::isa_meta::Category;
+use rvdasm::mnemonic::Mnemonic;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_generator_seed() {
+    let words = |seed| -> Vec<u32> {
+        Generator::new(Xlen::XLEN64, seed)
+            .generate(100)
+            .iter()
+            .map(|insn| insn.raw)
+            .collect()
+    };
+    assert_eq!(words(1), words(1));
+    assert_ne!(words(1), words(2));
+    assert_eq!(words(1).len(), 100);
+}
+
+#[test]
+fn test_generator_legal() {
+    let isa = Disassembler::from_isa_string("rv32imafc").unwrap();
+    let extensions = isa.extensions().unwrap().clone();
+    let mut generator = Generator::new(Xlen::XLEN32, 7);
+    generator.set_extensions(Some(extensions.clone()));
+    let insns = generator.generate(5000);
+    let mut mnemonics = BTreeSet::new();
+    for insn in &insns {
+        // every word decodes back to itself under the chosen ISA
+        let decoded = isa.disassmeble_one(insn.raw).unwrap();
+        assert_eq!(decoded.mnemonic, insn.mnemonic);
+        assert!(extensions.contains(&insn.extension()), "{}", insn);
+        mnemonics.insert(insn.mnemonic);
+
+        // non-zero immediates and registers of the compressed forms
+        let imm = insn.args.imm.map(|imm| imm.get_val_signed_imm());
+        match insn.mnemonic {
+            Mnemonic::CAddi4spn | Mnemonic::CAddi16sp | Mnemonic::CLui => {
+                assert_ne!(imm, Some(0), "{}", insn)
+            }
+            Mnemonic::CLi | Mnemonic::CMv | Mnemonic::CLwsp => {
+                assert_ne!(insn.operand("rd"), Some(0), "{}", insn)
+            }
+            _ => {}
+        }
+        if let Some(rm) = insn.args.flags.get("rm") {
+            assert!(![5, 6].contains(&rm.get_val()), "{}", insn);
+        }
+    }
+    assert!(mnemonics.contains(&Mnemonic::CJal));
+    assert!(mnemonics.contains(&Mnemonic::FmaddS));
+    assert!(!mnemonics.contains(&Mnemonic::FaddD));
+}
+
+#[test]
+fn test_generator_weights() {
+    let mut generator = Generator::new(Xlen::XLEN64, 3);
+    for category in [
+        Category::Load,
+        Category::Store,
+        Category::Alu,
+        Category::Vector,
+    ] {
+        generator.set_weight(category, 0);
+    }
+    generator.set_weight(Category::Branch, 1000);
+    let insns = generator.generate(1000);
+    let branches = insns
+        .iter()
+        .filter(|insn| insn.category() == Category::Branch)
+        .count();
+    assert!(branches > 900, "{} branches", branches);
+    assert!(insns
+        .iter()
+        .all(|insn| !matches!(insn.category(), Category::Load | Category::Alu)));
+}
+
+#[test]
+fn test_generator_constraints() {
+    let mut generator = Generator::new(Xlen::XLEN64, 11);
+    generator.set_mnemonics(Some(BTreeSet::from([
+        Mnemonic::Addi,
+        Mnemonic::CAddi,
+        Mnemonic::Lw,
+        Mnemonic::CLw,
+        Mnemonic::Beq,
+    ])));
+    generator.set_registers(Some((8..16).collect()));
+    generator.set_imm_range(Some(0..=16));
+    let insns = generator.generate(500);
+    assert_eq!(insns.len(), 500);
+    let mut mnemonics = BTreeSet::new();
+    for insn in &insns {
+        mnemonics.insert(insn.mnemonic);
+        for reg in insn.args.dst.values().chain(insn.args.src.values()) {
+            assert!((8..16).contains(&reg.get_val()), "{}", insn);
+        }
+        let imm = insn.args.imm.unwrap().get_val_signed_imm();
+        assert!((0..=16).contains(&imm), "{}", insn);
+    }
+    assert_eq!(mnemonics.len(), 5);
+
+    // the only c.lui immediate in range is the reserved 0
+    generator.set_mnemonics(Some(BTreeSet::from([Mnemonic::CLui])));
+    assert!(generator.next_insn().is_none());
+    // and every add that writes x0 is a HINT
+    generator.set_mnemonics(Some(BTreeSet::from([Mnemonic::Add])));
+    generator.set_registers(Some(BTreeSet::from([0])));
+    assert!(generator.next_insn().is_none());
+    generator.set_mnemonics(None);
+    generator.set_extensions(Some(BTreeSet::new()));
+    assert!(generator.generate(10).is_empty());
+}
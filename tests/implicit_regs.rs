@@ -0,0 +1,69 @@
+// tests for the implicit register operands
+use rvdasm::disassembler::*;
+use rvdasm::reg::*;
+
+#[test]
+fn test_implicit_gprs() {
+    let disassembler = Disassembler::new(Xlen::XLEN32);
+    // c.lwsp a0, 12(sp)
+    let insn = disassembler.disassmeble_one(0x4532).unwrap();
+    assert_eq!(insn.args.implicit_src, vec![Reg::Gpr(2)]);
+    assert!(insn.args.implicit_dst.is_empty());
+    // c.addi16sp sp, -64
+    let insn = disassembler.disassmeble_one(0x7139).unwrap();
+    assert_eq!(insn.args.implicit_src, vec![Reg::Gpr(2)]);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Gpr(2)]);
+    // c.jal 0
+    let insn = disassembler.disassmeble_one(0x2001).unwrap();
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Gpr(1)]);
+}
+
+#[test]
+fn test_implicit_csrs() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // fadd.s fa0, fa0, fa1 (dynamic rounding)
+    let insn = disassembler.disassmeble_one(0x00b57553).unwrap();
+    assert_eq!(insn.args.implicit_src, vec![Reg::Csr(CSR_FRM)]);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Csr(CSR_FFLAGS)]);
+    // fadd.s fa0, fa0, fa1, rne
+    let insn = disassembler.disassmeble_one(0x00b50553).unwrap();
+    assert!(insn.args.implicit_src.is_empty());
+    // vadd.vv v1, v2, v3, v0.t
+    let insn = disassembler.disassmeble_one(0x002180d7).unwrap();
+    assert_eq!(
+        insn.args.implicit_src,
+        vec![Reg::Csr(CSR_VL), Reg::Csr(CSR_VTYPE), Reg::Vr(0)]
+    );
+    // vsetvli a0, a1, e32, m1, ta, ma
+    let insn = disassembler.disassmeble_one(0x0d05f557).unwrap();
+    assert_eq!(
+        insn.args.implicit_dst,
+        vec![Reg::Csr(CSR_VL), Reg::Csr(CSR_VTYPE)]
+    );
+}
+
+#[test]
+fn test_implicit_vector_csrs() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let vl_vtype = [Reg::Csr(CSR_VL), Reg::Csr(CSR_VTYPE)];
+    // vfadd.vv v1, v2, v3
+    let insn = disassembler.disassmeble_one(0x022190d7).unwrap();
+    assert_eq!(insn.args.implicit_src[2..], [Reg::Csr(CSR_FRM)]);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Csr(CSR_FFLAGS)]);
+    // vmfeq.vv v1, v2, v3
+    let insn = disassembler.disassmeble_one(0x622190d7).unwrap();
+    assert_eq!(insn.args.implicit_src, vl_vtype);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Csr(CSR_FFLAGS)]);
+    // vsadd.vv v1, v2, v3
+    let insn = disassembler.disassmeble_one(0x862180d7).unwrap();
+    assert_eq!(insn.args.implicit_src, vl_vtype);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Csr(CSR_VXSAT)]);
+    // vaadd.vv v1, v2, v3
+    let insn = disassembler.disassmeble_one(0x2621a0d7).unwrap();
+    assert_eq!(insn.args.implicit_src[2..], [Reg::Csr(CSR_VXRM)]);
+    assert!(insn.args.implicit_dst.is_empty());
+    // vnclip.wi v1, v2, 3
+    let insn = disassembler.disassmeble_one(0xbe21b0d7).unwrap();
+    assert_eq!(insn.args.implicit_src[2..], [Reg::Csr(CSR_VXRM)]);
+    assert_eq!(insn.args.implicit_dst, vec![Reg::Csr(CSR_VXSAT)]);
+}
@@ -0,0 +1,79 @@
+// tests for the per-instruction metadata
+use rvdasm::disassembler::*;
+use rvdasm::isa::*;
+use rvdasm::isa_meta::*;
+use rvdasm::mnemonic::*;
+
+mod common;
+use common::spec_tables;
+
+#[test]
+fn test_metadata_on_insn() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // ld a0, 8(sp)
+    let insn = disassembler.disassmeble_one(0x00813503).unwrap();
+    assert_eq!(insn.extension(), Extension::Rv64I);
+    assert_eq!(insn.format(), Format::I);
+    assert_eq!(insn.category(), Category::Load);
+    // c.lwsp a0, 12(sp)
+    let insn = disassembler.disassmeble_one(0x4532).unwrap();
+    assert_eq!(insn.extension(), Extension::RvC);
+    assert_eq!(insn.format(), Format::Ci);
+    // mulw a0, a0, a1
+    let insn = disassembler.disassmeble_one(0x02b5053b).unwrap();
+    assert_eq!(insn.extension(), Extension::Rv64M);
+    assert_eq!(insn.category(), Category::MulDiv);
+}
+
+#[test]
+fn test_metadata_on_spec() {
+    let spec = RV_ISA_SPECS_GENERIC_FULL_OPCODE_57
+        .iter()
+        .find(|spec| spec.mnemonic == Mnemonic::VaddVv)
+        .unwrap();
+    assert_eq!(spec.extension, Extension::RvV);
+    assert_eq!(spec.format, Format::Va);
+    assert_eq!(spec.category, Category::Vector);
+    for mnemonic in ALL_MNEMONICS {
+        assert_ne!(mnemonic.extension(), Extension::Unknown);
+        assert_ne!(mnemonic.format(), Format::Unknown);
+        assert_ne!(mnemonic.category(), Category::Unknown);
+    }
+}
+
+#[test]
+fn test_metadata_matches_tables() {
+    // the metadata is kept by hand, so check it against where each spec lives
+    let compressed_extensions = [
+        Extension::RvC,
+        Extension::Rv32C,
+        Extension::Rv64C,
+        Extension::RvCD,
+        Extension::Rv32CF,
+        Extension::RvZcb,
+    ];
+    for (xlen, specs) in spec_tables() {
+        for spec in specs {
+            let ext = spec.extension.as_str();
+            if ext.starts_with("rv32") {
+                assert_eq!(xlen, Some(32), "{}", spec.name);
+            }
+            if ext.starts_with("rv64") {
+                assert_eq!(xlen, Some(64), "{}", spec.name);
+            }
+            let compressed = is_compressed(spec.match_bits);
+            assert_eq!(
+                spec.format.as_str().starts_with('C'),
+                compressed,
+                "{}",
+                spec.name
+            );
+            assert_eq!(
+                compressed_extensions.contains(&spec.extension),
+                compressed,
+                "{}",
+                spec.name
+            );
+        }
+    }
+}
@@ -0,0 +1,104 @@
+// tests for jump table recovery
+use rvdasm::cfg::*;
+use rvdasm::disassembler::*;
+use rvdasm::jump_table::*;
+use rvdasm::memory::MemoryImage;
+
+mod common;
+use common::assemble;
+
+#[test]
+fn test_absolute_jump_table() {
+    let code = assemble(&[
+        0x00300793, // 0x00: li a5, 3
+        0x02a7e663, // 0x04: bltu a5, a0, 0x30
+        0x00251513, // 0x08: slli a0, a0, 2
+        0x000027b7, // 0x0c: lui a5, 2
+        0x00078793, // 0x10: addi a5, a5, 0
+        0x00f50533, // 0x14: add a0, a0, a5
+        0x00052503, // 0x18: lw a0, 0(a0)
+        0x00050067, // 0x1c: jr a0
+        0x00a00513, // 0x20: li a0, 10
+        0x00008067, // 0x24: ret
+        0x00b00513, // 0x28: li a0, 11
+        0x00008067, // 0x2c: ret
+        0x00000513, // 0x30: li a0, 0
+        0x00008067, // 0x34: ret
+    ]);
+    let table = assemble(&[0x1020, 0x1028, 0x1020, 0x1030]);
+    let mut memory = MemoryImage::new();
+    memory.add_segment(0x1000, &code);
+    memory.add_segment(0x2000, &table);
+
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insns = disassembler.disassemble_all(&code, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let tables = find_jump_tables(&cfg, &memory);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].jump, 0x101c);
+    assert_eq!(tables[0].table, 0x2000);
+    assert_eq!(tables[0].entry_size, 4);
+    assert!(tables[0].bounded);
+    assert_eq!(tables[0].targets, vec![0x1020, 0x1028, 0x1020, 0x1030]);
+
+    let cfg = Cfg::build_with_targets(&insns, Xlen::XLEN64, &indirect_targets(&tables));
+    let targets: Vec<u64> = cfg.successors(0x1008).iter().map(|edge| edge.to).collect();
+    assert_eq!(targets, vec![0x1020, 0x1028, 0x1030]);
+}
+
+#[test]
+fn test_relative_jump_table_recursive() {
+    let mut code = assemble(&[
+        0x00353793, // 0x00: sltiu a5, a0, 3
+        0x02078463, // 0x04: beqz a5, 0x2c
+        0x00251513, // 0x08: slli a0, a0, 2
+        0x00000717, // 0x0c: auipc a4, 0
+        0x02870713, // 0x10: addi a4, a4, 40
+        0x00e50533, // 0x14: add a0, a0, a4
+        0x00052503, // 0x18: lw a0, 0(a0)
+        0x00e50533, // 0x1c: add a0, a0, a4
+        0x00050067, // 0x20: jr a0
+        0x00a00513, // 0x24: li a0, 10
+        0x00008067, // 0x28: ret
+        0x00000513, // 0x2c: li a0, 0
+        0x00008067, // 0x30: ret
+    ]);
+    // entries relative to the table at 0x34
+    code.extend(assemble(&[
+        (-0x10i32) as u32,
+        (-0x8i32) as u32,
+        (-0x10i32) as u32,
+    ]));
+    let mut memory = MemoryImage::new();
+    memory.add_segment(0, &code);
+
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let (traversal, tables) =
+        disassemble_recursive_with_jump_tables(&disassembler, &code, 0, &[0], &memory);
+    assert_eq!(tables.len(), 1);
+    assert_eq!(tables[0].base, Some(0x34));
+    assert!(tables[0].signed);
+    assert_eq!(tables[0].targets, vec![0x24, 0x2c, 0x24]);
+    assert!(traversal.insns.contains_key(&0x24));
+    assert!(traversal.insns.contains_key(&0x28));
+    assert_eq!(traversal.data, vec![0x34..0x40]);
+}
+
+#[test]
+fn test_wide_shift_is_not_a_table() {
+    // a shift by 32 scales no entry size, and must not overflow
+    let code = assemble(&[
+        0x02051513, // 0x00: slli a0, a0, 32
+        0x000027b7, // 0x04: lui a5, 2
+        0x00f50533, // 0x08: add a0, a0, a5
+        0x00052503, // 0x0c: lw a0, 0(a0)
+        0x00050067, // 0x10: jr a0
+    ]);
+    let mut memory = MemoryImage::new();
+    memory.add_segment(0x1000, &code);
+
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insns = disassembler.disassemble_all(&code, 0x1000);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    assert!(find_jump_tables(&cfg, &memory).is_empty());
+}
@@ -0,0 +1,95 @@
+// tests for legal, HINT, reserved and illegal encodings
+use rvdasm::args::Legality;
+use rvdasm::disassembler::*;
+use rvdasm::mnemonic::Mnemonic;
+
+fn decode(xlen: Xlen, raw: u32) -> (Mnemonic, Legality) {
+    let insn = Disassembler::new(xlen).disassmeble_one(raw).unwrap();
+    (insn.mnemonic, insn.legality)
+}
+
+#[test]
+fn test_legality_compressed() {
+    let rv64 = |raw| decode(Xlen::XLEN64, raw);
+    // the all-zero halfword
+    assert_eq!(rv64(0x0000), (Mnemonic::CAddi4spn, Legality::Illegal));
+    // c.addi4spn s1, sp, 0 and c.addi4spn a0, sp, 4
+    assert_eq!(rv64(0x0004), (Mnemonic::CAddi4spn, Legality::Reserved));
+    assert_eq!(rv64(0x0048), (Mnemonic::CAddi4spn, Legality::Legal));
+    // c.lui a0, 0, c.lui zero, 1 and c.addi16sp sp, 0
+    assert_eq!(rv64(0x6501), (Mnemonic::CLui, Legality::Reserved));
+    assert_eq!(rv64(0x6005), (Mnemonic::CLui, Legality::Hint));
+    assert_eq!(rv64(0x6101), (Mnemonic::CAddi16sp, Legality::Reserved));
+    // c.jr zero
+    assert_eq!(rv64(0x8002), (Mnemonic::CJr, Legality::Reserved));
+    assert_eq!(rv64(0x8082), (Mnemonic::CJr, Legality::Legal));
+    // writes to x0: c.li, c.mv, c.add, c.slli are HINTs, loads are reserved
+    assert_eq!(rv64(0x4005), (Mnemonic::CLi, Legality::Hint));
+    assert_eq!(rv64(0x802a), (Mnemonic::CMv, Legality::Hint));
+    assert_eq!(rv64(0x902a), (Mnemonic::CAdd, Legality::Hint));
+    assert_eq!(rv64(0x0006), (Mnemonic::CSlli, Legality::Hint));
+    assert_eq!(rv64(0x4002), (Mnemonic::CLwsp, Legality::Reserved));
+    assert_eq!(rv64(0x6002), (Mnemonic::CLdsp, Legality::Reserved));
+    assert_eq!(rv64(0x2001), (Mnemonic::CAddiw, Legality::Reserved));
+    // zero immediates: c.addi a0, 0 and c.slli a0, 0; c.nop only without one
+    assert_eq!(rv64(0x0501), (Mnemonic::CAddi, Legality::Hint));
+    assert_eq!(rv64(0x0502), (Mnemonic::CSlli, Legality::Hint));
+    assert_eq!(rv64(0x0001), (Mnemonic::CNop, Legality::Legal));
+    assert_eq!(rv64(0x0005), (Mnemonic::CNop, Legality::Hint));
+    assert_eq!(rv64(0x1502), (Mnemonic::CSlli, Legality::Legal));
+
+    let rv32 = |raw| decode(Xlen::XLEN32, raw);
+    // c.jal on RV32, and shifts by 32 or more
+    assert_eq!(rv32(0x2001), (Mnemonic::CJal, Legality::Legal));
+    assert_eq!(rv32(0x0506), (Mnemonic::CSlli, Legality::Legal));
+    assert_eq!(rv32(0x1502), (Mnemonic::CSlli, Legality::Reserved));
+    assert_eq!(rv32(0x9505), (Mnemonic::CSrai, Legality::Reserved));
+    assert_eq!(rv32(0x8105), (Mnemonic::CSrli, Legality::Legal));
+}
+
+#[test]
+fn test_legality_full() {
+    // slli a0, a0, 32 only exists on RV64
+    assert_eq!(
+        decode(Xlen::XLEN32, 0x02051513),
+        (Mnemonic::Slli, Legality::Reserved)
+    );
+    assert_eq!(
+        decode(Xlen::XLEN64, 0x02051513),
+        (Mnemonic::Slli, Legality::Legal)
+    );
+    // srai a0, a0, 1 and addi a0, a0, 1
+    assert_eq!(
+        decode(Xlen::XLEN32, 0x40155513),
+        (Mnemonic::Srai, Legality::Legal)
+    );
+    assert_eq!(
+        decode(Xlen::XLEN32, 0x00150513),
+        (Mnemonic::Addi, Legality::Legal)
+    );
+
+    let rv64 = |raw| decode(Xlen::XLEN64, raw);
+    // writes to x0: addi x0, x1, 5, lui x0, 0, auipc x0, 1, add x0, x0, a1,
+    // slli x0, a0, 3 and addiw x0, a0, 1 are HINTs, nop and mul are not
+    assert_eq!(rv64(0x00508013), (Mnemonic::Addi, Legality::Hint));
+    assert_eq!(rv64(0x00000037), (Mnemonic::Lui, Legality::Hint));
+    assert_eq!(rv64(0x00001017), (Mnemonic::Auipc, Legality::Hint));
+    assert_eq!(rv64(0x00b00033), (Mnemonic::Add, Legality::Hint));
+    assert_eq!(rv64(0x00351013), (Mnemonic::Slli, Legality::Hint));
+    assert_eq!(rv64(0x0015001b), (Mnemonic::Addiw, Legality::Hint));
+    assert_eq!(rv64(0x00000013), (Mnemonic::Addi, Legality::Legal));
+    assert_eq!(rv64(0x02b50033), (Mnemonic::Mul, Legality::Legal));
+    // fence 0, rw and fence rw, 0 order nothing; fence rw, rw does
+    assert_eq!(rv64(0x0030000f), (Mnemonic::Fence, Legality::Hint));
+    assert_eq!(rv64(0x0300000f), (Mnemonic::Fence, Legality::Hint));
+    assert_eq!(rv64(0x0330000f), (Mnemonic::Fence, Legality::Legal));
+    assert_eq!(
+        decode(Xlen::XLEN32, 0x00351013),
+        (Mnemonic::Slli, Legality::Hint)
+    );
+
+    let insn = Disassembler::new(Xlen::XLEN32)
+        .disassmeble_one(0x00151513)
+        .unwrap();
+    assert_eq!(insn.to_string(), "slli x10, x10, 1");
+}
@@ -0,0 +1,86 @@
+// tests for mapping symbols and ISA strings
+use rvdasm::disassembler::*;
+use rvdasm::isa_meta::Extension;
+use rvdasm::mapping::*;
+
+#[test]
+fn test_parse_mapping_symbols() {
+    assert_eq!(
+        MappingSymbol::parse("$d", 0x10).unwrap().kind,
+        MappingKind::Data
+    );
+    assert_eq!(
+        MappingSymbol::parse("$x", 0x10).unwrap().kind,
+        MappingKind::Code { isa: None }
+    );
+    assert_eq!(
+        MappingSymbol::parse("$xrv32i2p1_c2p0", 0x10).unwrap().kind,
+        MappingKind::Code {
+            isa: Some("rv32i2p1_c2p0".to_string())
+        }
+    );
+    assert!(MappingSymbol::parse("main", 0x10).is_none());
+}
+
+#[test]
+fn test_parse_isa_string() {
+    let (xlen, extensions) = parse_isa_string("rv32i2p1_m2p0_c2p0_zicsr2p0").unwrap();
+    assert_eq!(xlen, Xlen::XLEN32);
+    assert!(extensions.contains(&Extension::RvM));
+    assert!(extensions.contains(&Extension::RvC));
+    assert!(extensions.contains(&Extension::RvZicsr));
+    assert!(!extensions.contains(&Extension::RvF));
+    assert!(!extensions.contains(&Extension::RvCD));
+
+    let (xlen, extensions) = parse_isa_string("rv64gc").unwrap();
+    assert_eq!(xlen, Xlen::XLEN64);
+    assert!(extensions.contains(&Extension::RvCD));
+    assert!(!extensions.contains(&Extension::Rv32CF));
+    assert!(!extensions.contains(&Extension::RvV));
+    assert!(parse_isa_string("x86_64").is_none());
+}
+
+#[test]
+fn test_disabled_extension() {
+    let disassembler = Disassembler::from_isa_string("rv64i").unwrap();
+    assert!(disassembler.disassmeble_one(0x02b50533).is_none()); // mul a0, a0, a1
+    assert!(disassembler.disassmeble_one(0x4505).is_none()); // c.li a0, 1
+    assert_eq!(
+        disassembler.disassmeble_one(0x00150513).unwrap().get_name(),
+        "addi"
+    );
+    let disassembler = Disassembler::with_extensions(Xlen::XLEN64, &[Extension::RvM]);
+    assert_eq!(
+        disassembler.disassmeble_one(0x02b50533).unwrap().get_name(),
+        "mul"
+    );
+}
+
+#[test]
+fn test_disassemble_mapped() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let mut code = Vec::new();
+    code.extend(0x00150513u32.to_le_bytes()); // 0x00: addi a0, a0, 1
+    code.extend(0x12345678u32.to_le_bytes()); // 0x04: data
+    code.extend(0xabcdu16.to_le_bytes()); // 0x08: data
+    code.extend(0x4505u16.to_le_bytes()); // 0x0a: c.li a0, 1
+    code.extend(0x4505u16.to_le_bytes()); // 0x0c: c.li a0, 1 without C
+    let symbols = [
+        MappingSymbol::parse("$x", 0).unwrap(),
+        MappingSymbol::parse("$d", 4).unwrap(),
+        MappingSymbol::parse("$x", 0xa).unwrap(),
+        MappingSymbol::parse("$xrv64i", 0xc).unwrap(),
+    ];
+    let lines = disassembler.disassemble_mapped(&code, 0, &symbols);
+    let text: Vec<String> = lines.values().map(|line| line.to_string()).collect();
+    assert_eq!(
+        text,
+        vec![
+            "addi x10, x10, 1",
+            ".word 0x12345678",
+            ".half 0xabcd",
+            "c.li x10, 1",
+            "unknown",
+        ]
+    );
+}
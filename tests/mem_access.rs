@@ -0,0 +1,97 @@
+// tests for the memory access descriptors
+use rvdasm::disassembler::*;
+use rvdasm::mem_access::*;
+
+#[test]
+fn test_scalar_mem_access() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // lh a0, -4(s0)
+    let access = disassembler
+        .disassmeble_one(0xffc41503)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    assert_eq!(access.kind, MemAccessKind::Load);
+    assert_eq!((access.base, access.offset), (8, -4));
+    assert_eq!((access.width, access.signed), (Some(2), true));
+    // sd ra, 24(sp)
+    let access = disassembler
+        .disassmeble_one(0x00113c23)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    assert_eq!(access.kind, MemAccessKind::Store);
+    assert_eq!((access.base, access.offset, access.width), (2, 24, Some(8)));
+    // amoadd.w a0, a1, (a2)
+    let access = disassembler
+        .disassmeble_one(0x00b6252f)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    assert_eq!(access.kind, MemAccessKind::ReadModifyWrite);
+    assert_eq!((access.base, access.offset, access.width), (12, 0, Some(4)));
+    // add a0, a0, a1
+    assert!(disassembler
+        .disassmeble_one(0x00b50533)
+        .unwrap()
+        .mem_access()
+        .is_none());
+}
+
+#[test]
+fn test_compressed_mem_access() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // c.lwsp a0, 12(sp)
+    let access = disassembler
+        .disassmeble_one(0x4532)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    assert_eq!((access.base, access.offset, access.width), (2, 12, Some(4)));
+    // c.ld a5, 8(a0)
+    let access = disassembler
+        .disassmeble_one(0x651c)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    assert_eq!((access.base, access.offset, access.width), (10, 8, Some(8)));
+}
+
+#[test]
+fn test_vector_mem_access() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // vlse32.v v8, (a0), a1
+    let access = disassembler
+        .disassmeble_one(0x0ab56407)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    let vector = access.vector.unwrap();
+    assert_eq!(vector.addressing, VectorAddressing::Strided { stride: 11 });
+    assert_eq!((access.base, access.width), (10, Some(4)));
+    // vluxei16.v v8, (a0), v4
+    let access = disassembler
+        .disassmeble_one(0x06455407)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    let vector = access.vector.unwrap();
+    assert_eq!(
+        vector.addressing,
+        VectorAddressing::Indexed {
+            index: 4,
+            ordered: false
+        }
+    );
+    assert_eq!((access.width, vector.index_width), (None, Some(2)));
+    // vl2re8.v v2, (a0)
+    let access = disassembler
+        .disassmeble_one(0x22850107)
+        .unwrap()
+        .mem_access()
+        .unwrap();
+    let vector = access.vector.unwrap();
+    assert_eq!(vector.addressing, VectorAddressing::WholeRegister);
+    assert_eq!(vector.nf, 2);
+    assert!(!vector.is_segment());
+}
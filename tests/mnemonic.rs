@@ -0,0 +1,63 @@
+// tests for the mnemonic enum
+use rvdasm::disassembler::*;
+use rvdasm::mnemonic::*;
+use std::collections::BTreeSet;
+
+mod common;
+use common::spec_tables;
+
+#[test]
+fn test_mnemonic_round_trip() {
+    for mnemonic in ALL_MNEMONICS {
+        let parsed: Mnemonic = mnemonic.to_string().parse().unwrap();
+        assert_eq!(parsed, *mnemonic);
+    }
+    assert!("c.addd".parse::<Mnemonic>().is_err());
+}
+
+#[test]
+fn test_mnemonic_on_insn() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insn = disassembler.disassmeble_one(0x00000293).unwrap();
+    assert_eq!(insn.mnemonic, Mnemonic::Addi);
+    assert_eq!(insn.name, "addi");
+    // c.j -4
+    let insn = disassembler.disassmeble_one(0xbff5).unwrap();
+    assert_eq!(insn.get_mnemonic(), Mnemonic::CJ);
+    assert!(insn.is_direct_jump());
+}
+
+/// Helper: Derive the variant name parse_opcodes gives a spec name,
+/// e.g. `c.addi16sp` -> `CAddi16sp` and `vle8.v` -> `Vle8V`
+fn variant_name(name: &str) -> String {
+    name.split(['.', '_'])
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_mnemonics_match_specs() {
+    // the enum is kept by hand, so check it against the spec tables
+    let mut from_specs = BTreeSet::new();
+    for (_, specs) in spec_tables() {
+        for spec in specs {
+            assert_eq!(spec.name, spec.mnemonic.as_str());
+            assert_eq!(
+                format!("{:?}", spec.mnemonic),
+                variant_name(&spec.name),
+                "{}",
+                spec.name
+            );
+            from_specs.insert(spec.mnemonic);
+        }
+    }
+    let all: BTreeSet<Mnemonic> = ALL_MNEMONICS.iter().copied().collect();
+    assert_eq!(all, from_specs);
+    assert_eq!(all.len(), ALL_MNEMONICS.len());
+}
@@ -0,0 +1,130 @@
+// tests for the encoding overlap checker
+use rvdasm::disassembler::*;
+use rvdasm::isa::Spec;
+use rvdasm::isa_meta::Extension;
+use rvdasm::mnemonic::Mnemonic;
+use rvdasm::overlap::*;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_overlap_tables() {
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        let report = OverlapReport::build(xlen, None);
+        assert_eq!(report.conflicts, vec![]);
+        assert_eq!(report.unreachable().count(), 0);
+        assert!(report.specs.iter().all(|spec| spec.decoded <= spec.matched));
+    }
+
+    let report = OverlapReport::build(Xlen::XLEN64, None);
+    let coverage = |mnemonic| {
+        let spec = report
+            .specs
+            .iter()
+            .find(|spec| spec.mnemonic == mnemonic)
+            .unwrap();
+        (spec.table, spec.matched, spec.decoded)
+    };
+    // c.addi leaves rd = x0 to c.nop
+    assert_eq!(
+        coverage(Mnemonic::CAddi),
+        (Table::GenericCompressed, 2048, 1984)
+    );
+    assert_eq!(coverage(Mnemonic::CNop), (Table::GenericCompressed, 64, 64));
+    assert_eq!(coverage(Mnemonic::CLd), (Table::XlenCompressed, 2048, 2048));
+    assert_eq!(
+        coverage(Mnemonic::Add),
+        (Table::GenericFull, 1 << 15, 1 << 15)
+    );
+    assert_eq!(
+        coverage(Mnemonic::Addiw),
+        (Table::XlenFull, 1 << 22, 1 << 22)
+    );
+
+    // RV32 keeps c.slli, c.srli and c.srai in its own table
+    let rv32 = OverlapReport::build(Xlen::XLEN32, Some(&BTreeSet::from([Extension::RvC])));
+    let tables: BTreeSet<Table> = rv32.specs.iter().map(|spec| spec.table).collect();
+    assert_eq!(
+        tables,
+        BTreeSet::from([Table::GenericCompressed, Table::XlenCompressed])
+    );
+    let xlen_specs: Vec<Mnemonic> = rv32
+        .specs
+        .iter()
+        .filter(|spec| spec.table == Table::XlenCompressed)
+        .map(|spec| spec.mnemonic)
+        .collect();
+    assert_eq!(
+        xlen_specs,
+        vec![Mnemonic::CSlli, Mnemonic::CSrai, Mnemonic::CSrli]
+    );
+}
+
+#[test]
+fn test_overlap_conflicts() {
+    // a c.addi without operand constraints swallows c.nop
+    let addi = Spec::new(Mnemonic::CAddi, 0xe003, 0x0001, vec![]);
+    let nop = Spec::new(Mnemonic::CNop, 0xef83, 0x0001, vec![]);
+    // every OP-IMM encoding, then slti and a partial overlap with slli
+    let op_imm = Spec::new(Mnemonic::Addi, 0x0000_007f, 0x0000_0013, vec![]);
+    let slti = Spec::new(Mnemonic::Slti, 0x0000_707f, 0x0000_2013, vec![]);
+    let slli = Spec::new(Mnemonic::Slli, 0x0000_707f, 0x0000_1013, vec![]);
+    let srli = Spec::new(Mnemonic::Srli, 0xfc00_007f, 0x0000_0013, vec![]);
+    let lui = Spec::new(Mnemonic::Lui, 0x0000_007f, 0x0000_0037, vec![]);
+
+    let report = OverlapReport::check(&[
+        (&addi, Table::GenericCompressed),
+        (&nop, Table::XlenCompressed),
+        (&lui, Table::GenericFull),
+        (&slli, Table::GenericFull),
+        (&srli, Table::XlenFull),
+        (&op_imm, Table::XlenFull),
+        (&slti, Table::XlenFull),
+    ]);
+    let conflict = |winner, hidden| {
+        report
+            .conflicts
+            .iter()
+            .find(|conflict| conflict.winner == winner && conflict.hidden == hidden)
+            .map(|conflict| (conflict.count, conflict.example))
+    };
+    assert_eq!(
+        conflict(Mnemonic::CAddi, Mnemonic::CNop),
+        Some((64, 0x0001))
+    );
+    assert_eq!(
+        conflict(Mnemonic::Slli, Mnemonic::Srli),
+        Some((1 << 16, 0x1013))
+    );
+    assert_eq!(
+        conflict(Mnemonic::Srli, Mnemonic::Addi),
+        Some(((1 << 19) - (1 << 16), 0x13))
+    );
+    // srli already decodes the slti words with the top bits clear
+    assert_eq!(
+        conflict(Mnemonic::Srli, Mnemonic::Slti),
+        Some((1 << 16, 0x2013))
+    );
+    assert_eq!(
+        conflict(Mnemonic::Addi, Mnemonic::Slti),
+        Some(((1 << 22) - (1 << 16), 0x0400_2013))
+    );
+    assert_eq!(conflict(Mnemonic::Lui, Mnemonic::Addi), None);
+    assert_eq!(report.conflicts.len(), 6);
+
+    let unreachable: Vec<Mnemonic> = report.unreachable().map(|spec| spec.mnemonic).collect();
+    assert_eq!(unreachable, vec![Mnemonic::CNop, Mnemonic::Slti]);
+    // the report lists 32-bit specs by opcode
+    let decoded: Vec<u64> = report.specs.iter().map(|spec| spec.decoded).collect();
+    assert_eq!(
+        decoded,
+        vec![
+            2048,
+            0,
+            1 << 22,
+            (1 << 19) - (1 << 16),
+            (1 << 25) - (1 << 22) - (1 << 19) + (1 << 16),
+            0,
+            1 << 25
+        ]
+    );
+}
@@ -0,0 +1,128 @@
+// tests for patching instruction fields
+use rvdasm::disassembler::*;
+use rvdasm::encoder::EncodeError;
+use rvdasm::patch::*;
+
+#[test]
+fn test_patch_word() {
+    let patcher = Patcher::new(Xlen::XLEN64);
+    let patch = |raw, field, value| patcher.patch_word(raw, field, value).unwrap();
+    // encodings from llvm-mc
+    // addi a0, a1, -4
+    assert_eq!(patch(0xffc58513, "imm", 100), 0x06458513);
+    assert_eq!(patch(0xffc58513, "rd", 12), 0xffc58613);
+    // beq a0, a1, -16
+    assert_eq!(patch(0xfeb508e3, "imm", 2046), 0x7eb50f63);
+    // jal ra, 16
+    assert_eq!(patch(0x010000ef, "imm", -2048), 0x801ff0ef);
+    // lui a0, 0x12345
+    assert_eq!(
+        patch(0x12345537, "imm", 0xabcde000u32 as i32 as i64),
+        0xabcde537
+    );
+    // csrrw a0, mstatus, a1
+    assert_eq!(patch(0x30059573, "csr", 0x305), 0x30559573);
+    // fadd.d fs0, fa1, fa2, rtz takes integer names for its FP registers
+    assert_eq!(patch(0x02c59553, "rd", 8), 0x02c59453);
+    // vadd.vx v1, v2, a0, v0.t
+    assert_eq!(patch(0x002540d7, "vd", 3), 0x002541d7);
+    // c.j 16, c.addi a0, 1, c.lwsp a0, 12(sp), c.bnez a0, 8
+    assert_eq!(patch(0xa801, "imm", 100), 0xa095);
+    assert_eq!(patch(0x0505, "imm", -5), 0x156d);
+    assert_eq!(patch(0x4532, "imm", 60), 0x5572);
+    assert_eq!(patch(0xe501, "imm", -32), 0xf165);
+    // c.add a0, a1: `rs1` shares the bits of `rd`
+    assert_eq!(patch(0x952e, "rd", 11), 0x95ae);
+    assert_eq!(patch(0x952e, "rs1", 12), 0x962e);
+}
+
+#[test]
+fn test_patch_word_errors() {
+    let patcher = Patcher::new(Xlen::XLEN64);
+    let error = |raw, field, value| patcher.patch_word(raw, field, value).unwrap_err();
+    let out_of_range = |operand: &str, value| {
+        PatchError::Encode(EncodeError::OutOfRange {
+            operand: operand.to_string(),
+            value,
+        })
+    };
+    assert_eq!(error(0xffc58513, "imm", 2048), out_of_range("imm", 2048));
+    assert_eq!(error(0xffc58513, "rd", 32), out_of_range("rd", 32));
+    // branch offsets are even
+    assert_eq!(error(0xfeb508e3, "imm", 3), out_of_range("imm", 3));
+    // c.lw only reaches x8-x15
+    assert_eq!(error(0x4188, "rd", 5), out_of_range("rd", 5));
+    assert_eq!(
+        error(0xffc58513, "csr", 0x300),
+        PatchError::Encode(EncodeError::UnexpectedOperand("csr".to_string()))
+    );
+    // c.addi reserves x0
+    assert_eq!(
+        error(0x0505, "rd", 0),
+        PatchError::Encode(EncodeError::Invalid {
+            operand: "rd".to_string(),
+            value: 0
+        })
+    );
+    assert_eq!(
+        error(0xffffffff, "imm", 0),
+        PatchError::Undecodable { raw: 0xffffffff }
+    );
+}
+
+#[test]
+fn test_patch_bytes() {
+    let patcher = Patcher::new(Xlen::XLEN64);
+    let mut code: Vec<u8> = [
+        0x00a00293u32.to_le_bytes().to_vec(), // 0x1000: addi t0, zero, 10
+        0x0505u16.to_le_bytes().to_vec(),     // 0x1004: c.addi a0, 1
+        0xfe029ee3u32.to_le_bytes().to_vec(), // 0x1006: bnez t0, -4
+        0xa801u16.to_le_bytes().to_vec(),     // 0x100a: c.j 16
+    ]
+    .concat();
+
+    assert_eq!(
+        patcher.patch(&mut code, 0x1000, 0x1000, "imm", 20),
+        Ok(0x01400293)
+    );
+    assert_eq!(code[..4], 0x01400293u32.to_le_bytes());
+    assert_eq!(
+        patcher.patch(&mut code, 0x1000, 0x1004, "imm", 2),
+        Ok(0x0509)
+    );
+    assert_eq!(code[4..6], 0x0509u16.to_le_bytes());
+
+    // bnez t0, 0x1000
+    assert_eq!(
+        patcher.retarget(&mut code, 0x1000, 0x1006, 0x1000),
+        Ok(0xfe029de3)
+    );
+    assert_eq!(code[6..10], 0xfe029de3u32.to_le_bytes());
+    // c.j 0x1004
+    assert_eq!(
+        patcher.retarget(&mut code, 0x1000, 0x100a, 0x1004),
+        Ok(0xbfed)
+    );
+    assert_eq!(code[10..], 0xbfedu16.to_le_bytes());
+
+    let before = code.clone();
+    assert_eq!(
+        patcher.retarget(&mut code, 0x1000, 0x1000, 0x1004),
+        Err(PatchError::NoTarget { raw: 0x01400293 })
+    );
+    assert_eq!(
+        patcher.patch(&mut code, 0x1000, 0x100c, "imm", 0),
+        Err(PatchError::OutOfBounds { addr: 0x100c })
+    );
+    assert_eq!(
+        patcher.patch(&mut code, 0x1000, 0xffc, "imm", 0),
+        Err(PatchError::OutOfBounds { addr: 0xffc })
+    );
+    assert_eq!(
+        patcher.patch(&mut code, 0, u64::MAX, "imm", 0),
+        Err(PatchError::OutOfBounds { addr: u64::MAX })
+    );
+    // c.j only reaches +-2 KiB
+    assert!(patcher.retarget(&mut code, 0x1000, 0x100a, 0x2000).is_err());
+    assert_eq!(code, before);
+}
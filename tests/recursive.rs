@@ -0,0 +1,66 @@
+// tests for recursive-traversal disassembly
+use rvdasm::disassembler::*;
+
+mod common;
+use common::assemble;
+
+#[test]
+fn test_recursive_skips_literal_pool() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x0080006f, // 0x00: j 8
+        0x12345678, // 0x04: literal
+        0x00150513, // 0x08: addi a0, a0, 1
+        0x00008067, // 0x0c: ret
+    ]);
+    let traversal = disassembler.disassemble_recursive(&code, 0x1000, &[0x1000]);
+    let mut addrs: Vec<u64> = traversal.insns.keys().copied().collect();
+    addrs.sort();
+    assert_eq!(addrs, vec![0x1000, 0x1008, 0x100c]);
+    assert_eq!(traversal.data, vec![0x1004..0x1008]);
+    assert!(traversal.conflicts.is_empty());
+}
+
+#[test]
+fn test_recursive_follows_branches_and_calls() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x00050663, // 0x00: beqz a0, 12
+        0x008000ef, // 0x04: jal ra, 8
+        0x00008067, // 0x08: ret
+        0x00008067, // 0x0c: ret
+        0xdeadbeef, // 0x10: never reached
+    ]);
+    let traversal = disassembler.disassemble_recursive(&code, 0, &[0]);
+    assert_eq!(traversal.insns.len(), 4);
+    assert_eq!(traversal.data, vec![0x10..0x14]);
+}
+
+#[test]
+fn test_recursive_reports_overlap() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x0060006f, // 0x00: j 6, into the middle of the next word
+        0x45050513, // 0x04: addi a0, a0, 1104 / 0x06: c.li a0, 1
+        0x00008067, // 0x08: ret
+    ]);
+    let traversal = disassembler.disassemble_recursive(&code, 0, &[0, 4]);
+    assert_eq!(traversal.insns[&6].get_name(), "c.li");
+    assert!(!traversal.insns.contains_key(&4));
+    assert_eq!(traversal.conflicts, vec![Conflict { addr: 6, other: 4 }]);
+    assert_eq!(traversal.data, vec![4..6]);
+}
+
+#[test]
+fn test_recursive_reports_undecodable() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let code = assemble(&[
+        0x00050463, // 0x00: beqz a0, 8
+        0xffffffff, // 0x04: not an instruction
+        0x00008067, // 0x08: ret
+    ]);
+    let traversal = disassembler.disassemble_recursive(&code, 0, &[0]);
+    assert_eq!(traversal.undecodable, vec![4]);
+    assert_eq!(traversal.data, vec![4..8]);
+    assert_eq!(traversal.insns.len(), 2);
+}
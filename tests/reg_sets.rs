@@ -0,0 +1,65 @@
+// tests for the register read/write sets
+use rvdasm::disassembler::*;
+use rvdasm::reg::*;
+use std::collections::BTreeSet;
+
+fn set(regs: &[Reg]) -> BTreeSet<Reg> {
+    regs.iter().copied().collect()
+}
+
+#[test]
+fn test_scalar_reg_sets() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // addi x0, x5, 1
+    let insn = disassembler.disassmeble_one(0x00128013).unwrap();
+    assert_eq!(insn.regs_read(), set(&[Reg::Gpr(5)]));
+    assert!(insn.regs_written().is_empty());
+    // fcvt.w.s a0, fa0, rtz
+    let insn = disassembler.disassmeble_one(0xc0051553).unwrap();
+    assert_eq!(insn.regs_read(), set(&[Reg::Fpr(10)]));
+    assert_eq!(
+        insn.regs_written(),
+        set(&[Reg::Gpr(10), Reg::Csr(CSR_FFLAGS)])
+    );
+    // fcvt.d.s fa0, fa1
+    let insn = disassembler.disassmeble_one(0x42058553).unwrap();
+    assert_eq!(insn.regs_read(), set(&[Reg::Fpr(11)]));
+    assert_eq!(
+        insn.regs_written(),
+        set(&[Reg::Fpr(10), Reg::Csr(CSR_FFLAGS)])
+    );
+    // c.and a0, a1
+    let insn = disassembler.disassmeble_one(0x8d6d).unwrap();
+    assert_eq!(insn.regs_read(), set(&[Reg::Gpr(10), Reg::Gpr(11)]));
+    assert_eq!(insn.regs_written(), set(&[Reg::Gpr(10)]));
+}
+
+#[test]
+fn test_csr_reg_sets() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // csrr a0, mstatus (csrrs a0, mstatus, x0)
+    let insn = disassembler.disassmeble_one(0x30002573).unwrap();
+    assert_eq!(insn.regs_read(), set(&[Reg::Gpr(0), Reg::Csr(0x300)]));
+    assert_eq!(insn.regs_written(), set(&[Reg::Gpr(10)]));
+    // csrwi mstatus, 8 (csrrwi x0, mstatus, 8)
+    let insn = disassembler.disassmeble_one(0x30045073).unwrap();
+    assert!(insn.regs_read().is_empty());
+    assert_eq!(insn.regs_written(), set(&[Reg::Csr(0x300)]));
+}
+
+#[test]
+fn test_vector_reg_sets() {
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    // vwadd.vv v4, v8, v12 with LMUL=2
+    let insn = disassembler.disassmeble_one(0xc6862257).unwrap();
+    let sets = insn.reg_sets(Some(2));
+    assert_eq!(
+        sets.written,
+        set(&[Reg::Vr(4), Reg::Vr(5), Reg::Vr(6), Reg::Vr(7)])
+    );
+    assert!(sets.read.contains(&Reg::Vr(9)) && sets.read.contains(&Reg::Vr(13)));
+    assert!(!sets.read.contains(&Reg::Vr(10)));
+    // vl2re8.v v2, (a0)
+    let insn = disassembler.disassmeble_one(0x22850107).unwrap();
+    assert_eq!(insn.regs_written(), set(&[Reg::Vr(2), Reg::Vr(3)]));
+}
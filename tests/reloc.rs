@@ -0,0 +1,103 @@
+// tests for relocation parsing and rendering
+use rvdasm::disassembler::*;
+use rvdasm::reloc::*;
+
+fn rela64(offset: u64, sym: u32, r_type: u32, addend: i64) -> Vec<u8> {
+    let info = ((sym as u64) << 32) | r_type as u64;
+    [
+        offset.to_le_bytes(),
+        info.to_le_bytes(),
+        addend.to_le_bytes(),
+    ]
+    .concat()
+}
+
+fn rela32(offset: u32, sym: u32, r_type: u8, addend: i32) -> Vec<u8> {
+    let info = (sym << 8) | r_type as u32;
+    [
+        offset.to_le_bytes(),
+        info.to_le_bytes(),
+        addend.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Symbol table of a small object: 1 = `.L0` on the auipc at 0x10, 2 = `foo`, 3 = `sym`
+fn symbol(index: u32) -> Option<RelocSymbol> {
+    let (name, value) = match index {
+        1 => (".L0", 0x10),
+        2 => ("foo", 0),
+        3 => ("sym", 0),
+        _ => return None,
+    };
+    Some(RelocSymbol {
+        name: name.to_string(),
+        value,
+    })
+}
+
+#[test]
+fn test_reloc_kind_names() {
+    assert_eq!(RelocKind::from_r_type(19), RelocKind::CallPlt);
+    assert_eq!(RelocKind::CallPlt.to_string(), "R_RISCV_CALL_PLT");
+    assert_eq!(RelocKind::RvcJump.r_type(), 45);
+    assert_eq!(RelocKind::from_r_type(51).to_string(), "R_RISCV_RELAX");
+    assert_eq!(RelocKind::from_r_type(250), RelocKind::Other(250));
+    assert_eq!(RelocKind::Other(250).to_string(), "R_RISCV_250");
+}
+
+#[test]
+fn test_parse_rela64() {
+    let bytes = [
+        rela64(0x0, 2, 19, 0),
+        rela64(0x0, 0, 51, 0),
+        rela64(0x10, 3, 23, 4),
+        rela64(0x14, 1, 24, 0),
+        rela64(0x1c, 3, 16, -8),
+    ]
+    .concat();
+    let relocs = parse_rela(&bytes, Xlen::XLEN64, symbol);
+    assert_eq!(relocs.len(), 5);
+    assert_eq!(relocs[0].kind, RelocKind::CallPlt);
+    assert_eq!(relocs[0].to_string(), "R_RISCV_CALL_PLT\tfoo");
+    assert_eq!(relocs[1].to_string(), "R_RISCV_RELAX\t*ABS*");
+    assert_eq!(relocs[2].target(), "sym+0x4");
+    assert_eq!(relocs[3].symbol.value, 0x10);
+    assert_eq!(relocs[4].target(), "sym-0x8");
+}
+
+#[test]
+fn test_parse_rela32() {
+    let bytes = [rela32(0x8, 2, 45, 0), rela32(0xc, 3, 44, 2)].concat();
+    let relocs = parse_rela(&bytes, Xlen::XLEN32, symbol);
+    assert_eq!(relocs.len(), 2);
+    assert_eq!(relocs[0].offset, 0x8);
+    assert_eq!(relocs[0].kind, RelocKind::RvcJump);
+    assert_eq!(relocs[1].kind, RelocKind::RvcBranch);
+    assert_eq!(relocs[1].to_string(), "R_RISCV_RVC_BRANCH\tsym+0x2");
+}
+
+#[test]
+fn test_pcrel_lo_pairing() {
+    let bytes = [
+        rela64(0x10, 3, 23, 4),
+        rela64(0x10, 0, 51, 0),
+        rela64(0x14, 1, 24, 0),
+        rela64(0x14, 0, 51, 0),
+        rela64(0x18, 1, 25, 0),
+    ]
+    .concat();
+    let table = RelocationTable::from_relocations(parse_rela(&bytes, Xlen::XLEN64, symbol));
+    assert_eq!(table.len(), 5);
+    assert_eq!(table.at(0x10).len(), 2);
+    assert!(table.at(0x20).is_empty());
+
+    let lo = &table.at(0x14)[0];
+    assert_eq!(table.pcrel_hi(lo).unwrap().kind, RelocKind::PcrelHi20);
+    assert_eq!(table.describe(lo), "R_RISCV_PCREL_LO12_I\t.L0 (sym+0x4)");
+    let store = &table.at(0x18)[0];
+    assert_eq!(table.describe(store), "R_RISCV_PCREL_LO12_S\t.L0 (sym+0x4)");
+    // only the low half looks for a partner
+    assert!(table.pcrel_hi(&table.at(0x10)[0]).is_none());
+    assert_eq!(table.describe(&table.at(0x10)[1]), "R_RISCV_RELAX\t*ABS*");
+}
@@ -0,0 +1,177 @@
+// tests for compressed instruction expansion and compression
+use rvdasm::args::Legality;
+use rvdasm::assembler::Assembler;
+use rvdasm::disassembler::*;
+use rvdasm::mnemonic::Mnemonic;
+use rvdasm::reg::Reg;
+use std::collections::BTreeSet;
+
+#[test]
+fn test_expand_all_compressed() {
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        let disassembler = Disassembler::new(xlen);
+        for raw in (0..=0xffffu32).filter(|raw| is_compressed(*raw)) {
+            let Some(insn) = disassembler.disassmeble_one(raw) else {
+                continue;
+            };
+            // RV32 shifts by 32 or more are reserved and have no 32-bit form,
+            // nor do the Zcb instructions standing for Zbb and Zba ones
+            let Some(expanded) = insn.expand(xlen) else {
+                let no_base = [
+                    Mnemonic::CSextB,
+                    Mnemonic::CSextH,
+                    Mnemonic::CZextH,
+                    Mnemonic::CZextW,
+                ];
+                assert!(
+                    insn.legality == Legality::Reserved || no_base.contains(&insn.mnemonic),
+                    "{}",
+                    insn
+                );
+                continue;
+            };
+            assert!(!is_compressed(expanded.raw), "{}", insn);
+            assert_eq!(expanded.len, 2);
+            // the same registers move, ignoring the `x0` the expansion spells out
+            let without_zero = |mut regs: BTreeSet<Reg>| {
+                regs.remove(&Reg::Gpr(0));
+                regs
+            };
+            assert_eq!(
+                without_zero(expanded.regs_read()),
+                without_zero(insn.regs_read()),
+                "{} -> {}",
+                insn,
+                expanded
+            );
+            assert_eq!(
+                without_zero(expanded.regs_written()),
+                without_zero(insn.regs_written())
+            );
+            assert_eq!(
+                expanded.control_flow(0x1000, xlen),
+                insn.control_flow(0x1000, xlen)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_expand_examples() {
+    let rv64 = Disassembler::new(Xlen::XLEN64);
+    let expand = |raw| {
+        rv64.disassmeble_one(raw)
+            .unwrap()
+            .expand(Xlen::XLEN64)
+            .unwrap()
+    };
+    // c.addi4spn a0, sp, 16
+    assert_eq!(expand(0x0808).to_string(), "addi x10, x2, 16");
+    // c.lwsp a0, 12(sp)
+    assert_eq!(expand(0x4532).raw, 0x00c12503);
+    // c.sdsp ra, 8(sp)
+    assert_eq!(expand(0xe406).raw, 0x00113423);
+    // c.fld fa0, 8(a1)
+    assert_eq!(expand(0x2588).raw, 0x0085b507);
+    // c.mv a0, a1
+    assert_eq!(expand(0x852e).raw, 0x00b00533);
+    // c.jr ra
+    assert_eq!(expand(0x8082).raw, 0x00008067);
+    assert!(rv64
+        .disassmeble_one(0x00000013)
+        .unwrap()
+        .expand(Xlen::XLEN64)
+        .is_none());
+}
+
+#[test]
+fn test_expand_xlen() {
+    // the same word is `c.jal` on RV32 and `c.addiw a0, 1` on RV64
+    let rv32 = Disassembler::new(Xlen::XLEN32)
+        .disassmeble_one(0x2505)
+        .unwrap();
+    assert_eq!(rv32.mnemonic, Mnemonic::CJal);
+    let jal = rv32.expand(Xlen::XLEN32).unwrap();
+    assert_eq!(jal.mnemonic, Mnemonic::Jal);
+    assert_eq!(jal.operand("rd"), Some(1));
+    assert_eq!(jal.offset, rv32.offset);
+    let rv64 = Disassembler::new(Xlen::XLEN64)
+        .disassmeble_one(0x2505)
+        .unwrap();
+    assert_eq!(rv64.mnemonic, Mnemonic::CAddiw);
+    assert_eq!(rv64.expand(Xlen::XLEN64).unwrap().raw, 0x0015051b);
+}
+
+#[test]
+fn test_compress_round_trip() {
+    for xlen in [Xlen::XLEN32, Xlen::XLEN64] {
+        let disassembler = Disassembler::new(xlen);
+        for raw in (0..=0xffffu32).filter(|raw| is_compressed(*raw)) {
+            let Some(insn) = disassembler.disassmeble_one(raw) else {
+                continue;
+            };
+            let Some(expanded) = insn.expand(xlen) else {
+                continue;
+            };
+            let full = disassembler.disassmeble_one(expanded.raw).unwrap();
+            // HINTs and reserved encodings are never produced
+            let Some(compressed) = full.compress(xlen, None) else {
+                continue;
+            };
+            let back = disassembler.disassmeble_one(compressed as u32).unwrap();
+            assert_eq!(back.expand(xlen).unwrap().raw, expanded.raw, "{}", insn);
+        }
+    }
+}
+
+#[test]
+fn test_compress_examples() {
+    let assembler = Assembler::new(Xlen::XLEN64);
+    let rv64 = Disassembler::new(Xlen::XLEN64);
+    let compress = |text: &str| {
+        let raw = assembler.assemble_one(text).unwrap();
+        rv64.disassmeble_one(raw)
+            .unwrap()
+            .compress(Xlen::XLEN64, None)
+    };
+    // encodings from llvm-mc
+    assert_eq!(compress("addi a0, a0, 1"), Some(0x0505));
+    assert_eq!(compress("lw a0, 12(sp)"), Some(0x4532));
+    assert_eq!(compress("sd ra, 8(sp)"), Some(0xe406));
+    assert_eq!(compress("fld fa0, 8(a1)"), Some(0x2588));
+    assert_eq!(compress("add a0, zero, a1"), Some(0x852e));
+    assert_eq!(compress("jalr zero, 0(ra)"), Some(0x8082));
+    assert_eq!(compress("beq a0, zero, 8"), Some(0xc501));
+    // immediate out of range, register outside x8-x15, reserved zero offset
+    assert_eq!(compress("addi a0, a0, 100"), None);
+    assert_eq!(compress("lw a0, 12(t0)"), None);
+    assert_eq!(compress("addi a0, sp, 0"), None);
+    assert_eq!(compress("c.addi a0, 1"), None);
+}
+
+#[test]
+fn test_compress_extensions() {
+    let compress = |isa: &str, text: &str| {
+        let disassembler = Disassembler::from_isa_string(isa).unwrap();
+        let xlen = disassembler.xlen();
+        let raw = Assembler::new(xlen).assemble_one(text).unwrap();
+        Disassembler::new(xlen)
+            .disassmeble_one(raw)
+            .unwrap()
+            .compress(xlen, disassembler.extensions())
+    };
+    assert_eq!(compress("rv64gc", "fld fa0, 8(a1)"), Some(0x2588));
+    assert_eq!(compress("rv64g_zca", "fld fa0, 8(a1)"), None);
+    assert_eq!(compress("rv32gc", "flw fa0, 4(a1)"), Some(0x61c8));
+    assert_eq!(compress("rv64gc", "flw fa0, 4(a1)"), None);
+    assert_eq!(compress("rv64imac", "addi a0, a0, 1"), Some(0x0505));
+    assert_eq!(compress("rv64ima", "addi a0, a0, 1"), None);
+    // Zcb
+    assert_eq!(compress("rv64gc", "lbu a0, 1(a1)"), None);
+    assert_eq!(compress("rv64gc_zcb", "lbu a0, 1(a1)"), Some(0x81c8));
+    assert_eq!(compress("rv64gc_zcb", "lh a0, 2(a1)"), Some(0x85e8));
+    assert_eq!(compress("rv64gc_zcb", "sb a0, 1(a1)"), Some(0x89c8));
+    assert_eq!(compress("rv64gc_zcb", "andi a0, a0, 255"), Some(0x9d61));
+    assert_eq!(compress("rv64gc_zcb", "xori a0, a0, -1"), Some(0x9d75));
+    assert_eq!(compress("rv64gc_zcb", "mul a0, a0, a1"), Some(0x9d4d));
+}
@@ -0,0 +1,75 @@
+// tests for the cross-reference database
+use rvdasm::cfg::*;
+use rvdasm::const_prop::*;
+use rvdasm::disassembler::*;
+use rvdasm::xref::*;
+
+mod common;
+use common::assemble;
+
+#[test]
+fn test_xrefs() {
+    let code = assemble(&[
+        0x10000737, // 0x00: lui a4, 0x10000
+        0x00472503, // 0x04: lw a0, 4(a4)
+        0x00156513, // 0x08: ori a0, a0, 1
+        0x00a72223, // 0x0c: sw a0, 4(a4)
+        0x018000ef, // 0x10: jal ra, 0x28
+        0x00000597, // 0x14: auipc a1, 0
+        0x02058593, // 0x18: addi a1, a1, 32
+        0x00000097, // 0x1c: auipc ra, 0
+        0x00c080e7, // 0x20: jalr ra, 12(ra)
+        0x00050263, // 0x24: beqz a0, 0x28
+        0x00008067, // 0x28: ret
+    ]);
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insns = disassembler.disassemble_all(&code, 0);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let db = XrefDb::build(&cfg, &ConstProp::within_blocks(&cfg));
+
+    assert_eq!(db.callers(0x28), vec![0x10, 0x20]);
+    assert!(db.refs_to(0x28).contains(&Xref {
+        from: 0x24,
+        to: 0x28,
+        kind: XrefKind::Branch
+    }));
+    let mmio: Vec<Xref> = db.refs_to_range(0x10000000..0x10001000).copied().collect();
+    assert_eq!(
+        mmio,
+        vec![
+            Xref {
+                from: 0x4,
+                to: 0x10000004,
+                kind: XrefKind::Read
+            },
+            Xref {
+                from: 0xc,
+                to: 0x10000004,
+                kind: XrefKind::Write
+            },
+        ]
+    );
+    assert_eq!(db.data_refs_to(0x34)[0].kind, XrefKind::Address);
+    assert_eq!(db.refs_from(0x18)[0].to, 0x34);
+    assert!(db.refs_from(0x8).is_empty());
+}
+
+#[test]
+fn test_address_xrefs_need_lui_or_auipc() {
+    let code = [
+        0x37, 0x05, 0x00, 0x10, // 0x00: lui a0, 0x10000
+        0xaa, 0x85, // 0x04: c.mv a1, a0
+        0x0d, 0x46, // 0x06: c.li a2, 3
+        0xb2, 0x86, // 0x08: c.mv a3, a2
+        0x13, 0x07, 0x86, 0x00, // 0x0a: addi a4, a2, 8
+        0x82, 0x80, // 0x0e: ret
+    ];
+    let disassembler = Disassembler::new(Xlen::XLEN64);
+    let insns = disassembler.disassemble_all(&code, 0);
+    let cfg = Cfg::build(&insns, Xlen::XLEN64);
+    let db = XrefDb::build(&cfg, &ConstProp::within_blocks(&cfg));
+    assert_eq!(db.data_refs_to(0x10000000)[0].from, 0x4);
+    assert!(db.refs_from(0x8).is_empty());
+    assert!(db.refs_from(0xa).is_empty());
+    assert!(db.refs_to(0x3).is_empty());
+}